@@ -0,0 +1,65 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A handle to an event handler, used to remove it later.
+///
+/// Handles are typed by the event arguments, so a handle from one event cannot be
+/// passed to an event of a different type, and they can only be obtained by adding
+/// a handler. Each handle is unique for the lifetime of the process: once its
+/// handler is removed, a handle never refers to another handler, even one that
+/// reuses the same storage slot, and it never refers to a handler of another event.
+pub struct HandlerId<TEventArgs> {
+    key: usize,
+    generation: u64,
+    marker: PhantomData<fn(TEventArgs)>,
+}
+
+impl<TEventArgs> HandlerId<TEventArgs> {
+    pub(crate) fn new(key: usize) -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self {
+            key,
+            generation: NEXT.fetch_add(1, Ordering::Relaxed),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the storage slot of the handler.
+    pub(crate) fn key(&self) -> usize {
+        self.key
+    }
+}
+
+impl<TEventArgs> Clone for HandlerId<TEventArgs> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<TEventArgs> Copy for HandlerId<TEventArgs> {}
+
+impl<TEventArgs> PartialEq for HandlerId<TEventArgs> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.generation == other.generation
+    }
+}
+
+impl<TEventArgs> Eq for HandlerId<TEventArgs> {}
+
+impl<TEventArgs> Hash for HandlerId<TEventArgs> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+        self.generation.hash(state);
+    }
+}
+
+impl<TEventArgs> fmt::Debug for HandlerId<TEventArgs> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HandlerId")
+            .field("key", &self.key)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
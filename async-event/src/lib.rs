@@ -1,8 +1,17 @@
-use futures::future::{FutureExt, JoinAll, LocalBoxFuture, join_all};
+use futures::future::{Either, FutureExt, JoinAll, LocalBoxFuture, join_all, select};
+use futures::stream::{self, StreamExt};
 use std::{cell::RefCell, future::Future, rc::Rc};
 
 use slab::Slab;
 
+mod id;
+mod spawn;
+
+pub use id::HandlerId;
+pub use spawn::Spawn;
+#[cfg(feature = "wasm")]
+pub use spawn::WasmSpawner;
+
 /// Type alias for asynchronous event handlers.
 ///
 /// This represents a boxed function that takes a reference to event arguments
@@ -12,7 +21,9 @@ pub type AsyncEventHandler<'a, TEventArgs> = Rc<dyn Fn(TEventArgs) -> LocalBoxFu
 /// An asynchronous event that can have multiple handlers attached to it.
 ///
 /// This is similar to C#'s `event` keyword but designed for async/await patterns.
-/// Handlers are stored in a slab storage for efficient access by index.
+/// Handlers are stored in a slab storage for efficient access by index, and
+/// are invoked in the order they were added, even when they reuse the slot of a
+/// removed handler.
 ///
 /// # Examples
 ///
@@ -37,7 +48,8 @@ pub type AsyncEventHandler<'a, TEventArgs> = Rc<dyn Fn(TEventArgs) -> LocalBoxFu
 /// # });
 /// ```
 pub struct AsyncEvent<'a, TEventArgs> {
-    handlers: RefCell<Slab<AsyncEventHandler<'a, TEventArgs>>>,
+    handlers: RefCell<Slab<(HandlerId<TEventArgs>, AsyncEventHandler<'a, TEventArgs>)>>,
+    order: RefCell<Vec<usize>>,
 }
 
 impl<'a, TEventArgs> Default for AsyncEvent<'a, TEventArgs> {
@@ -59,6 +71,7 @@ impl<'a, TEventArgs> AsyncEvent<'a, TEventArgs> {
     pub fn new() -> Self {
         Self {
             handlers: Slab::new().into(),
+            order: Vec::new().into(),
         }
     }
 
@@ -81,14 +94,19 @@ impl<'a, TEventArgs> AsyncEvent<'a, TEventArgs> {
     /// });
     /// # });
     /// ```
-    pub fn add<F, Fut>(&self, handler: F) -> usize
+    pub fn add<F, Fut>(&self, handler: F) -> HandlerId<TEventArgs>
     where
         F: Fn(TEventArgs) -> Fut + 'a,
         Fut: Future<Output = ()> + 'a,
     {
-        self.handlers
-            .borrow_mut()
-            .insert(Rc::new(move |arg| handler(arg).boxed_local()))
+        let handler: AsyncEventHandler<'a, TEventArgs> =
+            Rc::new(move |arg| handler(arg).boxed_local());
+        let mut handlers = self.handlers.borrow_mut();
+        let entry = handlers.vacant_entry();
+        let handle = HandlerId::new(entry.key());
+        entry.insert((handle, handler));
+        self.order.borrow_mut().push(handle.key());
+        handle
     }
 
     /// Removes an event handler using its handle.
@@ -110,8 +128,48 @@ impl<'a, TEventArgs> AsyncEvent<'a, TEventArgs> {
     /// assert!(!event.remove(handle)); // Already removed
     /// # });
     /// ```
-    pub fn remove(&self, handle: usize) -> bool {
-        self.handlers.borrow_mut().try_remove(handle).is_some()
+    pub fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        let mut handlers = self.handlers.borrow_mut();
+        let removed = handlers
+            .get(handle.key())
+            .is_some_and(|&(other, _)| other == handle);
+        if removed {
+            handlers.remove(handle.key());
+            self.order
+                .borrow_mut()
+                .retain(|&other| other != handle.key());
+        }
+        removed
+    }
+
+    /// Returns the number of event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_event_rs::AsyncEvent;
+    ///
+    /// let event = AsyncEvent::<()>::new();
+    /// event.add(|args| async move { println!("Handler 1"); });
+    ///
+    /// assert_eq!(event.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.handlers.borrow().len()
+    }
+
+    /// Returns `true` if the event has no handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_event_rs::AsyncEvent;
+    ///
+    /// let event: AsyncEvent<()> = AsyncEvent::new();
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     /// Removes all event handlers.
@@ -131,6 +189,7 @@ impl<'a, TEventArgs> AsyncEvent<'a, TEventArgs> {
     /// ```
     pub fn clear(&self) {
         self.handlers.borrow_mut().clear();
+        self.order.borrow_mut().clear();
     }
 
     /// Invokes all event handlers sequentially (one after another).
@@ -154,18 +213,93 @@ impl<'a, TEventArgs> AsyncEvent<'a, TEventArgs> {
     where
         TEventArgs: Clone,
     {
-        let handlers: Vec<AsyncEventHandler<'a, TEventArgs>> = self
-            .handlers
-            .borrow()
-            .iter()
-            .map(|(_, handler)| Rc::clone(handler))
-            .collect();
-
-        for handler in handlers {
+        for (_, handler) in self.snapshot() {
             handler(arg.clone()).await;
         }
     }
 
+    /// Invokes the event once for each of the event arguments in `args`, in order.
+    ///
+    /// This is equivalent to awaiting [`invoke_async`](Self::invoke_async) for each
+    /// of them, except that the handlers are looked up once for the whole batch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_event_rs::AsyncEvent;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let event = AsyncEvent::<u32>::new();
+    /// event.add(|args| async move { println!("Particle {} moved", args); });
+    ///
+    /// event.invoke_all_async(&[1, 2, 3]).await; // Prints for particles 1, 2 and 3
+    /// # });
+    /// ```
+    pub async fn invoke_all_async(&self, args: &[TEventArgs])
+    where
+        TEventArgs: Clone,
+    {
+        let snapshot = self.snapshot();
+        for arg in args {
+            for (_, handler) in &snapshot {
+                handler(arg.clone()).await;
+            }
+        }
+    }
+
+    /// Invokes all event handlers sequentially, abandoning each handler that does
+    /// not complete before its timer.
+    ///
+    /// A new timer is created with `timer` for each handler and raced against it.
+    /// If the timer completes first, the handler's future is dropped, which cancels
+    /// it, and the invocation moves on to the next handler. Any timer future works,
+    /// so this does not depend on a particular runtime.
+    ///
+    /// Returns `Ok(())` if every handler completed in time, or `Err` with the
+    /// handles of the handlers that timed out, in invocation order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use async_event_rs::AsyncEvent;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+    /// let event = AsyncEvent::new();
+    /// event.add(|args| async move { println!("Handler 1"); });
+    /// let stuck = event.add(|args| futures::future::pending());
+    /// event.add(|args| async move { println!("Handler 3 still runs"); });
+    ///
+    /// let timeout = || tokio::time::sleep(Duration::from_millis(10));
+    /// assert_eq!(event.invoke_timeout_async((), timeout).await, Err(vec![stuck]));
+    /// # });
+    /// ```
+    pub async fn invoke_timeout_async<T, Fut>(
+        &self,
+        arg: TEventArgs,
+        timer: T,
+    ) -> Result<(), Vec<HandlerId<TEventArgs>>>
+    where
+        TEventArgs: Clone,
+        T: Fn() -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let mut timed_out = Vec::new();
+        for (handle, handler) in self.snapshot() {
+            let timer = std::pin::pin!(timer());
+            if let Either::Right(_) = select(handler(arg.clone()), timer).await {
+                timed_out.push(handle);
+            }
+        }
+
+        if timed_out.is_empty() {
+            Ok(())
+        } else {
+            Err(timed_out)
+        }
+    }
+
     /// Invokes all event handlers in parallel.
     ///
     /// All handlers are spawned concurrently and executed simultaneously.
@@ -188,13 +322,96 @@ impl<'a, TEventArgs> AsyncEvent<'a, TEventArgs> {
         TEventArgs: Clone,
     {
         join_all(
-            self.handlers
-                .borrow()
+            self.snapshot()
                 .iter()
                 .map(|(_, handler)| handler(arg.clone()))
                 .collect::<Vec<_>>(),
         )
     }
+
+    /// Invokes all event handlers in parallel, with at most `limit` handlers running at once.
+    ///
+    /// Handlers are started in order as earlier ones complete, which bounds the
+    /// number of in-flight futures while still overlapping their latency.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_event_rs::AsyncEvent;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let event = AsyncEvent::new();
+    /// event.add(|args| async move { println!("Handler 1"); });
+    /// event.add(|args| async move { println!("Handler 2"); });
+    /// event.add(|args| async move { println!("Handler 3"); });
+    ///
+    /// event.invoke_parallel_limited_async((), 2).await; // At most 2 handlers at a time
+    /// # });
+    /// ```
+    pub async fn invoke_parallel_limited_async(&self, arg: TEventArgs, limit: usize)
+    where
+        TEventArgs: Clone,
+    {
+        assert!(limit > 0, "concurrency limit must be greater than zero");
+
+        let futures: Vec<_> = self
+            .snapshot()
+            .iter()
+            .map(|(_, handler)| handler(arg.clone()))
+            .collect();
+
+        stream::iter(futures)
+            .buffer_unordered(limit)
+            .collect::<()>()
+            .await;
+    }
+
+    /// Spawns all event handlers onto `spawner` without waiting for them to complete.
+    ///
+    /// This lets synchronous code raise the event: the handlers run whenever the
+    /// executor behind [`Spawn`] gets to them, in parallel with each other. As
+    /// the spawned futures outlive the call, the handlers must be `'static`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_event_rs::AsyncEvent;
+    ///
+    /// let mut pool = futures::executor::LocalPool::new();
+    /// let event = AsyncEvent::new();
+    /// event.add(|args| async move { println!("Handler 1"); });
+    /// event.add(|args| async move { println!("Handler 2"); });
+    ///
+    /// event.invoke_spawned(&pool.spawner(), ()); // Returns immediately
+    /// pool.run(); // Prints "Handler 1", "Handler 2"
+    /// ```
+    pub fn invoke_spawned<S>(&self, spawner: &S, arg: TEventArgs)
+    where
+        'a: 'static,
+        TEventArgs: Clone,
+        S: Spawn + ?Sized,
+    {
+        for (_, handler) in self.snapshot() {
+            spawner.spawn(handler(arg.clone()));
+        }
+    }
+
+    /// Returns the handlers and their handles in insertion order.
+    fn snapshot(&self) -> Vec<(HandlerId<TEventArgs>, AsyncEventHandler<'a, TEventArgs>)> {
+        let handlers = self.handlers.borrow();
+        self.order
+            .borrow()
+            .iter()
+            .map(|&key| {
+                let (handle, handler) = &handlers[key];
+                (*handle, Rc::clone(handler))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -230,6 +447,23 @@ mod tests {
         assert_eq!(*counter.borrow(), 4);
     }
 
+    #[tokio::test]
+    async fn test_invoke_all_async() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = AsyncEvent::new();
+
+        for name in ["a", "b"] {
+            let log = Rc::clone(&log);
+            event.add(move |arg: i32| {
+                let log = Rc::clone(&log);
+                async move { log.borrow_mut().push((name, arg)) }
+            });
+        }
+
+        event.invoke_all_async(&[1, 2]).await;
+        assert_eq!(*log.borrow(), vec![("a", 1), ("b", 1), ("a", 2), ("b", 2)]);
+    }
+
     #[tokio::test]
     async fn test_invoke_parallel_async() {
         let log = Rc::new(RefCell::new(vec![]));
@@ -256,6 +490,108 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_invoke_parallel_limited_async() {
+        let running = Rc::new(RefCell::new(0));
+        let peak = Rc::new(RefCell::new(0));
+        let event = AsyncEvent::new();
+
+        for _ in 0..5 {
+            let running = Rc::clone(&running);
+            let peak = Rc::clone(&peak);
+            event.add(move |_| {
+                let running = Rc::clone(&running);
+                let peak = Rc::clone(&peak);
+                async move {
+                    *running.borrow_mut() += 1;
+                    let current = *running.borrow();
+                    peak.replace_with(|&mut peak| peak.max(current));
+                    tokio::task::yield_now().await;
+                    *running.borrow_mut() -= 1;
+                }
+            });
+        }
+
+        event.invoke_parallel_limited_async((), 2).await;
+
+        assert_eq!(*running.borrow(), 0);
+        assert_eq!(*peak.borrow(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_insertion_order_after_reuse() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = AsyncEvent::new();
+
+        let push = |i| {
+            let log = Rc::clone(&log);
+            move |_| {
+                let log = Rc::clone(&log);
+                async move { log.borrow_mut().push(i) }
+            }
+        };
+        let first = event.add(push(1));
+        event.add(push(2));
+        assert!(event.remove(first));
+        event.add(push(3));
+
+        event.invoke_async(()).await;
+        assert_eq!(*log.borrow(), vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_remove_stale_handle() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = AsyncEvent::new();
+
+        let push = |i| {
+            let log = Rc::clone(&log);
+            move |_| {
+                let log = Rc::clone(&log);
+                async move { log.borrow_mut().push(i) }
+            }
+        };
+        let stale = event.add(push(1));
+        assert!(event.remove(stale));
+        let reused = event.add(push(2));
+        assert_ne!(stale, reused);
+
+        // The new handler reuses the slot, but the stale handle does not refer to it
+        assert!(!event.remove(stale));
+        event.invoke_async(()).await;
+        assert_eq!(*log.borrow(), vec![2]);
+        assert!(event.remove(reused));
+    }
+
+    #[tokio::test]
+    async fn test_invoke_timeout_async() {
+        use std::time::Duration;
+
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = AsyncEvent::new();
+
+        let log_clone = Rc::clone(&log);
+        let slow = event.add(move |_| {
+            let log = Rc::clone(&log_clone);
+            async move {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                log.borrow_mut().push("slow");
+            }
+        });
+        let log_clone = Rc::clone(&log);
+        event.add(move |_| {
+            let log = Rc::clone(&log_clone);
+            async move { log.borrow_mut().push("fast") }
+        });
+
+        let timer = || tokio::time::sleep(Duration::from_millis(10));
+        assert_eq!(event.invoke_timeout_async((), timer).await, Err(vec![slow]));
+        assert_eq!(*log.borrow(), vec!["fast"]);
+
+        event.remove(slow);
+        assert_eq!(event.invoke_timeout_async((), timer).await, Ok(()));
+    }
+
     #[tokio::test]
     async fn test_remove_handler() {
         let counter = Rc::new(RefCell::new(0));
@@ -0,0 +1,151 @@
+use futures::executor::LocalSpawner;
+use futures::future::LocalBoxFuture;
+use futures::task::LocalSpawnExt;
+
+/// A minimal abstraction over the executors that can run the handlers of an
+/// [`AsyncEvent`](crate::AsyncEvent) in the background.
+///
+/// The handlers return non-`Send` futures, so the executor must run them on the
+/// current thread. Nothing in the crate depends on a particular runtime: any
+/// closure taking the future implements the trait, and implementations for some
+/// executors are provided behind features:
+///
+/// - [`LocalSpawner`] of the `futures` crate, always.
+/// - `tokio::task::LocalSet`, with the `tokio` feature.
+/// - `async_executor::LocalExecutor`, as used by smol, with the `smol` feature.
+/// - [`WasmSpawner`](crate::WasmSpawner) for `wasm-bindgen-futures`, with the
+///   `wasm` feature.
+///
+/// # Examples
+///
+/// ```
+/// use async_event_rs::{AsyncEvent, Spawn};
+///
+/// fn notify(event: &AsyncEvent<'static, u32>, spawner: &impl Spawn) {
+///     event.invoke_spawned(spawner, 1);
+/// }
+///
+/// let mut pool = futures::executor::LocalPool::new();
+/// let event = AsyncEvent::new();
+/// event.add(|args| async move { println!("Handler got {}", args); });
+///
+/// notify(&event, &pool.spawner());
+/// pool.run(); // Prints "Handler got 1"
+/// ```
+pub trait Spawn {
+    /// Spawns `future` to run to completion in the background.
+    fn spawn(&self, future: LocalBoxFuture<'static, ()>);
+}
+
+impl<F: Fn(LocalBoxFuture<'static, ()>)> Spawn for F {
+    fn spawn(&self, future: LocalBoxFuture<'static, ()>) {
+        self(future)
+    }
+}
+
+impl Spawn for LocalSpawner {
+    fn spawn(&self, future: LocalBoxFuture<'static, ()>) {
+        // The pool has been dropped, so the future could never run anyway.
+        let _ = self.spawn_local(future);
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Spawn for tokio::task::LocalSet {
+    fn spawn(&self, future: LocalBoxFuture<'static, ()>) {
+        self.spawn_local(future);
+    }
+}
+
+#[cfg(feature = "smol")]
+impl Spawn for async_executor::LocalExecutor<'_> {
+    fn spawn(&self, future: LocalBoxFuture<'static, ()>) {
+        async_executor::LocalExecutor::spawn(self, future).detach();
+    }
+}
+
+/// Spawns futures onto the JavaScript event loop with
+/// `wasm_bindgen_futures::spawn_local`.
+///
+/// This requires the `wasm` feature.
+#[cfg(feature = "wasm")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WasmSpawner;
+
+#[cfg(feature = "wasm")]
+impl Spawn for WasmSpawner {
+    fn spawn(&self, future: LocalBoxFuture<'static, ()>) {
+        wasm_bindgen_futures::spawn_local(future);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use futures::executor::LocalPool;
+
+    use crate::AsyncEvent;
+
+    #[test]
+    fn test_invoke_spawned() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = AsyncEvent::new();
+        for name in ["a", "b"] {
+            let log = Rc::clone(&log);
+            event.add(move |arg: i32| {
+                let log = Rc::clone(&log);
+                async move { log.borrow_mut().push((name, arg)) }
+            });
+        }
+
+        let mut pool = LocalPool::new();
+        event.invoke_spawned(&pool.spawner(), 1);
+        assert!(log.borrow().is_empty());
+        pool.run();
+        assert_eq!(*log.borrow(), vec![("a", 1), ("b", 1)]);
+
+        let queued = RefCell::new(vec![]);
+        event.invoke_spawned(&|future| queued.borrow_mut().push(future), 2);
+        assert_eq!(queued.borrow().len(), 2);
+        for future in queued.take() {
+            futures::executor::block_on(future);
+        }
+        assert_eq!(log.borrow()[2..], [("a", 2), ("b", 2)]);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "tokio")]
+    async fn test_invoke_spawned_tokio() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = AsyncEvent::new();
+        let handler_log = Rc::clone(&log);
+        event.add(move |arg: i32| {
+            let log = Rc::clone(&handler_log);
+            async move { log.borrow_mut().push(arg) }
+        });
+
+        let local = tokio::task::LocalSet::new();
+        event.invoke_spawned(&local, 1);
+        local.await;
+        assert_eq!(*log.borrow(), vec![1]);
+    }
+
+    #[test]
+    #[cfg(feature = "smol")]
+    fn test_invoke_spawned_smol() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = AsyncEvent::new();
+        let handler_log = Rc::clone(&log);
+        event.add(move |arg: i32| {
+            let log = Rc::clone(&handler_log);
+            async move { log.borrow_mut().push(arg) }
+        });
+
+        let executor = async_executor::LocalExecutor::new();
+        event.invoke_spawned(&executor, 1);
+        while executor.try_tick() {}
+        assert_eq!(*log.borrow(), vec![1]);
+    }
+}
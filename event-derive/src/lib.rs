@@ -0,0 +1,164 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{
+    Data, DeriveInput, Error, Field, Fields, GenericArgument, Lifetime, Path, PathArguments,
+    Result, Type, parse_macro_input,
+};
+
+/// Generates subscription and invocation methods for the event fields of a struct.
+///
+/// For each field of type `Event<'a, TEventArgs>` marked with `#[event]`, this
+/// generates `on_<field>(&self, handler) -> HandlerId<TEventArgs>`, which adds a
+/// handler to the event, and `emit_<field>(&self, args)`, which invokes it.
+/// Both methods have the visibility of the field, so an event in a private
+/// field can only be subscribed to and raised by the module that owns it.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{Event, EventEmitter};
+///
+/// #[derive(EventEmitter)]
+/// struct Button<'a> {
+///     #[event]
+///     clicked: Event<'a, (i32, i32)>,
+///     label: String,
+/// }
+///
+/// let button = Button {
+///     clicked: Event::new(),
+///     label: "OK".to_string(),
+/// };
+/// let handle = button.on_clicked(|(x, y)| println!("Clicked at ({}, {})", x, y));
+///
+/// button.emit_clicked(&(1, 2)); // Prints "Clicked at (1, 2)"
+/// assert!(button.clicked.remove(handle));
+/// ```
+///
+/// The generated code refers to the `event_rs` crate by name. If the dependency
+/// is renamed, pass its path with `#[event_rs(crate = ...)]` on the struct:
+///
+/// ```
+/// use event_rs as events;
+///
+/// #[derive(events::EventEmitter)]
+/// #[event_rs(crate = events)]
+/// struct Timer {
+///     #[event]
+///     elapsed: events::Event<'static, u64>,
+/// }
+/// ```
+#[proc_macro_derive(EventEmitter, attributes(event, event_rs))]
+pub fn derive_event_emitter(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            input,
+            "EventEmitter can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            input,
+            "EventEmitter can only be derived for structs with named fields",
+        ));
+    };
+
+    let krate = crate_path(input)?;
+    let mut methods = Vec::new();
+    for field in &fields.named {
+        if !field.attrs.iter().any(|attr| attr.path().is_ident("event")) {
+            continue;
+        }
+        methods.push(expand_field(field, &krate)?);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
+
+/// Returns the path given with `#[event_rs(crate = ...)]`, or `::event_rs`.
+fn crate_path(input: &DeriveInput) -> Result<Path> {
+    let mut krate = syn::parse_quote!(::event_rs);
+    for attr in &input.attrs {
+        if !attr.path().is_ident("event_rs") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported event_rs attribute, expected `crate`"))
+            }
+        })?;
+    }
+    Ok(krate)
+}
+
+fn expand_field(field: &Field, krate: &Path) -> Result<proc_macro2::TokenStream> {
+    let ident = field.ident.as_ref().expect("named fields have identifiers");
+    let vis = &field.vis;
+    let (lifetime, args, result) = event_type(&field.ty)?;
+    let on = format_ident!("on_{}", ident);
+    let emit = format_ident!("emit_{}", ident);
+    let on_doc = format!("Adds an event handler to the `{}` event.", ident);
+    let emit_doc = format!("Invokes the `{}` event.", ident);
+
+    Ok(quote! {
+        #[doc = #on_doc]
+        #vis fn #on<F>(&self, handler: F) -> #krate::HandlerId<#args>
+        where
+            F: Fn(&#args) -> #result + #lifetime,
+        {
+            self.#ident.add(handler)
+        }
+
+        #[doc = #emit_doc]
+        #vis fn #emit(&self, args: &#args) {
+            self.#ident.invoke(args)
+        }
+    })
+}
+
+/// Splits `Event<'a, TEventArgs, TResult>` into its generic arguments.
+fn event_type(ty: &Type) -> Result<(Lifetime, Type, Type)> {
+    let error = || Error::new_spanned(ty, "#[event] fields must have type `Event<'a, TEventArgs>`");
+    let Type::Path(path) = ty else {
+        return Err(error());
+    };
+    let segment = path.path.segments.last().ok_or_else(error)?;
+    if segment.ident != "Event" {
+        return Err(error());
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return Err(error());
+    };
+
+    let mut lifetime = Lifetime::new("'static", Span::call_site());
+    let mut types = Vec::new();
+    for argument in &arguments.args {
+        match argument {
+            GenericArgument::Lifetime(argument) => lifetime = argument.clone(),
+            GenericArgument::Type(argument) => types.push(argument.clone()),
+            _ => return Err(error()),
+        }
+    }
+
+    let mut types = types.into_iter();
+    let args = types.next().ok_or_else(error)?;
+    let result = types.next().unwrap_or_else(|| syn::parse_quote!(()));
+    Ok((lifetime, args, result))
+}
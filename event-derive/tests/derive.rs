@@ -0,0 +1,90 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use event_rs::{Event, EventEmitter};
+
+#[derive(EventEmitter)]
+struct Validator {
+    #[event]
+    validating: Event<'static, String, bool>,
+    #[allow(dead_code)]
+    name: &'static str,
+}
+
+#[derive(EventEmitter)]
+struct Observer<'a, T> {
+    #[event]
+    changed: Event<'a, T>,
+}
+
+#[derive(EventEmitter)]
+struct Bounded<T: Clone + 'static> {
+    #[event]
+    updated: Event<'static, Vec<T>>,
+}
+
+mod renamed {
+    use event_rs as events;
+
+    #[derive(events::EventEmitter)]
+    #[event_rs(crate = events)]
+    pub struct Timer {
+        #[event]
+        pub elapsed: events::Event<'static, u64>,
+    }
+}
+
+#[test]
+fn test_result_type() {
+    let validator = Validator {
+        validating: Event::new(),
+        name: "validator",
+    };
+    let handle = validator.on_validating(|value| !value.is_empty());
+    validator.emit_validating(&String::from("value"));
+    assert_eq!(
+        validator.validating.invoke_collect(&String::new()),
+        vec![false]
+    );
+    assert!(validator.validating.remove(handle));
+}
+
+#[test]
+fn test_lifetime_and_generics() {
+    let log = RefCell::new(vec![]);
+    let observer = Observer {
+        changed: Event::new(),
+    };
+    let handle = observer.on_changed(|value: &i32| log.borrow_mut().push(*value));
+    observer.emit_changed(&1);
+    assert!(observer.changed.remove(handle));
+    observer.emit_changed(&2);
+    assert_eq!(*log.borrow(), vec![1]);
+
+    let total = Rc::new(Cell::new(0));
+    let bounded = Bounded {
+        updated: Event::new(),
+    };
+    let counter = Rc::clone(&total);
+    bounded.on_updated(move |values: &Vec<u8>| counter.set(counter.get() + values.len()));
+    bounded.emit_updated(&vec![1, 2, 3]);
+    assert_eq!(total.get(), 3);
+}
+
+#[test]
+fn test_crate_path() {
+    let seconds = Rc::new(Cell::new(0));
+    let timer = renamed::Timer {
+        elapsed: event_rs::Event::new(),
+    };
+    let counter = Rc::clone(&seconds);
+    timer.on_elapsed(move |value| counter.set(*value));
+    timer.emit_elapsed(&5);
+    assert_eq!(seconds.get(), 5);
+}
+
+#[test]
+fn test_compile_errors() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,9 @@
+use event_rs::EventEmitter;
+
+#[derive(EventEmitter)]
+struct Button {
+    #[event]
+    clicked: Vec<(i32, i32)>,
+}
+
+fn main() {}
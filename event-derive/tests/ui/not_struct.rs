@@ -0,0 +1,8 @@
+use event_rs::EventEmitter;
+
+#[derive(EventEmitter)]
+enum Button {
+    Clicked,
+}
+
+fn main() {}
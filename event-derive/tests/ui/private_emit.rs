@@ -0,0 +1,25 @@
+mod button {
+    use event_rs::{Event, EventEmitter};
+
+    #[derive(Default, EventEmitter)]
+    pub struct Button {
+        #[event]
+        pub clicked: Event<'static, (i32, i32)>,
+        #[event]
+        dropped: Event<'static, ()>,
+    }
+
+    impl Button {
+        pub fn drop_now(&self) {
+            self.emit_dropped(&());
+        }
+    }
+}
+
+fn main() {
+    let button = button::Button::default();
+    button.on_clicked(|_| {});
+    button.emit_clicked(&(1, 2));
+    button.drop_now();
+    button.emit_dropped(&());
+}
@@ -0,0 +1,6 @@
+use event_rs::{Event, EventEmitter};
+
+#[derive(EventEmitter)]
+struct Button(#[event] Event<'static, (i32, i32)>);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use event_rs::{Event, EventEmitter};
+
+#[derive(EventEmitter)]
+#[event_rs(path = event_rs)]
+struct Button {
+    #[event]
+    clicked: Event<'static, (i32, i32)>,
+}
+
+fn main() {}
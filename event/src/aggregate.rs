@@ -0,0 +1,315 @@
+//! Strategies for combining the results of event handlers, see
+//! [`Event::invoke_aggregate`].
+
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+
+use crate::{Event, Storage};
+
+/// A strategy for combining the results of event handlers into one value.
+///
+/// The results are pushed in invocation order. A strategy may stop the
+/// invocation early once it knows its output, like [`Iterator::any`].
+pub trait Aggregate<TResult> {
+    /// The combined value.
+    type Output;
+
+    /// Adds the result of a handler, returning `ControlFlow::Break` to leave the
+    /// remaining handlers uncalled.
+    fn push(&mut self, result: TResult) -> ControlFlow<()>;
+
+    /// Returns the combined value.
+    fn finish(self) -> Self::Output;
+}
+
+/// Keeps the result of the first handler, without calling the others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct First<T>(Option<T>);
+
+impl<T> First<T> {
+    /// Creates the strategy
+    pub fn new() -> Self {
+        Self(None)
+    }
+}
+
+impl<T> Default for First<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Aggregate<T> for First<T> {
+    type Output = Option<T>;
+
+    fn push(&mut self, result: T) -> ControlFlow<()> {
+        self.0 = Some(result);
+        ControlFlow::Break(())
+    }
+
+    fn finish(self) -> Option<T> {
+        self.0
+    }
+}
+
+/// Keeps the result of the last handler, like a C# multicast delegate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Last<T>(Option<T>);
+
+impl<T> Last<T> {
+    /// Creates the strategy
+    pub fn new() -> Self {
+        Self(None)
+    }
+}
+
+impl<T> Default for Last<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Aggregate<T> for Last<T> {
+    type Output = Option<T>;
+
+    fn push(&mut self, result: T) -> ControlFlow<()> {
+        self.0 = Some(result);
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self) -> Option<T> {
+        self.0
+    }
+}
+
+/// Collects the results of all handlers in invocation order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collect<T>(Vec<T>);
+
+impl<T> Collect<T> {
+    /// Creates the strategy
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> Default for Collect<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Aggregate<T> for Collect<T> {
+    type Output = Vec<T>;
+
+    fn push(&mut self, result: T) -> ControlFlow<()> {
+        self.0.push(result);
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self) -> Vec<T> {
+        self.0
+    }
+}
+
+/// Folds the results of all handlers into an accumulator.
+#[derive(Debug, Clone)]
+pub struct Fold<B, F> {
+    accumulator: Option<B>,
+    f: F,
+}
+
+impl<B, F> Fold<B, F> {
+    /// Creates the strategy, which starts from `init` and combines it with each
+    /// result using `f`
+    pub fn new<T>(init: B, f: F) -> Self
+    where
+        F: FnMut(B, T) -> B,
+    {
+        Self {
+            accumulator: Some(init),
+            f,
+        }
+    }
+}
+
+impl<T, B, F> Aggregate<T> for Fold<B, F>
+where
+    F: FnMut(B, T) -> B,
+{
+    type Output = B;
+
+    fn push(&mut self, result: T) -> ControlFlow<()> {
+        self.accumulator = self
+            .accumulator
+            .take()
+            .map(|accumulator| (self.f)(accumulator, result));
+        ControlFlow::Continue(())
+    }
+
+    fn finish(self) -> B {
+        self.accumulator
+            .expect("the accumulator is only taken while a result is pushed")
+    }
+}
+
+/// Returns `true` if any handler returned `true`, without calling the handlers
+/// after it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Any(bool);
+
+impl Any {
+    /// Creates the strategy
+    pub fn new() -> Self {
+        Self(false)
+    }
+}
+
+impl Aggregate<bool> for Any {
+    type Output = bool;
+
+    fn push(&mut self, result: bool) -> ControlFlow<()> {
+        self.0 = result;
+        if result {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    fn finish(self) -> bool {
+        self.0
+    }
+}
+
+/// Returns `true` if every handler returned `true`, without calling the
+/// handlers after one that returned `false`.
+///
+/// An event without handlers yields `true`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct All(bool);
+
+impl All {
+    /// Creates the strategy
+    pub fn new() -> Self {
+        Self(true)
+    }
+}
+
+impl Default for All {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Aggregate<bool> for All {
+    type Output = bool;
+
+    fn push(&mut self, result: bool) -> ControlFlow<()> {
+        self.0 = result;
+        if result {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    }
+
+    fn finish(self) -> bool {
+        self.0
+    }
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> Event<'a, TEventArgs, TResult, TStorage> {
+    /// Invokes event handlers sequentially and combines their results with the
+    /// given strategy.
+    ///
+    /// The strategy may stop the invocation early, e.g. [`First`] only calls the
+    /// first handler and [`Any`] stops at the first handler that returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    /// use event_rs::aggregate::{All, Fold, Last};
+    ///
+    /// let event = Event::<u32, u32>::new();
+    /// event.add(|args| args + 1);
+    /// event.add(|args| args * 2);
+    ///
+    /// assert_eq!(event.invoke_aggregate(&5, Last::new()), Some(10));
+    /// assert_eq!(event.invoke_aggregate(&5, Fold::new(0, |sum, x| sum + x)), 16);
+    ///
+    /// let validate = Event::<&str, bool>::new();
+    /// validate.add(|name| !name.is_empty());
+    /// validate.add(|name| name.len() < 8);
+    ///
+    /// assert!(validate.invoke_aggregate(&"Ada", All::new()));
+    /// assert!(!validate.invoke_aggregate(&"", All::new()));
+    /// ```
+    pub fn invoke_aggregate<A>(&self, arg: &TEventArgs, mut aggregate: A) -> A::Output
+    where
+        A: Aggregate<TResult>,
+    {
+        self.invoke_with(arg, |call| match call() {
+            Some(result) => aggregate.push(result),
+            None => ControlFlow::Continue(()),
+        });
+        aggregate.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_strategies() {
+        let calls = Cell::new(0);
+        let event = Event::<i32, i32>::new();
+        event.add(|arg| {
+            calls.set(calls.get() + 1);
+            *arg
+        });
+        event.add(|arg| {
+            calls.set(calls.get() + 1);
+            arg * 10
+        });
+
+        assert_eq!(event.invoke_aggregate(&1, First::new()), Some(1));
+        assert_eq!(calls.replace(0), 1);
+        assert_eq!(event.invoke_aggregate(&1, Last::new()), Some(10));
+        assert_eq!(event.invoke_aggregate(&1, Collect::new()), vec![1, 10]);
+        assert_eq!(event.invoke_aggregate(&2, Fold::new(1, |p, x| p * x)), 40);
+
+        let empty = Event::<(), i32>::new();
+        assert_eq!(empty.invoke_aggregate(&(), First::new()), None);
+    }
+
+    #[test]
+    fn test_bool_strategies() {
+        let calls = Cell::new(0);
+        let event = Event::<i32, bool>::new();
+        event.add(|arg| {
+            calls.set(calls.get() + 1);
+            *arg > 0
+        });
+        event.add(|arg| {
+            calls.set(calls.get() + 1);
+            *arg > 10
+        });
+
+        assert!(event.invoke_aggregate(&5, Any::new()));
+        assert_eq!(calls.replace(0), 1);
+        assert!(!event.invoke_aggregate(&-5, All::new()));
+        assert_eq!(calls.replace(0), 1);
+        assert!(!event.invoke_aggregate(&5, All::new()));
+        assert!(event.invoke_aggregate(&50, All::new()));
+        assert!(!event.invoke_aggregate(&-5, Any::new()));
+
+        let empty = Event::<(), bool>::new();
+        assert!(empty.invoke_aggregate(&(), All::new()));
+        assert!(!empty.invoke_aggregate(&(), Any::new()));
+    }
+}
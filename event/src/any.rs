@@ -0,0 +1,153 @@
+use alloc::boxed::Box;
+use core::any::{Any, TypeId};
+
+use crate::{Event, HandlerId, Storage};
+
+/// Type alias for type-erased event handlers.
+///
+/// Each handler is a boxed function that takes a reference to the event
+/// arguments as `&dyn Any`.
+pub type AnyEventHandler<'a> = Box<dyn Fn(&dyn Any) + 'a>;
+
+/// A handle to an event handler added through [`AnyEvent`].
+///
+/// Like [`HandlerId`], each handle is unique for the lifetime of the process, but
+/// it is not typed by the event arguments. Any handle can be converted into one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnyHandlerId {
+    key: usize,
+    generation: u64,
+}
+
+impl<TEventArgs> From<HandlerId<TEventArgs>> for AnyHandlerId {
+    fn from(id: HandlerId<TEventArgs>) -> Self {
+        Self {
+            key: id.key(),
+            generation: id.generation(),
+        }
+    }
+}
+
+/// A type-erased event, whose handlers receive the event arguments as `&dyn Any`.
+///
+/// This is object safe, so events with different event arguments can be stored
+/// together, e.g. in a map from `TypeId` to `Box<dyn AnyEvent>`, and invoked with
+/// arguments whose type is only known at runtime. It is implemented for every
+/// [`Event`] whose event arguments are `'static` and whose handlers return `()`.
+///
+/// # Examples
+///
+/// ```
+/// use std::any::TypeId;
+/// use std::collections::HashMap;
+///
+/// use event_rs::{AnyEvent, Event};
+///
+/// let clicked = Event::<(i32, i32)>::new();
+/// clicked.add(|(x, y)| println!("Clicked at ({}, {})", x, y));
+/// let typed = Event::<char>::new();
+/// typed.add(|key| println!("Typed {}", key));
+///
+/// let mut routes: HashMap<TypeId, Box<dyn AnyEvent>> = HashMap::new();
+/// routes.insert(clicked.args_type_id(), Box::new(clicked));
+/// routes.insert(typed.args_type_id(), Box::new(typed));
+///
+/// let message: Box<dyn std::any::Any> = Box::new('a');
+/// let event = &routes[&(*message).type_id()];
+/// assert!(event.invoke_any(&*message)); // Prints "Typed a"
+/// assert!(!event.invoke_any(&1u8)); // Not the event's argument type
+/// ```
+pub trait AnyEvent<'a> {
+    /// Returns the `TypeId` of the event arguments.
+    fn args_type_id(&self) -> TypeId;
+
+    /// Returns the name of the type of the event arguments, for diagnostics.
+    fn args_type_name(&self) -> &'static str;
+
+    /// Adds an event handler that receives the event arguments as `&dyn Any`.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    fn add_any(&self, handler: AnyEventHandler<'a>) -> AnyHandlerId;
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    fn remove_any(&self, handle: AnyHandlerId) -> bool;
+
+    /// Invokes all event handlers sequentially, if `arg` has the type of the event
+    /// arguments.
+    ///
+    /// Returns `false` without invoking the event if it does not.
+    fn invoke_any(&self, arg: &dyn Any) -> bool;
+
+    /// Returns the number of event handlers.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the event has no handlers.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'a, TEventArgs: Any, TStorage: Storage> AnyEvent<'a> for Event<'a, TEventArgs, (), TStorage> {
+    fn args_type_id(&self) -> TypeId {
+        TypeId::of::<TEventArgs>()
+    }
+
+    fn args_type_name(&self) -> &'static str {
+        core::any::type_name::<TEventArgs>()
+    }
+
+    fn add_any(&self, handler: AnyEventHandler<'a>) -> AnyHandlerId {
+        self.add(move |arg| handler(arg)).into()
+    }
+
+    fn remove_any(&self, handle: AnyHandlerId) -> bool {
+        self.remove(HandlerId::new(handle.key, handle.generation))
+    }
+
+    fn invoke_any(&self, arg: &dyn Any) -> bool {
+        match arg.downcast_ref() {
+            Some(arg) => {
+                self.invoke(arg);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn len(&self) -> usize {
+        Event::len(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_erased_handlers() {
+        let log = RefCell::new(vec![]);
+        let typed = Event::<u32>::new();
+        typed.add(|arg| log.borrow_mut().push(format!("typed {}", arg)));
+
+        let events: [&dyn AnyEvent; 2] = [&typed, &Event::<&'static str>::new()];
+        let handle = events[0].add_any(Box::new(|arg| {
+            log.borrow_mut()
+                .push(format!("erased {:?}", arg.downcast_ref::<u32>()))
+        }));
+        assert_eq!(events[0].args_type_name(), "u32");
+        assert_eq!(events[0].len(), 2);
+        assert!(events[1].is_empty());
+
+        assert!(events[0].invoke_any(&1u32));
+        assert!(!events[0].invoke_any(&"1"));
+        assert!(events[0].remove_any(handle));
+        assert!(!events[1].remove_any(handle));
+        assert!(events[0].invoke_any(&2u32));
+
+        assert_eq!(*log.borrow(), vec!["typed 1", "erased Some(1)", "typed 2"]);
+    }
+}
@@ -0,0 +1,103 @@
+use alloc::collections::VecDeque;
+
+/// What to do with an invocation that is queued while the queue is full, see
+/// [`Event::set_backpressure`](crate::Event::set_backpressure), and the
+/// `_with` variants of the bridges to streams and channels.
+///
+/// Telemetry usually wants to drop stale samples, while commands must not be
+/// lost, so the policy is chosen per event.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Backpressure {
+    /// Queues every invocation, without a limit.
+    #[default]
+    Unbounded,
+    /// Holds up the producer once the given number of invocations are queued,
+    /// until there is room again.
+    Block(usize),
+    /// Drops the oldest queued invocation to make room once the given number of
+    /// invocations are queued.
+    DropOldest(usize),
+    /// Drops the new invocation once the given number of invocations are queued.
+    DropNewest(usize),
+    /// Replaces the queued invocations with the new one, so only the latest is
+    /// delivered.
+    Coalesce,
+}
+
+impl Backpressure {
+    /// Queues `arg` according to the policy.
+    ///
+    /// Returns `arg` back if the policy is [`Block`](Self::Block) and the queue
+    /// is full, leaving it to the caller to make room. Dropped invocations are
+    /// not reported.
+    pub(crate) fn push<T>(self, queue: &mut VecDeque<T>, arg: T) -> Result<(), T> {
+        match self {
+            Backpressure::Unbounded => queue.push_back(arg),
+            Backpressure::Block(capacity) => {
+                if queue.len() >= capacity {
+                    return Err(arg);
+                }
+                queue.push_back(arg);
+            }
+            Backpressure::DropOldest(capacity) => {
+                if capacity == 0 {
+                    return Ok(());
+                }
+                while queue.len() >= capacity {
+                    queue.pop_front();
+                }
+                queue.push_back(arg);
+            }
+            Backpressure::DropNewest(capacity) => {
+                if queue.len() < capacity {
+                    queue.push_back(arg);
+                }
+            }
+            Backpressure::Coalesce => {
+                queue.clear();
+                queue.push_back(arg);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_all(policy: Backpressure, args: &[i32]) -> (Vec<i32>, Vec<i32>) {
+        let mut queue = VecDeque::new();
+        let rejected = args
+            .iter()
+            .filter_map(|&arg| policy.push(&mut queue, arg).err())
+            .collect();
+        (queue.into(), rejected)
+    }
+
+    #[test]
+    fn test_policies() {
+        let args = [1, 2, 3, 4];
+        assert_eq!(
+            push_all(Backpressure::Unbounded, &args),
+            (vec![1, 2, 3, 4], vec![])
+        );
+        assert_eq!(
+            push_all(Backpressure::Block(2), &args),
+            (vec![1, 2], vec![3, 4])
+        );
+        assert_eq!(
+            push_all(Backpressure::DropOldest(2), &args),
+            (vec![3, 4], vec![])
+        );
+        assert_eq!(
+            push_all(Backpressure::DropNewest(2), &args),
+            (vec![1, 2], vec![])
+        );
+        assert_eq!(push_all(Backpressure::Coalesce, &args), (vec![4], vec![]));
+        assert_eq!(
+            push_all(Backpressure::DropOldest(0), &args),
+            (vec![], vec![])
+        );
+    }
+}
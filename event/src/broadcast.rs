@@ -0,0 +1,163 @@
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::sync::watch;
+
+use crate::Event;
+use crate::handlers::Handler;
+
+impl<'a, TEventArgs: Clone + 'a> Event<'a, TEventArgs> {
+    /// Returns a Tokio broadcast receiver that receives a clone of the event
+    /// arguments each time the event is invoked.
+    ///
+    /// Further receivers can be created with [`resubscribe`](broadcast::Receiver::resubscribe),
+    /// so the event can fan out into any number of tasks. The channel keeps the
+    /// last `capacity` values for slow receivers, see [`broadcast::channel`].
+    /// Once all receivers are dropped, the handler is removed from the event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// let mut receiver = event.to_broadcast(16);
+    ///
+    /// event.invoke(&1);
+    /// assert_eq!(receiver.try_recv(), Ok(1));
+    /// ```
+    pub fn to_broadcast(&self, capacity: usize) -> broadcast::Receiver<TEventArgs> {
+        let (sender, receiver) = broadcast::channel(capacity);
+        self.insert(Handler::guarded(move |arg: &TEventArgs, expired| {
+            let sent = sender.send(arg.clone()).is_ok();
+            expired.set(!sent);
+            sent.then_some(())
+        }));
+        receiver
+    }
+
+    /// Returns a Tokio watch receiver that holds the event arguments of the latest
+    /// invocation, starting with `initial`.
+    ///
+    /// Async tasks can await [`changed`](watch::Receiver::changed) instead of
+    /// registering a handler, and any number of them can share the value through
+    /// clones of the receiver. Unlike [`to_broadcast`](Self::to_broadcast), slow
+    /// receivers only ever see the latest value. Once all receivers are dropped,
+    /// the handler is removed from the event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// let receiver = event.to_watch(0);
+    /// assert_eq!(*receiver.borrow(), 0);
+    ///
+    /// event.invoke(&1);
+    /// event.invoke(&2);
+    /// assert!(receiver.has_changed().unwrap());
+    /// assert_eq!(*receiver.borrow(), 2);
+    /// ```
+    pub fn to_watch(&self, initial: TEventArgs) -> watch::Receiver<TEventArgs> {
+        let (sender, receiver) = watch::channel(initial);
+        self.insert(Handler::guarded(move |arg: &TEventArgs, expired| {
+            let sent = sender.send(arg.clone()).is_ok();
+            expired.set(!sent);
+            sent.then_some(())
+        }));
+        receiver
+    }
+
+    /// Invokes the event with each value received from a Tokio broadcast receiver,
+    /// until the channel is closed.
+    ///
+    /// Values the receiver missed because it lagged behind are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tokio::sync::broadcast;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.add(|value| println!("Received {}", value));
+    ///
+    /// let (sender, receiver) = broadcast::channel(16);
+    /// sender.send(1).unwrap();
+    /// drop(sender);
+    ///
+    /// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// # runtime.block_on(async {
+    /// event.drain_broadcast(receiver).await; // Prints "Received 1"
+    /// # });
+    /// ```
+    pub async fn drain_broadcast(&self, mut receiver: broadcast::Receiver<TEventArgs>) {
+        loop {
+            match receiver.recv().await {
+                Ok(arg) => self.invoke(&arg),
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_to_broadcast() {
+        let event = Event::new();
+        let mut first = event.to_broadcast(4);
+        let mut second = first.resubscribe();
+
+        event.invoke(&1);
+        assert_eq!(first.try_recv(), Ok(1));
+        assert_eq!(second.try_recv(), Ok(1));
+
+        drop((first, second));
+        event.invoke(&2);
+        assert!(event.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_to_watch() {
+        let event = Event::new();
+        let mut receiver = event.to_watch(0);
+        let other = receiver.clone();
+
+        event.invoke(&1);
+        event.invoke(&2);
+        receiver.changed().await.unwrap();
+        assert_eq!(*receiver.borrow_and_update(), 2);
+        assert!(!receiver.has_changed().unwrap());
+        assert_eq!(*other.borrow(), 2);
+
+        drop((receiver, other));
+        event.invoke(&3);
+        assert!(event.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_drain_broadcast() {
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+        event.add(|value: &i32| log.borrow_mut().push(*value));
+
+        let (sender, receiver) = broadcast::channel(2);
+        for value in 0..4 {
+            sender.send(value).unwrap();
+        }
+        drop(sender);
+
+        event.drain_broadcast(receiver).await;
+        assert_eq!(*log.borrow(), vec![2, 3]);
+    }
+}
@@ -0,0 +1,203 @@
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::{Event, HandlerId};
+
+/// A central event aggregator that routes events by their type.
+///
+/// Each event type `T` gets its own [`Event<T>`](Event), created on first use.
+/// Subscribers and publishers only need to agree on the type, not on a shared
+/// `Event` instance.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::EventBus;
+///
+/// struct UserCreated {
+///     name: String,
+/// }
+///
+/// let bus = EventBus::new();
+/// bus.subscribe::<UserCreated>(|args| {
+///     println!("Welcome, {}!", args.name);
+/// });
+///
+/// bus.publish(UserCreated { name: "Alice".into() });
+/// ```
+#[derive(Default)]
+pub struct EventBus {
+    events: RefCell<HashMap<TypeId, Rc<dyn Any>>>,
+}
+
+impl EventBus {
+    /// Creates a new, empty EventBus
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventBus;
+    ///
+    /// let bus = EventBus::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an event handler for events of type `T`.
+    ///
+    /// Returns a handle that can be used to remove the handler later
+    /// with [`unsubscribe`](Self::unsubscribe).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventBus;
+    ///
+    /// let bus = EventBus::new();
+    /// let handle = bus.subscribe::<u32>(|args| {
+    ///     println!("Received {}", args);
+    /// });
+    /// ```
+    pub fn subscribe<T: 'static>(&self, handler: impl Fn(&T) + 'static) -> HandlerId<T> {
+        self.event::<T>().add(handler)
+    }
+
+    /// Removes an event handler for events of type `T` using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventBus;
+    ///
+    /// let bus = EventBus::new();
+    /// let handle = bus.subscribe::<u32>(|args| {
+    ///     println!("Received {}", args);
+    /// });
+    ///
+    /// assert!(bus.unsubscribe::<u32>(handle));
+    /// assert!(!bus.unsubscribe::<u32>(handle)); // Already removed
+    /// ```
+    pub fn unsubscribe<T: 'static>(&self, handle: HandlerId<T>) -> bool {
+        self.existing::<T>()
+            .is_some_and(|event| event.remove(handle))
+    }
+
+    /// Invokes all handlers subscribed to events of type `T`.
+    ///
+    /// Handlers may subscribe or publish other event types while running.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventBus;
+    ///
+    /// let bus = EventBus::new();
+    /// bus.subscribe::<u32>(|args| assert_eq!(*args, 42));
+    ///
+    /// bus.publish(42u32);
+    /// bus.publish("nobody listens"); // No handlers, nothing happens
+    /// ```
+    pub fn publish<T: 'static>(&self, event: T) {
+        if let Some(handlers) = self.existing::<T>() {
+            handlers.invoke(&event);
+        }
+    }
+
+    /// Removes all event handlers of every type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventBus;
+    ///
+    /// let bus = EventBus::new();
+    /// bus.subscribe::<u32>(|args| println!("Received {}", args));
+    ///
+    /// bus.clear(); // Remove all handlers
+    /// ```
+    pub fn clear(&self) {
+        self.events.borrow_mut().clear();
+    }
+
+    fn event<T: 'static>(&self) -> Rc<Event<'static, T>> {
+        let event = Rc::clone(
+            self.events
+                .borrow_mut()
+                .entry(TypeId::of::<T>())
+                .or_insert_with(|| Rc::new(Event::<T>::new())),
+        );
+        Self::downcast(event)
+    }
+
+    fn existing<T: 'static>(&self) -> Option<Rc<Event<'static, T>>> {
+        let event = Rc::clone(self.events.borrow().get(&TypeId::of::<T>())?);
+        Some(Self::downcast(event))
+    }
+
+    fn downcast<T: 'static>(event: Rc<dyn Any>) -> Rc<Event<'static, T>> {
+        event
+            .downcast()
+            .unwrap_or_else(|_| unreachable!("events are keyed by the TypeId of their args"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_publish_routes_by_type() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let bus = EventBus::new();
+
+        let numbers = Rc::clone(&log);
+        bus.subscribe::<i32>(move |arg| numbers.borrow_mut().push(arg.to_string()));
+        let strings = Rc::clone(&log);
+        bus.subscribe::<&str>(move |arg| strings.borrow_mut().push(arg.to_string()));
+
+        bus.publish(1);
+        bus.publish("two");
+        bus.publish(3.0);
+
+        assert_eq!(*log.borrow(), vec!["1", "two"]);
+    }
+
+    #[test]
+    fn test_subscribe_while_publishing() {
+        let counter = Rc::new(RefCell::new(0));
+        let bus = Rc::new(EventBus::new());
+
+        let weak = Rc::downgrade(&bus);
+        let counter_clone = Rc::clone(&counter);
+        bus.subscribe::<()>(move |_| {
+            let counter = Rc::clone(&counter_clone);
+            let bus = weak.upgrade().unwrap();
+            bus.subscribe::<u8>(move |_| *counter.borrow_mut() += 1);
+            bus.publish(0u8);
+        });
+
+        bus.publish(());
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let counter = Rc::new(RefCell::new(0));
+        let bus = EventBus::new();
+
+        let counter_clone = Rc::clone(&counter);
+        let handle = bus.subscribe::<()>(move |_| *counter_clone.borrow_mut() += 1);
+
+        assert!(bus.unsubscribe(handle));
+
+        bus.publish(());
+        assert_eq!(*counter.borrow(), 0);
+    }
+}
@@ -0,0 +1,179 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::EventMutArgs;
+
+/// Event arguments that handlers can cancel, like C#'s `CancelEventArgs`.
+///
+/// The invoker of an [`EventMutArgs`] checks [`is_canceled`](Self::is_canceled)
+/// after the invocation to honor the veto, e.g. to keep a window open when a
+/// handler canceled the "window closing" event. The wrapper dereferences to the
+/// wrapped event arguments.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{Cancelable, EventMutArgs};
+///
+/// struct WindowClosing {
+///     unsaved_changes: bool,
+/// }
+///
+/// let closing = EventMutArgs::<Cancelable<WindowClosing>>::new();
+/// closing.add(|args| {
+///     if args.unsaved_changes {
+///         args.cancel();
+///     }
+/// });
+///
+/// let mut args = Cancelable::new(WindowClosing { unsaved_changes: true });
+/// closing.invoke_mut(&mut args);
+/// assert!(args.is_canceled()); // Keep the window open
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Cancelable<TEventArgs> {
+    args: TEventArgs,
+    canceled: bool,
+}
+
+impl<TEventArgs> Cancelable<TEventArgs> {
+    /// Wraps the event arguments, which are not canceled yet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Cancelable;
+    ///
+    /// let args = Cancelable::new("report.txt");
+    /// assert!(!args.is_canceled());
+    /// ```
+    pub const fn new(args: TEventArgs) -> Self {
+        Self {
+            args,
+            canceled: false,
+        }
+    }
+
+    /// Cancels the operation the event announces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Cancelable;
+    ///
+    /// let mut args = Cancelable::new("report.txt");
+    /// args.cancel();
+    /// assert!(args.is_canceled());
+    /// ```
+    pub fn cancel(&mut self) {
+        self.canceled = true;
+    }
+
+    /// Returns `true` if a handler canceled the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Cancelable;
+    ///
+    /// let args = Cancelable::new("report.txt");
+    /// assert!(!args.is_canceled());
+    /// ```
+    pub const fn is_canceled(&self) -> bool {
+        self.canceled
+    }
+
+    /// Returns the wrapped event arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Cancelable;
+    ///
+    /// let args = Cancelable::new("report.txt");
+    /// assert_eq!(args.into_inner(), "report.txt");
+    /// ```
+    pub fn into_inner(self) -> TEventArgs {
+        self.args
+    }
+}
+
+impl<TEventArgs> Deref for Cancelable<TEventArgs> {
+    type Target = TEventArgs;
+
+    fn deref(&self) -> &TEventArgs {
+        &self.args
+    }
+}
+
+impl<TEventArgs> DerefMut for Cancelable<TEventArgs> {
+    fn deref_mut(&mut self) -> &mut TEventArgs {
+        &mut self.args
+    }
+}
+
+impl<'a, TEventArgs> EventMutArgs<'a, Cancelable<TEventArgs>> {
+    /// Invokes the event handlers sequentially until one of them cancels the
+    /// event arguments, leaving the remaining handlers uncalled.
+    ///
+    /// Returns `true` if the event arguments are canceled. Use
+    /// [`invoke_mut`](Self::invoke_mut) to call every handler regardless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Cancelable, EventMutArgs};
+    ///
+    /// let deleting = EventMutArgs::<Cancelable<&str>>::new();
+    /// deleting.add(|file| {
+    ///     if file.ends_with(".lock") {
+    ///         file.cancel();
+    ///     }
+    /// });
+    /// deleting.add(|file| println!("Deleting {}", **file));
+    ///
+    /// assert!(deleting.invoke_cancelable(&mut Cancelable::new("db.lock"))); // Prints nothing
+    /// assert!(!deleting.invoke_cancelable(&mut Cancelable::new("notes.txt")));
+    /// ```
+    pub fn invoke_cancelable(&self, arg: &mut Cancelable<TEventArgs>) -> bool {
+        self.invoke_mut_while(arg, |arg| !arg.is_canceled());
+        arg.is_canceled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_invoke_cancelable() {
+        let calls = Cell::new(0);
+        let event = EventMutArgs::new();
+        event.add(|args: &mut Cancelable<i32>| {
+            calls.set(calls.get() + 1);
+            if **args < 0 {
+                args.cancel();
+            }
+        });
+        event.add(|args| {
+            calls.set(calls.get() + 1);
+            **args *= 10;
+        });
+
+        let mut args = Cancelable::new(1);
+        assert!(!event.invoke_cancelable(&mut args));
+        assert_eq!(args.into_inner(), 10);
+        assert_eq!(calls.replace(0), 2);
+
+        let mut args = Cancelable::new(-1);
+        assert!(event.invoke_cancelable(&mut args));
+        assert_eq!(*args, -1);
+        assert_eq!(calls.replace(0), 1);
+
+        let mut args = Cancelable::new(-1);
+        event.invoke_mut(&mut args);
+        assert!(args.is_canceled());
+        assert_eq!(calls.replace(0), 2);
+    }
+}
@@ -0,0 +1,649 @@
+use alloc::collections::VecDeque;
+use std::sync::mpsc::{self, Receiver, RecvError, RecvTimeoutError, TryRecvError};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use crate::handlers::Handler;
+use crate::{Backpressure, Event, EventError, Storage};
+
+/// A queue between threads, whose length is limited by a [`Backpressure`] policy.
+pub(crate) struct SharedQueue<T> {
+    state: Mutex<QueueState<T>>,
+    changed: Condvar,
+}
+
+struct QueueState<T> {
+    queue: VecDeque<T>,
+    policy: Backpressure,
+    closed: bool,
+    /// The number of values taken from the queue so far.
+    taken: usize,
+}
+
+impl<T> SharedQueue<T> {
+    pub(crate) fn new(policy: Backpressure) -> Self {
+        Self {
+            state: Mutex::new(QueueState {
+                queue: VecDeque::new(),
+                policy,
+                closed: false,
+                taken: 0,
+            }),
+            changed: Condvar::new(),
+        }
+    }
+
+    pub(crate) fn set_policy(&self, policy: Backpressure) {
+        self.lock().policy = policy;
+        self.changed.notify_all();
+    }
+
+    /// Queues `arg` according to the policy, waiting for room under
+    /// [`Backpressure::Block`].
+    ///
+    /// With a capacity of 0, `arg` is handed off directly: the call waits
+    /// until a receiver takes it.
+    ///
+    /// Returns `arg` back if the queue is closed.
+    pub(crate) fn send(&self, mut arg: T) -> Result<(), T> {
+        let mut state = self.lock();
+        let rendezvous = loop {
+            if state.closed {
+                return Err(arg);
+            }
+            let (policy, rendezvous) = match state.policy {
+                Backpressure::Block(0) => (Backpressure::Block(1), true),
+                policy => (policy, false),
+            };
+            match policy.push(&mut state.queue, arg) {
+                Ok(()) => break rendezvous,
+                Err(rejected) => {
+                    arg = rejected;
+                    state = self
+                        .changed
+                        .wait(state)
+                        .unwrap_or_else(PoisonError::into_inner);
+                }
+            }
+        };
+        self.changed.notify_all();
+        if rendezvous {
+            let position = state.taken + state.queue.len();
+            state = self
+                .changed
+                .wait_while(state, |state| state.taken < position && !state.closed)
+                .unwrap_or_else(PoisonError::into_inner);
+            if state.taken < position {
+                let queued = position - 1 - state.taken;
+                return Err(state
+                    .queue
+                    .remove(queued)
+                    .expect("the value is still queued"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Takes the oldest queued value, waiting up to `timeout` for one, or for
+    /// ever if `timeout` is `None`.
+    pub(crate) fn recv(&self, timeout: Option<Duration>) -> Result<T, RecvTimeoutError> {
+        let state = self.lock();
+        let waiting = |state: &mut QueueState<T>| state.queue.is_empty() && !state.closed;
+        let mut state = match timeout {
+            Some(timeout) => {
+                self.changed
+                    .wait_timeout_while(state, timeout, waiting)
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .0
+            }
+            None => self
+                .changed
+                .wait_while(state, waiting)
+                .unwrap_or_else(PoisonError::into_inner),
+        };
+        let arg = state.queue.pop_front();
+        state.taken += usize::from(arg.is_some());
+        let closed = state.closed;
+        drop(state);
+        match arg {
+            Some(arg) => {
+                self.changed.notify_all();
+                Ok(arg)
+            }
+            None if closed => Err(RecvTimeoutError::Disconnected),
+            None => Err(RecvTimeoutError::Timeout),
+        }
+    }
+
+    /// Takes all queued values.
+    pub(crate) fn take(&self) -> VecDeque<T> {
+        let mut state = self.lock();
+        let queue = core::mem::take(&mut state.queue);
+        state.taken += queue.len();
+        drop(state);
+        self.changed.notify_all();
+        queue
+    }
+
+    fn close(&self) {
+        self.lock().closed = true;
+        self.changed.notify_all();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, QueueState<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// The owning side of a [`SharedQueue`], which closes the queue when dropped.
+pub(crate) struct QueueGuard<T>(pub(crate) Arc<SharedQueue<T>>);
+
+impl<T> Drop for QueueGuard<T> {
+    fn drop(&mut self) {
+        self.0.close();
+    }
+}
+
+impl<'a, TEventArgs: Clone + 'a> Event<'a, TEventArgs> {
+    /// Returns a channel receiver that receives a clone of the event arguments
+    /// each time the event is invoked.
+    ///
+    /// This moves invocations to another thread without an async runtime. Once
+    /// the receiver is dropped, the handler is removed from the event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// let receiver = event.channel();
+    ///
+    /// let worker = thread::spawn(move || receiver.iter().sum::<u32>());
+    /// event.invoke(&1);
+    /// event.invoke(&2);
+    /// drop(event);
+    ///
+    /// assert_eq!(worker.join().unwrap(), 3);
+    /// ```
+    pub fn channel(&self) -> Receiver<TEventArgs> {
+        let (sender, receiver) = mpsc::channel();
+        self.insert(Handler::guarded(move |arg: &TEventArgs, expired| {
+            let sent = sender.send(arg.clone()).is_ok();
+            expired.set(!sent);
+            sent.then_some(())
+        }));
+        receiver
+    }
+
+    /// Returns a receiver like [`channel`](Self::channel), whose queue of
+    /// invocations waiting to be received follows the given policy.
+    ///
+    /// With [`Backpressure::Block`], invoking the event waits until the
+    /// receiver makes room, and with a capacity of 0 until the receiver has
+    /// taken the value. Once the receiver is dropped, the handler is removed
+    /// from the event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Backpressure, Event};
+    ///
+    /// let event = Event::<u32>::new();
+    /// let receiver = event.channel_with(Backpressure::DropOldest(2));
+    ///
+    /// event.invoke(&1); // Dropped
+    /// event.invoke(&2);
+    /// event.invoke(&3);
+    /// assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![2, 3]);
+    /// ```
+    pub fn channel_with(&self, policy: Backpressure) -> EventReceiver<TEventArgs> {
+        let queue = Arc::new(SharedQueue::new(policy));
+        let sender = QueueGuard(Arc::clone(&queue));
+        self.insert(Handler::guarded(move |arg: &TEventArgs, expired| {
+            let sent = sender.0.send(arg.clone()).is_ok();
+            expired.set(!sent);
+            sent.then_some(())
+        }));
+        EventReceiver { queue }
+    }
+}
+
+/// The receiving side of [`Event::channel_with`].
+///
+/// It works like a [`Receiver`] from `std`, and can be sent to another thread.
+/// Receiving fails once the event is dropped, or the handler is removed, and
+/// all queued values have been received.
+pub struct EventReceiver<TEventArgs> {
+    queue: Arc<SharedQueue<TEventArgs>>,
+}
+
+impl<TEventArgs> Drop for EventReceiver<TEventArgs> {
+    fn drop(&mut self) {
+        self.queue.close();
+    }
+}
+
+impl<TEventArgs> EventReceiver<TEventArgs> {
+    /// Blocks the current thread until a value is received.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    ///
+    /// use event_rs::{Backpressure, Event};
+    ///
+    /// let event = Event::<u32>::new();
+    /// let receiver = event.channel_with(Backpressure::Block(1));
+    ///
+    /// let worker = thread::spawn(move || receiver.recv());
+    /// event.invoke(&1);
+    /// assert_eq!(worker.join().unwrap(), Ok(1));
+    /// ```
+    pub fn recv(&self) -> Result<TEventArgs, RecvError> {
+        self.queue.recv(None).map_err(|_| RecvError)
+    }
+
+    /// Receives a value if one is waiting, without blocking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::mpsc::TryRecvError;
+    ///
+    /// use event_rs::{Backpressure, Event};
+    ///
+    /// let event = Event::<u32>::new();
+    /// let receiver = event.channel_with(Backpressure::Coalesce);
+    /// assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+    ///
+    /// event.invoke(&1);
+    /// assert_eq!(receiver.try_recv(), Ok(1));
+    /// ```
+    pub fn try_recv(&self) -> Result<TEventArgs, TryRecvError> {
+        self.queue
+            .recv(Some(Duration::ZERO))
+            .map_err(|error| match error {
+                RecvTimeoutError::Timeout => TryRecvError::Empty,
+                RecvTimeoutError::Disconnected => TryRecvError::Disconnected,
+            })
+    }
+
+    /// Blocks the current thread until a value is received, or `timeout`
+    /// elapses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::mpsc::RecvTimeoutError;
+    /// use std::time::Duration;
+    ///
+    /// use event_rs::{Backpressure, Event};
+    ///
+    /// let event = Event::<u32>::new();
+    /// let receiver = event.channel_with(Backpressure::DropNewest(8));
+    ///
+    /// let timeout = Duration::from_millis(10);
+    /// assert_eq!(receiver.recv_timeout(timeout), Err(RecvTimeoutError::Timeout));
+    /// ```
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<TEventArgs, RecvTimeoutError> {
+        self.queue.recv(Some(timeout))
+    }
+
+    /// Returns an iterator that blocks waiting for values, and ends once
+    /// receiving fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Backpressure, Event};
+    ///
+    /// let event = Event::<u32>::new();
+    /// let receiver = event.channel_with(Backpressure::Unbounded);
+    ///
+    /// event.invoke(&1);
+    /// event.invoke(&2);
+    /// drop(event);
+    /// assert_eq!(receiver.iter().sum::<u32>(), 3);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = TEventArgs> + '_ {
+        core::iter::from_fn(|| self.recv().ok())
+    }
+
+    /// Returns an iterator over the values waiting to be received, without
+    /// blocking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Backpressure, Event};
+    ///
+    /// let event = Event::<u32>::new();
+    /// let receiver = event.channel_with(Backpressure::Unbounded);
+    ///
+    /// event.invoke(&1);
+    /// assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![1]);
+    /// ```
+    pub fn try_iter(&self) -> impl Iterator<Item = TEventArgs> + '_ {
+        core::iter::from_fn(|| self.try_recv().ok())
+    }
+}
+
+impl<'a, TEventArgs> Event<'a, TEventArgs> {
+    /// Invokes the event once for each value waiting in a channel receiver,
+    /// without blocking.
+    ///
+    /// Like [`pump`](Self::pump), this is meant to be called from the owner's
+    /// loop to deliver values sent from other threads.
+    ///
+    /// Returns the number of invocations delivered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    /// use std::thread;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.add(|value| println!("Received {}", value));
+    ///
+    /// let (sender, receiver) = mpsc::channel();
+    /// thread::spawn(move || sender.send(1).unwrap()).join().unwrap();
+    ///
+    /// assert_eq!(event.drain_from(&receiver), 1);
+    /// assert_eq!(event.drain_from(&receiver), 0); // Nothing left to deliver
+    /// ```
+    pub fn drain_from(&self, receiver: &Receiver<TEventArgs>) -> usize {
+        receiver.try_iter().map(|arg| self.invoke(&arg)).count()
+    }
+}
+
+impl<'a, TEventArgs: Send, TResult, TStorage: Storage> Event<'a, TEventArgs, TResult, TStorage> {
+    /// Returns a handle that other threads can use to raise the event.
+    ///
+    /// The event itself stays on the thread that owns it. Invocations raised
+    /// through the handle are queued, and the owner delivers them on its next
+    /// call to [`pump`](Self::pump), like a GUI event loop proxy. The queue
+    /// follows the policy set with [`set_backpressure`](Self::set_backpressure),
+    /// so with [`Backpressure::Block`] raising waits until the owner pumps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.add(|progress| println!("Progress {}", progress));
+    ///
+    /// let raiser = event.raiser();
+    /// thread::spawn(move || raiser.raise(50)).join().unwrap();
+    ///
+    /// assert_eq!(event.pump(), 1); // Prints "Progress 50"
+    /// ```
+    pub fn raiser(&self) -> EventRaiser<TEventArgs> {
+        let raised = self
+            .raised
+            .get_or_init(|| QueueGuard(Arc::new(SharedQueue::new(self.backpressure.get()))));
+        EventRaiser {
+            queue: Arc::clone(&raised.0),
+        }
+    }
+}
+
+/// A handle for raising an [`Event`] from other threads.
+///
+/// Created by [`Event::raiser`]. The handle can be cloned and sent to any number
+/// of threads, and the invocations it raises are delivered by [`Event::pump`] on
+/// the thread that owns the event.
+pub struct EventRaiser<TEventArgs> {
+    queue: Arc<SharedQueue<TEventArgs>>,
+}
+
+impl<TEventArgs> Clone for EventRaiser<TEventArgs> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: Arc::clone(&self.queue),
+        }
+    }
+}
+
+impl<TEventArgs> EventRaiser<TEventArgs> {
+    /// Queues an invocation of the event with `arg`.
+    ///
+    /// Returns `false` if the event has been dropped, in which case `arg` is
+    /// dropped too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// let raiser = event.raiser();
+    /// assert!(raiser.raise(1));
+    ///
+    /// drop(event);
+    /// assert!(!raiser.raise(2));
+    /// ```
+    pub fn raise(&self, arg: TEventArgs) -> bool {
+        self.queue.send(arg).is_ok()
+    }
+
+    /// Queues an invocation of the event with `arg`.
+    ///
+    /// Returns [`EventError::EventClosed`] if the event has been dropped, in
+    /// which case `arg` is dropped too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Event, EventError};
+    ///
+    /// let event = Event::<u32>::new();
+    /// let raiser = event.raiser();
+    /// drop(event);
+    ///
+    /// assert!(matches!(raiser.try_raise(1), Err(EventError::EventClosed)));
+    /// ```
+    pub fn try_raise(&self, arg: TEventArgs) -> Result<(), EventError> {
+        self.queue.send(arg).map_err(|_| EventError::EventClosed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::sync::mpsc::TryRecvError;
+
+    use super::*;
+
+    #[test]
+    fn test_channel() {
+        let event = Event::new();
+        let receiver = event.channel();
+
+        event.invoke(&1);
+        event.invoke(&2);
+        assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![1, 2]);
+
+        drop(receiver);
+        event.invoke(&3);
+        assert!(event.is_empty());
+    }
+
+    #[test]
+    fn test_channel_closes_with_event() {
+        let event = Event::<()>::new();
+        let receiver = event.channel();
+
+        drop(event);
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+    }
+
+    #[test]
+    fn test_channel_with() {
+        let event = Event::new();
+        let oldest = event.channel_with(Backpressure::DropOldest(2));
+        let newest = event.channel_with(Backpressure::DropNewest(2));
+        let latest = event.channel_with(Backpressure::Coalesce);
+
+        for arg in 1..=3 {
+            event.invoke(&arg);
+        }
+        assert_eq!(oldest.try_iter().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(newest.try_iter().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(latest.try_recv(), Ok(3));
+
+        drop((oldest, newest));
+        event.invoke(&4);
+        assert_eq!(event.len(), 1);
+        drop(event);
+        assert_eq!(latest.try_recv(), Ok(4));
+        assert_eq!(latest.try_recv(), Err(TryRecvError::Disconnected));
+    }
+
+    #[test]
+    fn test_channel_with_block() {
+        use std::thread;
+
+        let event = Event::new();
+        let receiver = event.channel_with(Backpressure::Block(1));
+        let worker = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            receiver.iter().collect::<Vec<_>>()
+        });
+
+        // The second invocation waits until the worker receives the first
+        event.invoke(&1);
+        event.invoke(&2);
+        drop(event);
+        assert_eq!(worker.join().unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_channel_with_rendezvous() {
+        use std::thread;
+
+        let event = Event::new();
+        let receiver = event.channel_with(Backpressure::Block(0));
+        let worker = thread::spawn(move || {
+            let first = receiver.recv().unwrap();
+            thread::sleep(Duration::from_millis(20));
+            // The second invocation is still waiting for this receive
+            let second = receiver.recv().unwrap();
+            (first, second)
+        });
+
+        event.invoke(&1);
+        event.invoke(&2);
+        assert_eq!(worker.join().unwrap(), (1, 2));
+
+        // Without a receiver, the invocation is rejected instead of waiting
+        event.invoke(&3);
+        assert_eq!(event.len(), 0);
+    }
+
+    #[test]
+    fn test_drain_from() {
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+        event.add(|value: &i32| log.borrow_mut().push(*value));
+
+        let (sender, receiver) = mpsc::channel();
+        sender.send(1).unwrap();
+        sender.send(2).unwrap();
+
+        assert_eq!(event.drain_from(&receiver), 2);
+        assert_eq!(event.drain_from(&receiver), 0);
+        assert_eq!(*log.borrow(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_raiser_backpressure() {
+        use std::thread;
+
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+        event.add(|value: &i32| log.borrow_mut().push(*value));
+        event.set_backpressure(Backpressure::DropOldest(2));
+
+        let raiser = event.raiser();
+        for value in 1..=3 {
+            assert!(raiser.raise(value));
+        }
+        assert_eq!(event.pump(), 2);
+        assert_eq!(*log.borrow(), vec![2, 3]);
+
+        event.set_backpressure(Backpressure::Block(1));
+        let worker = thread::spawn(move || {
+            for value in 4..=6 {
+                assert!(raiser.raise(value));
+            }
+            raiser
+        });
+        while !worker.is_finished() {
+            event.pump();
+            thread::yield_now();
+        }
+        let raiser = worker.join().unwrap();
+        event.pump();
+
+        // Raising waits until the invocation is pumped
+        event.set_backpressure(Backpressure::Block(0));
+        let worker = thread::spawn(move || {
+            assert!(raiser.raise(7));
+            raiser
+        });
+        while !worker.is_finished() {
+            event.pump();
+            thread::yield_now();
+        }
+        let raiser = worker.join().unwrap();
+        assert_eq!(log.borrow().last(), Some(&7));
+
+        // The queue is full, so raising 9 waits until the event is dropped
+        event.set_backpressure(Backpressure::Block(1));
+        assert!(raiser.raise(8));
+        let worker = thread::spawn(move || raiser.raise(9));
+        thread::sleep(Duration::from_millis(20));
+        drop(event);
+        assert!(!worker.join().unwrap());
+        assert_eq!(*log.borrow(), vec![2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_raiser() {
+        use std::thread;
+
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+        event.add(|value: &i32| log.borrow_mut().push(*value));
+        event.post(0);
+
+        let raiser = event.raiser();
+        let workers: Vec<_> = (1..=2)
+            .map(|value| {
+                let raiser = raiser.clone();
+                thread::spawn(move || assert!(raiser.raise(value)))
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+        assert!(log.borrow().is_empty());
+
+        assert_eq!(event.pump(), 3);
+        assert_eq!(event.pump(), 0);
+        let mut log = log.take();
+        assert_eq!(log[0], 0);
+        log.sort();
+        assert_eq!(log, vec![0, 1, 2]);
+    }
+}
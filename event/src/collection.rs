@@ -0,0 +1,384 @@
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+use crate::Event;
+
+/// Event arguments of [`ObservableVec::changed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CollectionChanged<T> {
+    /// `value` was inserted at `index`.
+    Inserted {
+        /// The index of the new element.
+        index: usize,
+        /// The new element.
+        value: T,
+    },
+    /// `value` was removed from `index`.
+    Removed {
+        /// The index the element was removed from.
+        index: usize,
+        /// The removed element.
+        value: T,
+    },
+    /// The element at `index` was replaced.
+    Replaced {
+        /// The index of the replaced element.
+        index: usize,
+        /// The element before the change.
+        old: T,
+        /// The element after the change.
+        new: T,
+    },
+    /// All elements were removed.
+    Cleared,
+}
+
+/// A vector that raises an event whenever its elements change, like C#'s
+/// `ObservableCollection`.
+///
+/// Handlers run after the change has been made, and may read or modify the
+/// vector.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{CollectionChanged, ObservableVec};
+///
+/// let items = ObservableVec::new();
+/// items.changed().add(|change| match change {
+///     CollectionChanged::Inserted { index, value } => println!("Inserted {} at {}", value, index),
+///     CollectionChanged::Removed { index, value } => println!("Removed {} from {}", value, index),
+///     CollectionChanged::Replaced { index, new, .. } => println!("Replaced {} with {}", index, new),
+///     CollectionChanged::Cleared => println!("Cleared"),
+/// });
+///
+/// items.push("apple"); // Prints "Inserted apple at 0"
+/// items.set(0, "pear"); // Prints "Replaced 0 with pear"
+/// items.clear(); // Prints "Cleared"
+/// ```
+pub struct ObservableVec<'a, T> {
+    items: RefCell<Vec<T>>,
+    changed: Event<'a, CollectionChanged<T>>,
+}
+
+impl<'a, T> Default for ObservableVec<'a, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> ObservableVec<'a, T> {
+    /// Creates a new, empty ObservableVec
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableVec;
+    ///
+    /// let items: ObservableVec<u32> = ObservableVec::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            items: RefCell::new(Vec::new()),
+            changed: Event::new(),
+        }
+    }
+
+    /// Returns the event raised after each change to the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableVec;
+    ///
+    /// let items = ObservableVec::<u32>::new();
+    /// items.changed().add(|change| println!("{:?}", change));
+    /// ```
+    pub fn changed(&self) -> &Event<'a, CollectionChanged<T>> {
+        &self.changed
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableVec;
+    ///
+    /// let items = ObservableVec::new();
+    /// items.push(1);
+    ///
+    /// assert_eq!(items.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.items.borrow().len()
+    }
+
+    /// Returns `true` if there are no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableVec;
+    ///
+    /// let items: ObservableVec<u32> = ObservableVec::new();
+    /// assert!(items.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a copy of the element at `index`, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableVec;
+    ///
+    /// let items = ObservableVec::new();
+    /// items.push(1);
+    ///
+    /// assert_eq!(items.get(0), Some(1));
+    /// assert_eq!(items.get(1), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.items.borrow().get(index).cloned()
+    }
+
+    /// Returns a copy of all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableVec;
+    ///
+    /// let items = ObservableVec::new();
+    /// items.push(1);
+    /// items.push(2);
+    ///
+    /// assert_eq!(items.to_vec(), vec![1, 2]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.items.borrow().clone()
+    }
+
+    /// Appends an element and raises [`CollectionChanged::Inserted`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableVec;
+    ///
+    /// let items = ObservableVec::new();
+    /// items.push(1);
+    /// ```
+    pub fn push(&self, value: T)
+    where
+        T: Clone,
+    {
+        self.insert(self.len(), value);
+    }
+
+    /// Inserts an element at `index` and raises [`CollectionChanged::Inserted`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableVec;
+    ///
+    /// let items = ObservableVec::new();
+    /// items.push(2);
+    /// items.insert(0, 1);
+    ///
+    /// assert_eq!(items.to_vec(), vec![1, 2]);
+    /// ```
+    pub fn insert(&self, index: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.items.borrow_mut().insert(index, value.clone());
+        self.changed
+            .invoke(&CollectionChanged::Inserted { index, value });
+    }
+
+    /// Removes and returns the element at `index`, and raises [`CollectionChanged::Removed`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableVec;
+    ///
+    /// let items = ObservableVec::new();
+    /// items.push(1);
+    ///
+    /// assert_eq!(items.remove(0), 1);
+    /// assert!(items.is_empty());
+    /// ```
+    pub fn remove(&self, index: usize) -> T
+    where
+        T: Clone,
+    {
+        let value = self.items.borrow_mut().remove(index);
+        self.changed.invoke(&CollectionChanged::Removed {
+            index,
+            value: value.clone(),
+        });
+        value
+    }
+
+    /// Removes and returns the last element, and raises [`CollectionChanged::Removed`],
+    /// or returns `None` if there are no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableVec;
+    ///
+    /// let items = ObservableVec::new();
+    /// items.push(1);
+    ///
+    /// assert_eq!(items.pop(), Some(1));
+    /// assert_eq!(items.pop(), None);
+    /// ```
+    pub fn pop(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        let index = self.len().checked_sub(1)?;
+        Some(self.remove(index))
+    }
+
+    /// Replaces the element at `index`, returns the old one, and raises
+    /// [`CollectionChanged::Replaced`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableVec;
+    ///
+    /// let items = ObservableVec::new();
+    /// items.push(1);
+    ///
+    /// assert_eq!(items.set(0, 2), 1);
+    /// assert_eq!(items.get(0), Some(2));
+    /// ```
+    pub fn set(&self, index: usize, value: T) -> T
+    where
+        T: Clone,
+    {
+        let old = core::mem::replace(&mut self.items.borrow_mut()[index], value.clone());
+        self.changed.invoke(&CollectionChanged::Replaced {
+            index,
+            old: old.clone(),
+            new: value,
+        });
+        old
+    }
+
+    /// Removes all elements and raises [`CollectionChanged::Cleared`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableVec;
+    ///
+    /// let items = ObservableVec::new();
+    /// items.push(1);
+    /// items.clear();
+    ///
+    /// assert!(items.is_empty());
+    /// ```
+    pub fn clear(&self) {
+        self.items.borrow_mut().clear();
+        self.changed.invoke(&CollectionChanged::Cleared);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn test_changes() {
+        let log = RefCell::new(vec![]);
+        let items = ObservableVec::new();
+        items
+            .changed()
+            .add(|change| log.borrow_mut().push(change.clone()));
+
+        items.push('a');
+        items.insert(0, 'b');
+        items.set(1, 'c');
+        assert_eq!(items.remove(0), 'b');
+        assert_eq!(items.pop(), Some('c'));
+        assert_eq!(items.pop(), None);
+        items.clear();
+
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                CollectionChanged::Inserted {
+                    index: 0,
+                    value: 'a'
+                },
+                CollectionChanged::Inserted {
+                    index: 0,
+                    value: 'b'
+                },
+                CollectionChanged::Replaced {
+                    index: 1,
+                    old: 'a',
+                    new: 'c'
+                },
+                CollectionChanged::Removed {
+                    index: 0,
+                    value: 'b'
+                },
+                CollectionChanged::Removed {
+                    index: 0,
+                    value: 'c'
+                },
+                CollectionChanged::Cleared,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_handlers_see_change() {
+        let seen = Rc::new(RefCell::new(vec![]));
+        let items = Rc::new(ObservableVec::new());
+        items.push(1);
+
+        let weak = Rc::downgrade(&items);
+        let seen_clone = Rc::clone(&seen);
+        items.changed().add(move |_| {
+            let items = weak.upgrade().unwrap();
+            seen_clone.borrow_mut().push(items.len());
+        });
+
+        items.push(2);
+        items.clear();
+        assert_eq!(*seen.borrow(), vec![2, 0]);
+    }
+}
@@ -0,0 +1,288 @@
+use alloc::rc::Rc;
+
+use crate::{Event, HandlerId};
+
+impl<'a, TEventArgs> Event<'a, TEventArgs> {
+    /// Forwards every invocation of this event to `target`.
+    ///
+    /// Whenever this event is invoked, `target` is invoked with the same event
+    /// arguments, as one of this event's handlers.
+    ///
+    /// Returns a handle that can be used to remove the forwarding later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let app_closed = Event::<i32>::new();
+    /// let window_closed = Event::<i32>::new();
+    /// app_closed.add(|code| println!("Exiting with {}", code));
+    ///
+    /// let link = window_closed.forward_to(&app_closed);
+    /// window_closed.invoke(&0); // Prints "Exiting with 0"
+    ///
+    /// window_closed.remove(link);
+    /// window_closed.invoke(&0); // Does nothing
+    /// ```
+    pub fn forward_to<'b: 'a>(&self, target: &'a Event<'b, TEventArgs>) -> HandlerId<TEventArgs> {
+        self.add(move |arg| target.invoke(arg))
+    }
+
+    /// Forwards every invocation of this event to `target`, converting the event
+    /// arguments with `map`.
+    ///
+    /// Returns a handle that can be used to remove the forwarding later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let status = Event::<String>::new();
+    /// let connected = Event::<u16>::new();
+    /// status.add(|message| println!("{}", message));
+    ///
+    /// connected.forward_map(&status, |port| format!("Connected on port {}", port));
+    /// connected.invoke(&8080); // Prints "Connected on port 8080"
+    /// ```
+    pub fn forward_map<'b: 'a, TTargetArgs, F>(
+        &self,
+        target: &'a Event<'b, TTargetArgs>,
+        map: F,
+    ) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) -> TTargetArgs + 'a,
+    {
+        self.add(move |arg| target.invoke(&map(arg)))
+    }
+
+    /// Derives an event that is invoked with the converted event arguments
+    /// whenever this event is invoked.
+    ///
+    /// The derived event is linked to this one through a weak handler, so
+    /// subscribers only need the derived event, and dropping it removes the link.
+    /// When chaining derived events, keep the intermediate ones alive as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// struct MouseEventArgs {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let mouse_moved = Event::<MouseEventArgs>::new();
+    /// let x_changed = mouse_moved.map(|args| args.x);
+    /// x_changed.add(|x| println!("x = {}", x));
+    ///
+    /// mouse_moved.invoke(&MouseEventArgs { x: 1, y: 2 }); // Prints "x = 1"
+    /// ```
+    pub fn map<TTargetArgs, F>(&self, map: F) -> Rc<Event<'a, TTargetArgs>>
+    where
+        TTargetArgs: 'a,
+        F: Fn(&TEventArgs) -> TTargetArgs + 'a,
+    {
+        let derived = Rc::new(Event::new());
+        self.add_weak(&derived, move |derived, arg| derived.invoke(&map(arg)));
+        derived
+    }
+
+    /// Derives an event that is invoked whenever this event is invoked with
+    /// event arguments for which `predicate` returns `true`.
+    ///
+    /// Like [`map`](Self::map), the derived event is linked to this one through
+    /// a weak handler, and dropping it removes the link.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let key_pressed = Event::<char>::new();
+    /// let digit_pressed = key_pressed.filter(|key| key.is_ascii_digit());
+    /// digit_pressed.add(|key| println!("Digit {}", key));
+    ///
+    /// key_pressed.invoke(&'a'); // Does nothing
+    /// key_pressed.invoke(&'1'); // Prints "Digit 1"
+    /// ```
+    pub fn filter<P>(&self, predicate: P) -> Rc<Event<'a, TEventArgs>>
+    where
+        TEventArgs: 'a,
+        P: Fn(&TEventArgs) -> bool + 'a,
+    {
+        let derived = Rc::new(Event::new());
+        self.add_weak(&derived, move |derived, arg| {
+            if predicate(arg) {
+                derived.invoke(arg);
+            }
+        });
+        derived
+    }
+
+    /// Derives an event that is invoked whenever any of the `sources` is invoked,
+    /// with the event arguments converted using [`Into`].
+    ///
+    /// Like [`map`](Self::map), the derived event is linked to each source through
+    /// a weak handler, and dropping it removes the links. To merge sources with
+    /// different event argument types that do not convert into a common type, map
+    /// them to one first, or use [`merge_map`](Self::merge_map).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let keyboard = Event::<u8>::new();
+    /// let gamepad = Event::<u8>::new();
+    /// let input = Event::<u32>::merge([&keyboard, &gamepad]);
+    /// input.add(|code| println!("Input {}", code));
+    ///
+    /// keyboard.invoke(&1); // Prints "Input 1"
+    /// gamepad.invoke(&2); // Prints "Input 2"
+    /// ```
+    pub fn merge<'s, TSourceArgs, I>(sources: I) -> Rc<Event<'a, TEventArgs>>
+    where
+        'a: 's,
+        TEventArgs: 'a,
+        TSourceArgs: Clone + Into<TEventArgs> + 's,
+        I: IntoIterator<Item = &'s Event<'a, TSourceArgs>>,
+    {
+        Self::merge_map(sources, |arg: &TSourceArgs| arg.clone().into())
+    }
+
+    /// Derives an event that is invoked whenever any of the `sources` is invoked,
+    /// with the event arguments converted using `map`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let left = Event::<i32>::new();
+    /// let right = Event::<i32>::new();
+    /// let moved = Event::<String>::merge_map([&left, &right], |dx| format!("Moved by {}", dx));
+    /// moved.add(|message| println!("{}", message));
+    ///
+    /// left.invoke(&-1); // Prints "Moved by -1"
+    /// right.invoke(&1); // Prints "Moved by 1"
+    /// ```
+    pub fn merge_map<'s, TSourceArgs, I, F>(sources: I, map: F) -> Rc<Event<'a, TEventArgs>>
+    where
+        'a: 's,
+        TEventArgs: 'a,
+        TSourceArgs: 's,
+        I: IntoIterator<Item = &'s Event<'a, TSourceArgs>>,
+        F: Fn(&TSourceArgs) -> TEventArgs + 'a,
+    {
+        let derived = Rc::new(Event::new());
+        let map = Rc::new(map);
+        for source in sources {
+            let map = Rc::clone(&map);
+            source.add_weak(&derived, move |derived, arg| derived.invoke(&map(arg)));
+        }
+        derived
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_forward_to() {
+        let log = RefCell::new(vec![]);
+        let target = Event::new();
+        target.add(|value: &i32| log.borrow_mut().push(*value));
+
+        let source = Event::new();
+        let link = source.forward_to(&target);
+        source.invoke(&1);
+        target.invoke(&2);
+
+        assert!(source.remove(link));
+        source.invoke(&3);
+        assert_eq!(*log.borrow(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_forward_map() {
+        let log = RefCell::new(vec![]);
+        let target = Event::new();
+        target.add(|value: &String| log.borrow_mut().push(value.clone()));
+
+        let source = Event::new();
+        source.forward_map(&target, |value: &i32| value.to_string());
+        source.invoke(&1);
+        source.invoke(&2);
+
+        assert_eq!(*log.borrow(), vec!["1", "2"]);
+    }
+
+    #[test]
+    fn test_map() {
+        let log = RefCell::new(vec![]);
+        let source = Event::new();
+        let derived = source.map(|value: &i32| value * 10);
+        derived.add(|value| log.borrow_mut().push(*value));
+
+        source.invoke(&1);
+        derived.invoke(&2);
+        assert_eq!(*log.borrow(), vec![10, 2]);
+
+        drop(derived);
+        source.invoke(&3);
+        assert!(source.is_empty());
+    }
+
+    #[test]
+    fn test_filter() {
+        let log = RefCell::new(vec![]);
+        let source = Event::new();
+        let even = source.filter(|value: &i32| value % 2 == 0);
+        let derived = even.map(|value| value * 10);
+        derived.add(|value| log.borrow_mut().push(*value));
+
+        for value in 0..5 {
+            source.invoke(&value);
+        }
+        assert_eq!(*log.borrow(), vec![0, 20, 40]);
+    }
+
+    #[test]
+    fn test_merge() {
+        let log = RefCell::new(vec![]);
+        let first = Event::<u8>::new();
+        let second = Event::<u8>::new();
+        let merged = Event::<u32>::merge([&first, &second]);
+        merged.add(|value| log.borrow_mut().push(*value));
+
+        first.invoke(&1);
+        second.invoke(&2);
+        first.invoke(&3);
+        assert_eq!(*log.borrow(), vec![1, 2, 3]);
+
+        drop(merged);
+        first.invoke(&4);
+        second.invoke(&5);
+        assert!(first.is_empty());
+        assert!(second.is_empty());
+    }
+
+    #[test]
+    fn test_merge_map() {
+        let log = RefCell::new(vec![]);
+        let first = Event::new();
+        let second = Event::new();
+        let merged = Event::merge_map([&first, &second], |value: &i32| -value);
+        merged.add(|value| log.borrow_mut().push(*value));
+
+        first.invoke(&1);
+        second.invoke(&2);
+        assert_eq!(*log.borrow(), vec![-1, -2]);
+    }
+}
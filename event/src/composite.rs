@@ -0,0 +1,206 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::ops::Deref;
+
+use crate::Event;
+
+/// An event that re-exposes the invocations of several source events through a
+/// single subscription point.
+///
+/// Each linked source forwards its invocations to the composite event, and
+/// the forwarding handlers are removed from the sources when the composite is
+/// dropped. This saves a facade object from keeping the handles of the events
+/// of its internal components. The composite dereferences to the event it
+/// forwards to, which subscribers add their handlers to.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{CompositeEvent, Event};
+///
+/// let save_button_clicked = Event::<()>::new();
+/// let save_shortcut_pressed = Event::<()>::new();
+///
+/// let save_requested = CompositeEvent::new();
+/// save_requested.link(&save_button_clicked);
+/// save_requested.link(&save_shortcut_pressed);
+/// save_requested.add(|_| println!("Saving"));
+///
+/// save_button_clicked.invoke(&()); // Prints "Saving"
+/// save_shortcut_pressed.invoke(&()); // Prints "Saving"
+///
+/// drop(save_requested);
+/// assert!(save_button_clicked.is_empty());
+/// ```
+pub struct CompositeEvent<'s, 'a, TEventArgs> {
+    event: Rc<Event<'a, TEventArgs>>,
+    links: RefCell<Vec<Box<dyn FnOnce() + 's>>>,
+}
+
+impl<'s, 'a: 's, TEventArgs: 'a> Default for CompositeEvent<'s, 'a, TEventArgs> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'s, 'a: 's, TEventArgs: 'a> CompositeEvent<'s, 'a, TEventArgs> {
+    /// Creates a new CompositeEvent without sources
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::CompositeEvent;
+    ///
+    /// let event: CompositeEvent<()> = CompositeEvent::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            event: Rc::new(Event::new()),
+            links: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Forwards every invocation of `source` to the composite event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{CompositeEvent, Event};
+    ///
+    /// let source = Event::<u32>::new();
+    /// let event = CompositeEvent::new();
+    /// event.link(&source);
+    /// event.add(|args| println!("Event invoked with {}", args));
+    ///
+    /// source.invoke(&1); // Prints "Event invoked with 1"
+    /// ```
+    pub fn link(&self, source: &'s Event<'a, TEventArgs>) {
+        let handle = source.add_weak(&self.event, |event, arg| event.invoke(arg));
+        self.links.borrow_mut().push(Box::new(move || {
+            source.remove(handle);
+        }));
+    }
+
+    /// Forwards every invocation of `source` to the composite event, converting
+    /// the event arguments with `map`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{CompositeEvent, Event};
+    ///
+    /// let key_pressed = Event::<char>::new();
+    /// let clicked = Event::<(i32, i32)>::new();
+    ///
+    /// let input = CompositeEvent::<String>::new();
+    /// input.link_map(&key_pressed, |key| format!("Key {}", key));
+    /// input.link_map(&clicked, |(x, y)| format!("Click at ({}, {})", x, y));
+    /// input.add(|message| println!("{}", message));
+    ///
+    /// key_pressed.invoke(&'a'); // Prints "Key a"
+    /// clicked.invoke(&(1, 2)); // Prints "Click at (1, 2)"
+    /// ```
+    pub fn link_map<TSourceArgs, F>(&self, source: &'s Event<'a, TSourceArgs>, map: F)
+    where
+        TSourceArgs: 's,
+        F: Fn(&TSourceArgs) -> TEventArgs + 'a,
+    {
+        let handle = source.add_weak(&self.event, move |event, arg| event.invoke(&map(arg)));
+        self.links.borrow_mut().push(Box::new(move || {
+            source.remove(handle);
+        }));
+    }
+
+    /// Returns the number of linked sources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{CompositeEvent, Event};
+    ///
+    /// let source = Event::<()>::new();
+    /// let event = CompositeEvent::new();
+    /// event.link(&source);
+    ///
+    /// assert_eq!(event.sources(), 1);
+    /// ```
+    pub fn sources(&self) -> usize {
+        self.links.borrow().len()
+    }
+
+    /// Removes the forwarding handlers from all sources.
+    ///
+    /// The handlers of the composite event are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{CompositeEvent, Event};
+    ///
+    /// let source = Event::<()>::new();
+    /// let event = CompositeEvent::new();
+    /// event.link(&source);
+    ///
+    /// event.unlink_all();
+    /// assert!(source.is_empty());
+    /// ```
+    pub fn unlink_all(&self) {
+        let links = self.links.take();
+        for unlink in links {
+            unlink();
+        }
+    }
+}
+
+impl<'s, 'a, TEventArgs> Deref for CompositeEvent<'s, 'a, TEventArgs> {
+    type Target = Event<'a, TEventArgs>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.event
+    }
+}
+
+impl<'s, 'a, TEventArgs> Drop for CompositeEvent<'s, 'a, TEventArgs> {
+    fn drop(&mut self) {
+        for unlink in self.links.get_mut().drain(..) {
+            unlink();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_composite() {
+        let log = RefCell::new(vec![]);
+        let first = Event::<u8>::new();
+        let second = Event::<u8>::new();
+        let third = Event::<&str>::new();
+        let other = first.add(|_| {});
+
+        {
+            let composite = CompositeEvent::<u32>::new();
+            composite.link_map(&first, |arg| *arg as u32);
+            composite.link_map(&second, |arg| *arg as u32 * 10);
+            composite.link_map(&third, |arg| arg.len() as u32);
+            composite.add(|arg| log.borrow_mut().push(*arg));
+            assert_eq!(composite.sources(), 3);
+
+            first.invoke(&1);
+            second.invoke(&2);
+            third.invoke(&"abc");
+        }
+
+        first.invoke(&4);
+        assert_eq!(*log.borrow(), vec![1, 20, 3]);
+        assert_eq!(first.handles(), vec![other]);
+        assert!(second.is_empty());
+        assert!(third.is_empty());
+    }
+}
@@ -0,0 +1,270 @@
+use core::cell::Cell;
+
+use critical_section::Mutex;
+
+use crate::id::next_generation;
+use crate::{CapacityError, HandlerId};
+
+/// A handler stored in a [`CriticalSectionEvent`], along with its unique number.
+type Slot<'a, TEventArgs> = Option<(u64, &'a (dyn Fn(&TEventArgs) + Sync))>;
+
+/// An event that stores up to `N` handlers inline and can be shared with
+/// interrupt handlers.
+///
+/// This is the interrupt-safe counterpart of [`StaticEvent`](crate::StaticEvent).
+/// Its handler slots are guarded by the `critical-section` crate, so the event
+/// can live in a `static`, have handlers registered from thread context and be
+/// invoked from an interrupt handler, or the other way around. The target must
+/// provide a critical section implementation, as usual for that crate.
+///
+/// The critical section is only held to update or copy the handler slots.
+/// Handlers run outside of it, so they do not delay other interrupts, and must
+/// be `Sync` since an interrupt may invoke them while they already run.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::CriticalSectionEvent;
+///
+/// static BUTTON_PRESSED: CriticalSectionEvent<u8, 4> = CriticalSectionEvent::new();
+///
+/// fn on_pressed(pin: &u8) {
+///     println!("Button on pin {} pressed", pin);
+/// }
+///
+/// // Thread context
+/// BUTTON_PRESSED.try_add(&on_pressed).unwrap();
+///
+/// // Interrupt handler
+/// BUTTON_PRESSED.invoke(&13); // Prints "Button on pin 13 pressed"
+/// ```
+pub struct CriticalSectionEvent<'a, TEventArgs, const N: usize> {
+    slots: Mutex<[Cell<Slot<'a, TEventArgs>>; N]>,
+}
+
+impl<'a, TEventArgs, const N: usize> Default for CriticalSectionEvent<'a, TEventArgs, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, TEventArgs, const N: usize> CriticalSectionEvent<'a, TEventArgs, N> {
+    /// Creates a new, empty CriticalSectionEvent with room for `N` handlers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::CriticalSectionEvent;
+    ///
+    /// let event: CriticalSectionEvent<(), 4> = CriticalSectionEvent::new();
+    /// assert_eq!(event.capacity(), 4);
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            slots: Mutex::new([const { Cell::new(None) }; N]),
+        }
+    }
+
+    /// Adds an event handler to the event.
+    ///
+    /// Returns a handle that can be used to remove the handler later, or
+    /// [`CapacityError`] if the event already has `N` handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::CriticalSectionEvent;
+    ///
+    /// let handler = |args: &u32| println!("Event invoked with {}", args);
+    /// let event = CriticalSectionEvent::<u32, 1>::new();
+    ///
+    /// assert!(event.try_add(&handler).is_ok());
+    /// assert!(event.try_add(&handler).is_err());
+    /// ```
+    pub fn try_add(
+        &self,
+        handler: &'a (dyn Fn(&TEventArgs) + Sync),
+    ) -> Result<HandlerId<TEventArgs>, CapacityError> {
+        critical_section::with(|cs| {
+            let slots = self.slots.borrow(cs);
+            let key = slots
+                .iter()
+                .position(|slot| slot.get().is_none())
+                .ok_or(CapacityError)?;
+            let generation = next_generation();
+            slots[key].set(Some((generation, handler)));
+            Ok(HandlerId::new(key, generation))
+        })
+    }
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::CriticalSectionEvent;
+    ///
+    /// let handler = |args: &u32| println!("Event invoked with {}", args);
+    /// let event = CriticalSectionEvent::<u32, 1>::new();
+    /// let handle = event.try_add(&handler).unwrap();
+    ///
+    /// assert!(event.remove(handle));
+    /// assert!(!event.remove(handle)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        critical_section::with(|cs| {
+            let Some(slot) = self.slots.borrow(cs).get(handle.key()) else {
+                return false;
+            };
+            match slot.get() {
+                Some((generation, _)) if generation == handle.generation() => {
+                    slot.set(None);
+                    true
+                }
+                _ => false,
+            }
+        })
+    }
+
+    /// Returns the number of event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::CriticalSectionEvent;
+    ///
+    /// let handler = |args: &u32| println!("Handler 1");
+    /// let event = CriticalSectionEvent::<u32, 4>::new();
+    /// event.try_add(&handler).unwrap();
+    ///
+    /// assert_eq!(event.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        critical_section::with(|cs| {
+            self.slots
+                .borrow(cs)
+                .iter()
+                .filter(|slot| slot.get().is_some())
+                .count()
+        })
+    }
+
+    /// Returns `true` if the event has no handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::CriticalSectionEvent;
+    ///
+    /// let event: CriticalSectionEvent<(), 4> = CriticalSectionEvent::new();
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of handlers the event can hold, which is `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::CriticalSectionEvent;
+    ///
+    /// let event: CriticalSectionEvent<(), 8> = CriticalSectionEvent::new();
+    /// assert_eq!(event.capacity(), 8);
+    /// ```
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Removes all event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::CriticalSectionEvent;
+    ///
+    /// let handler = |args: &u32| println!("Handler 1");
+    /// let event = CriticalSectionEvent::<u32, 4>::new();
+    /// event.try_add(&handler).unwrap();
+    ///
+    /// event.clear(); // Remove all handlers
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn clear(&self) {
+        critical_section::with(|cs| {
+            for slot in self.slots.borrow(cs) {
+                slot.set(None);
+            }
+        });
+    }
+
+    /// Invokes all event handlers sequentially (one after another).
+    ///
+    /// Handlers removed during the invocation, e.g. by an interrupt, are skipped
+    /// if they have not run yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::CriticalSectionEvent;
+    ///
+    /// let first = |args: &()| println!("Handler 1");
+    /// let second = |args: &()| println!("Handler 2");
+    /// let event = CriticalSectionEvent::<(), 2>::new();
+    /// event.try_add(&first).unwrap();
+    /// event.try_add(&second).unwrap();
+    ///
+    /// event.invoke(&()); // Execute all handlers in order
+    /// ```
+    pub fn invoke(&self, arg: &TEventArgs) {
+        // Copy the handlers in one critical section and sort them back into
+        // insertion order, like `StaticEvent::invoke`.
+        let mut snapshot: [(usize, Slot<'a, TEventArgs>); N] = critical_section::with(|cs| {
+            let slots = self.slots.borrow(cs);
+            core::array::from_fn(|key| (key, slots[key].get()))
+        });
+        snapshot.sort_unstable_by_key(|(_, slot)| slot.map(|(generation, _)| generation));
+
+        for (key, slot) in snapshot {
+            let Some((generation, handler)) = slot else {
+                continue;
+            };
+            let registered = critical_section::with(
+                |cs| matches!(self.slots.borrow(cs)[key].get(), Some((current, _)) if current == generation),
+            );
+            if registered {
+                handler(arg);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_shared_across_threads() {
+        static EVENT: CriticalSectionEvent<u32, 2> = CriticalSectionEvent::new();
+        static LOG: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+        static HANDLER: fn(&u32) = |arg| LOG.lock().unwrap().push(*arg);
+        let handle = EVENT.try_add(&HANDLER).unwrap();
+        EVENT.try_add(&HANDLER).unwrap();
+        assert_eq!(EVENT.try_add(&HANDLER), Err(CapacityError));
+
+        thread::spawn(|| EVENT.invoke(&1)).join().unwrap();
+        assert!(EVENT.remove(handle));
+        EVENT.invoke(&2);
+
+        assert_eq!(*LOG.lock().unwrap(), vec![1, 1, 2]);
+        EVENT.clear();
+        assert!(EVENT.is_empty());
+    }
+}
@@ -0,0 +1,210 @@
+use crossbeam_channel::{Receiver, Sender, TrySendError};
+
+use crate::handlers::Handler;
+use crate::{Backpressure, Event, HandlerId};
+
+impl<'a, TEventArgs: Clone + 'a> Event<'a, TEventArgs> {
+    /// Adds an event handler that sends a clone of the event arguments to a
+    /// crossbeam channel.
+    ///
+    /// Sending to a bounded channel that is full blocks the invocation until
+    /// there is room. Once all receivers are dropped, the handler is removed
+    /// from the event.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// let (sender, receiver) = crossbeam_channel::bounded(16);
+    /// event.send_to(sender);
+    ///
+    /// event.invoke(&1);
+    /// assert_eq!(receiver.try_recv(), Ok(1));
+    /// ```
+    pub fn send_to(&self, sender: Sender<TEventArgs>) -> HandlerId<TEventArgs> {
+        self.insert(Handler::guarded(move |arg: &TEventArgs, expired| {
+            let sent = sender.send(arg.clone()).is_ok();
+            expired.set(!sent);
+            sent.then_some(())
+        }))
+    }
+
+    /// Returns an unbounded crossbeam channel receiver that receives a clone of
+    /// the event arguments each time the event is invoked.
+    ///
+    /// The receiver can be used with [`crossbeam_channel::select!`] alongside
+    /// other channels. Once it is dropped, the handler is removed from the event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_channel::select;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// let receiver = event.crossbeam_channel();
+    /// let (_stop, shutdown) = crossbeam_channel::unbounded::<()>();
+    ///
+    /// event.invoke(&1);
+    /// select! {
+    ///     recv(receiver) -> value => assert_eq!(value, Ok(1)),
+    ///     recv(shutdown) -> _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn crossbeam_channel(&self) -> Receiver<TEventArgs> {
+        self.crossbeam_channel_with(Backpressure::Unbounded)
+    }
+
+    /// Returns a crossbeam channel receiver like
+    /// [`crossbeam_channel`](Self::crossbeam_channel), whose queue of
+    /// invocations waiting to be received follows the given policy.
+    ///
+    /// With [`Backpressure::Block`], invoking the event waits until there is
+    /// room. To make room with [`Backpressure::DropOldest`] and
+    /// [`Backpressure::Coalesce`], the handler keeps a receiver of its own, so it
+    /// stays attached after the returned receiver is dropped, until it is
+    /// removed with [`clear`](Self::clear) or the event is dropped. With the
+    /// other policies, it is removed once all receivers are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Backpressure, Event};
+    ///
+    /// let event = Event::<u32>::new();
+    /// let receiver = event.crossbeam_channel_with(Backpressure::Coalesce);
+    ///
+    /// event.invoke(&1);
+    /// event.invoke(&2);
+    /// assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![2]);
+    /// ```
+    pub fn crossbeam_channel_with(&self, policy: Backpressure) -> Receiver<TEventArgs> {
+        let (sender, receiver) = match policy {
+            Backpressure::Unbounded => crossbeam_channel::unbounded(),
+            Backpressure::Block(capacity) | Backpressure::DropNewest(capacity) => {
+                crossbeam_channel::bounded(capacity)
+            }
+            Backpressure::DropOldest(capacity) => crossbeam_channel::bounded(capacity),
+            Backpressure::Coalesce => crossbeam_channel::bounded(1),
+        };
+        match policy {
+            Backpressure::Unbounded | Backpressure::Block(_) => {
+                self.send_to(sender);
+            }
+            Backpressure::DropNewest(_) => {
+                self.insert(Handler::guarded(move |arg: &TEventArgs, expired| {
+                    let sent = !matches!(
+                        sender.try_send(arg.clone()),
+                        Err(TrySendError::Disconnected(_))
+                    );
+                    expired.set(!sent);
+                    sent.then_some(())
+                }));
+            }
+            // Everything is dropped, but the channel stays open with the event
+            Backpressure::DropOldest(0) => {
+                self.add(move |_| {
+                    let _ = &sender;
+                });
+            }
+            Backpressure::DropOldest(_) | Backpressure::Coalesce => {
+                let evict = receiver.clone();
+                self.add(move |arg| {
+                    let mut arg = arg.clone();
+                    while let Err(TrySendError::Full(rejected)) = sender.try_send(arg) {
+                        let _ = evict.try_recv();
+                        arg = rejected;
+                    }
+                });
+            }
+        }
+        receiver
+    }
+}
+
+impl<'a, TEventArgs> Event<'a, TEventArgs> {
+    /// Invokes the event once for each value waiting in a crossbeam channel
+    /// receiver, without blocking.
+    ///
+    /// Returns the number of invocations delivered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.add(|value| println!("Received {}", value));
+    ///
+    /// let (sender, receiver) = crossbeam_channel::unbounded();
+    /// sender.send(1).unwrap();
+    ///
+    /// assert_eq!(event.drain_crossbeam(&receiver), 1);
+    /// ```
+    pub fn drain_crossbeam(&self, receiver: &Receiver<TEventArgs>) -> usize {
+        receiver.try_iter().map(|arg| self.invoke(&arg)).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_send_to() {
+        let event = Event::new();
+        let (sender, receiver) = crossbeam_channel::bounded(4);
+        let handle = event.send_to(sender);
+
+        event.invoke(&1);
+        event.invoke(&2);
+        assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![1, 2]);
+
+        drop(receiver);
+        event.invoke(&3);
+        assert_eq!(event.handler_info(handle), None);
+    }
+
+    #[test]
+    fn test_crossbeam_channel_with() {
+        let event = Event::new();
+        let oldest = event.crossbeam_channel_with(Backpressure::DropOldest(2));
+        let newest = event.crossbeam_channel_with(Backpressure::DropNewest(2));
+        let latest = event.crossbeam_channel_with(Backpressure::Coalesce);
+        let blocking = event.crossbeam_channel_with(Backpressure::Block(4));
+
+        for arg in 1..=3 {
+            event.invoke(&arg);
+        }
+        assert_eq!(oldest.try_iter().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(newest.try_iter().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(latest.try_iter().collect::<Vec<_>>(), vec![3]);
+        assert_eq!(blocking.try_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        drop((newest, blocking));
+        event.invoke(&4);
+        assert_eq!(event.len(), 2);
+    }
+
+    #[test]
+    fn test_drain_crossbeam() {
+        let log = RefCell::new(vec![]);
+        let source = Event::new();
+        let receiver = source.crossbeam_channel();
+
+        let event = Event::new();
+        event.add(|value: &i32| log.borrow_mut().push(*value));
+
+        source.invoke(&1);
+        source.invoke(&2);
+        assert_eq!(event.drain_crossbeam(&receiver), 2);
+        assert_eq!(*log.borrow(), vec![1, 2]);
+    }
+}
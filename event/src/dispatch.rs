@@ -0,0 +1,143 @@
+use std::sync::Arc;
+use std::sync::mpsc::{Sender, SyncSender};
+
+use crate::{HandlerId, SyncEvent};
+
+/// Type alias for a handler call that has been handed to a [`Dispatcher`].
+pub type DispatchTask = Box<dyn FnOnce() + Send>;
+
+/// Runs tasks on a designated thread or executor, such as a UI thread.
+///
+/// Implemented for the sending half of a channel, whose receiving thread runs
+/// the tasks it receives, and for closures that forward tasks to an executor.
+pub trait Dispatcher {
+    /// Schedules `task` to run, without waiting for it.
+    fn dispatch(&self, task: DispatchTask);
+}
+
+impl Dispatcher for Sender<DispatchTask> {
+    fn dispatch(&self, task: DispatchTask) {
+        // A closed channel means the dispatcher thread is gone, so the
+        // call is dropped like any other message sent to it.
+        let _ = self.send(task);
+    }
+}
+
+impl Dispatcher for SyncSender<DispatchTask> {
+    fn dispatch(&self, task: DispatchTask) {
+        let _ = self.send(task);
+    }
+}
+
+impl<F: Fn(DispatchTask)> Dispatcher for F {
+    fn dispatch(&self, task: DispatchTask) {
+        self(task)
+    }
+}
+
+impl<'a, TEventArgs> SyncEvent<'a, TEventArgs> {
+    /// Adds an event handler that runs on the given dispatcher rather than on the
+    /// invoking thread.
+    ///
+    /// Each invocation clones the event arguments and hands the call to
+    /// `dispatcher`, then returns without waiting for it. This keeps the state of
+    /// a subscriber confined to one thread, e.g. a UI thread, even when the event is
+    /// invoked from others.
+    ///
+    /// Returns a handle that can be used to remove the handler later. Calls that
+    /// were already dispatched still run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    /// use std::thread;
+    ///
+    /// use event_rs::{DispatchTask, SyncEvent};
+    ///
+    /// let (ui, tasks) = mpsc::channel::<DispatchTask>();
+    /// let event = SyncEvent::<u32>::new();
+    /// event.add_on(ui, |args| println!("Progress {} on the UI thread", args));
+    ///
+    /// thread::scope(|s| {
+    ///     s.spawn(|| event.invoke(&50));
+    /// });
+    ///
+    /// // The UI thread's message loop
+    /// for task in tasks.try_iter() {
+    ///     task(); // Prints "Progress 50 on the UI thread"
+    /// }
+    /// ```
+    pub fn add_on<D, F>(&self, dispatcher: D, handler: F) -> HandlerId<TEventArgs>
+    where
+        TEventArgs: Clone + Send + 'static,
+        D: Dispatcher + Send + Sync + 'a,
+        F: Fn(&TEventArgs) + Send + Sync + 'static,
+    {
+        let handler = Arc::new(handler);
+        self.add(move |arg| {
+            let handler = Arc::clone(&handler);
+            let arg = arg.clone();
+            dispatcher.dispatch(Box::new(move || handler(&arg)));
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::sync::mpsc;
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_runs_on_dispatcher_thread() {
+        static LOG: Mutex<Vec<(u32, bool)>> = Mutex::new(Vec::new());
+        let (sender, tasks) = mpsc::channel::<DispatchTask>();
+
+        let dispatcher = thread::spawn(move || {
+            for task in tasks {
+                task();
+            }
+        });
+
+        let event = SyncEvent::new();
+        let handle = event.add_on(sender, |arg: &u32| {
+            let on_dispatcher = thread::current().name() != Some("invoker");
+            LOG.lock().unwrap().push((*arg, on_dispatcher));
+        });
+
+        thread::Builder::new()
+            .name("invoker".into())
+            .spawn(move || {
+                event.invoke(&1);
+                event.invoke(&2);
+                assert!(event.remove(handle));
+                event.invoke(&3);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+
+        dispatcher.join().unwrap();
+        assert_eq!(*LOG.lock().unwrap(), vec![(1, true), (2, true)]);
+    }
+
+    #[test]
+    fn test_closure_dispatcher() {
+        let queue = Mutex::new(Vec::<DispatchTask>::new());
+        let event = SyncEvent::new();
+        event.add_on(
+            |task| queue.lock().unwrap().push(task),
+            |arg: &u32| assert_eq!(*arg, 7),
+        );
+
+        event.invoke(&7);
+        let tasks = std::mem::take(&mut *queue.lock().unwrap());
+        assert_eq!(tasks.len(), 1);
+        for task in tasks {
+            task();
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt;
+
+use crate::{CapacityError, Event, HandlerId, Storage};
+
+/// The error returned by the fallible methods of the events, such as
+/// [`Event::try_remove`] and [`Event::try_invoke`].
+///
+/// Unlike the `bool` returned by their infallible counterparts, the variants
+/// tell apart why an operation failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EventError {
+    /// The handle does not refer to a handler of the event, e.g. because the
+    /// handler was already removed.
+    HandlerNotFound,
+    /// The event has been dropped or can no longer be raised.
+    EventClosed,
+    /// The event has no room for another handler.
+    CapacityExceeded,
+    /// Handlers panicked, with their panic payloads in invocation order.
+    HandlerPanicked(Vec<Box<dyn Any + Send>>),
+}
+
+impl fmt::Display for EventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventError::HandlerNotFound => f.write_str("the handler was not found"),
+            EventError::EventClosed => f.write_str("the event is closed"),
+            EventError::CapacityExceeded => {
+                f.write_str("the event has no room for another handler")
+            }
+            EventError::HandlerPanicked(panics) => write!(f, "{} handlers panicked", panics.len()),
+        }
+    }
+}
+
+impl core::error::Error for EventError {}
+
+impl From<CapacityError> for EventError {
+    fn from(_: CapacityError) -> Self {
+        EventError::CapacityExceeded
+    }
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> Event<'a, TEventArgs, TResult, TStorage> {
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns [`EventError::HandlerNotFound`] if the handler was not found,
+    /// like [`remove`](Self::remove) returning `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Event, EventError};
+    ///
+    /// let event = Event::<u32>::new();
+    /// let handle = event.add(|args| println!("Event invoked with {}", args));
+    ///
+    /// assert!(event.try_remove(handle).is_ok());
+    /// assert!(matches!(event.try_remove(handle), Err(EventError::HandlerNotFound)));
+    /// ```
+    pub fn try_remove(&self, handle: HandlerId<TEventArgs>) -> Result<(), EventError> {
+        if self.remove(handle) {
+            Ok(())
+        } else {
+            Err(EventError::HandlerNotFound)
+        }
+    }
+
+    /// Invokes all event handlers sequentially, isolating them from each other's
+    /// panics like [`invoke_isolated`](Self::invoke_isolated).
+    ///
+    /// Returns [`EventError::HandlerPanicked`] with the panic payloads if any
+    /// handler panicked.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Event, EventError};
+    ///
+    /// let event = Event::new();
+    /// event.add(|args| panic!("Handler 1 failed"));
+    /// event.add(|args| { println!("Handler 2 still runs"); });
+    ///
+    /// match event.try_invoke(&()) {
+    ///     Err(EventError::HandlerPanicked(panics)) => assert_eq!(panics.len(), 1),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_invoke(&self, arg: &TEventArgs) -> Result<(), EventError> {
+        self.invoke_isolated(arg)
+            .map_err(EventError::HandlerPanicked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_remove() {
+        let event = Event::new();
+        let handle = event.add(|_: &()| {});
+
+        assert!(event.try_remove(handle).is_ok());
+        let error = event.try_remove(handle).unwrap_err();
+        assert!(matches!(error, EventError::HandlerNotFound));
+        assert_eq!(error.to_string(), "the handler was not found");
+        assert_eq!(
+            EventError::from(CapacityError).to_string(),
+            "the event has no room for another handler"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_try_invoke() {
+        let event = Event::new();
+        event.add(|arg: &i32| assert!(*arg >= 0, "negative"));
+
+        assert!(event.try_invoke(&1).is_ok());
+        let Err(EventError::HandlerPanicked(panics)) = event.try_invoke(&-1) else {
+            panic!("expected the handler to panic");
+        };
+        assert_eq!(panics[0].downcast_ref::<&str>(), Some(&"negative"));
+    }
+}
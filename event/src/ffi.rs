@@ -0,0 +1,217 @@
+//! A C interface to [`Event`], for embedding in C or C++ applications.
+//!
+//! Events are created with [`event_rs_event_new`] and freed with
+//! [`event_rs_event_free`]. Handlers are C function pointers with a `user_data`
+//! pointer of their own, and events are invoked with an opaque pointer to the
+//! event arguments, which is passed to each handler unchanged. Events are not
+//! thread-safe: each event must only be used from one thread at a time.
+//!
+//! A panic cannot unwind into C, so it aborts the process.
+//!
+//! # Examples
+//!
+//! ```c
+//! static void on_clicked(const void *args, void *user_data) {
+//!     const struct point *p = args;
+//!     printf("Clicked at (%d, %d)\n", p->x, p->y);
+//! }
+//!
+//! EventRsEvent *clicked = event_rs_event_new();
+//! EventRsHandlerId handle = event_rs_event_add(clicked, on_clicked, NULL, NULL);
+//!
+//! struct point p = { 1, 2 };
+//! event_rs_event_invoke(clicked, &p);
+//!
+//! event_rs_event_remove(clicked, handle);
+//! event_rs_event_free(clicked);
+//! ```
+
+use alloc::boxed::Box;
+use core::ffi::c_void;
+
+use crate::{Event, HandlerId};
+
+/// An event handler implemented in C.
+///
+/// It receives the event arguments pointer passed to [`event_rs_event_invoke`]
+/// and the `user_data` pointer it was added with.
+pub type EventRsCallback = unsafe extern "C" fn(args: *const c_void, user_data: *mut c_void);
+
+/// Releases the `user_data` of a handler once the handler is removed.
+pub type EventRsDestructor = unsafe extern "C" fn(user_data: *mut c_void);
+
+/// An event created by [`event_rs_event_new`], opaque to C.
+pub struct EventRsEvent(Event<'static, *const c_void>);
+
+/// A handle to an event handler, used to remove it with [`event_rs_event_remove`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventRsHandlerId {
+    /// The storage slot of the handler.
+    pub key: usize,
+    /// The process-wide unique number of the handler.
+    pub generation: u64,
+}
+
+/// A C handler along with its `user_data`, which it owns.
+struct Callback {
+    callback: EventRsCallback,
+    user_data: *mut c_void,
+    destructor: Option<EventRsDestructor>,
+}
+
+impl Drop for Callback {
+    fn drop(&mut self) {
+        if let Some(destructor) = self.destructor {
+            // SAFETY: the caller of `event_rs_event_add` promised that the
+            // destructor can be called with the user_data once.
+            unsafe { destructor(self.user_data) }
+        }
+    }
+}
+
+/// Creates a new, empty event. Free it with [`event_rs_event_free`].
+#[unsafe(no_mangle)]
+pub extern "C" fn event_rs_event_new() -> *mut EventRsEvent {
+    Box::into_raw(Box::new(EventRsEvent(Event::new())))
+}
+
+/// Frees an event and its handlers, calling their destructors.
+///
+/// # Safety
+///
+/// `event` must have been returned by [`event_rs_event_new`] and not freed yet,
+/// or be null, in which case nothing happens. It must not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn event_rs_event_free(event: *mut EventRsEvent) {
+    if !event.is_null() {
+        // SAFETY: guaranteed by the caller.
+        drop(unsafe { Box::from_raw(event) });
+    }
+}
+
+/// Adds a handler to an event.
+///
+/// `callback` is called with `user_data` on each invocation. If `destructor` is
+/// not null, it is called with `user_data` once the handler is removed, either
+/// by [`event_rs_event_remove`] or when the event is freed.
+///
+/// Returns a handle that can be used to remove the handler later.
+///
+/// # Safety
+///
+/// `event` must be a live event returned by [`event_rs_event_new`]. `callback`
+/// must be safe to call with `user_data` and the event arguments of every
+/// invocation until the handler is removed, and `destructor` must be safe to call
+/// with `user_data` once.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn event_rs_event_add(
+    event: *const EventRsEvent,
+    callback: EventRsCallback,
+    user_data: *mut c_void,
+    destructor: Option<EventRsDestructor>,
+) -> EventRsHandlerId {
+    let callback = Callback {
+        callback,
+        user_data,
+        destructor,
+    };
+    // SAFETY: guaranteed by the caller.
+    let event = unsafe { &(*event).0 };
+    let id = event.add(move |args| {
+        // Borrow the whole callback, so that the closure owns it rather than
+        // copies of its fields, and drops it along with the handler.
+        let Callback {
+            callback,
+            user_data,
+            ..
+        } = &callback;
+        // SAFETY: guaranteed by the caller.
+        unsafe { callback(*args, *user_data) }
+    });
+    EventRsHandlerId {
+        key: id.key(),
+        generation: id.generation(),
+    }
+}
+
+/// Removes a handler from an event using its handle.
+///
+/// Returns `true` if the handler was found and removed, `false` otherwise.
+///
+/// # Safety
+///
+/// `event` must be a live event returned by [`event_rs_event_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn event_rs_event_remove(
+    event: *const EventRsEvent,
+    handle: EventRsHandlerId,
+) -> bool {
+    // SAFETY: guaranteed by the caller.
+    let event = unsafe { &(*event).0 };
+    event.remove(HandlerId::new(handle.key, handle.generation))
+}
+
+/// Invokes all handlers of an event sequentially, passing them `args`.
+///
+/// # Safety
+///
+/// `event` must be a live event returned by [`event_rs_event_new`], and `args`
+/// must be valid for the handlers of the event.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn event_rs_event_invoke(event: *const EventRsEvent, args: *const c_void) {
+    // SAFETY: guaranteed by the caller.
+    let event = unsafe { &(*event).0 };
+    event.invoke(&args);
+}
+
+/// Returns the number of handlers of an event.
+///
+/// # Safety
+///
+/// `event` must be a live event returned by [`event_rs_event_new`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn event_rs_event_len(event: *const EventRsEvent) -> usize {
+    // SAFETY: guaranteed by the caller.
+    unsafe { (*event).0.len() }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    unsafe extern "C" fn push(args: *const c_void, user_data: *mut c_void) {
+        let log = unsafe { &*(user_data as *const RefCell<Vec<i32>>) };
+        log.borrow_mut().push(unsafe { *(args as *const i32) });
+    }
+
+    unsafe extern "C" fn release(user_data: *mut c_void) {
+        let log = unsafe { &*(user_data as *const RefCell<Vec<i32>>) };
+        log.borrow_mut().push(-1);
+    }
+
+    #[test]
+    fn test_ffi_lifecycle() {
+        let log = RefCell::new(Vec::<i32>::new());
+        let user_data = &log as *const _ as *mut c_void;
+
+        unsafe {
+            let event = event_rs_event_new();
+            let first = event_rs_event_add(event, push, user_data, Some(release));
+            event_rs_event_add(event, push, user_data, None);
+            event_rs_event_add(event, push, user_data, Some(release));
+            assert_eq!(event_rs_event_len(event), 3);
+
+            event_rs_event_invoke(event, &1 as *const i32 as *const c_void);
+            assert!(event_rs_event_remove(event, first));
+            assert!(!event_rs_event_remove(event, first));
+            event_rs_event_invoke(event, &2 as *const i32 as *const c_void);
+            event_rs_event_free(event);
+            event_rs_event_free(core::ptr::null_mut());
+        }
+
+        assert_eq!(*log.borrow(), vec![1, 1, 1, -1, 2, 2, -1]);
+    }
+}
@@ -0,0 +1,174 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+use crate::SyncEvent;
+
+type Key = (TypeId, Option<String>);
+
+/// A registry of thread-safe events keyed by the type of their event arguments,
+/// and optionally by a name.
+///
+/// Each key gets its own [`SyncEvent`], created on first use and kept for the
+/// lifetime of the registry. Unlike an [`EventBus`](crate::EventBus), the
+/// registry can be shared across threads, and [`global`] provides one for the
+/// whole application, so that crates can publish and subscribe without passing
+/// a registry around.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::EventRegistry;
+///
+/// struct Saved {
+///     path: String,
+/// }
+///
+/// let registry = EventRegistry::new();
+/// registry.event::<Saved>().add(|args| println!("Saved {}", args.path));
+///
+/// let saved = Saved { path: "notes.txt".into() };
+/// registry.event::<Saved>().invoke(&saved); // Prints "Saved notes.txt"
+/// ```
+#[derive(Default)]
+pub struct EventRegistry {
+    events: RwLock<HashMap<Key, Arc<dyn Any + Send + Sync>>>,
+}
+
+impl EventRegistry {
+    /// Creates a new, empty EventRegistry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventRegistry;
+    ///
+    /// let registry = EventRegistry::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the event for event arguments of type `T`, creating it if needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventRegistry;
+    ///
+    /// let registry = EventRegistry::new();
+    /// registry.event::<u32>().add(|args| println!("Received {}", args));
+    ///
+    /// assert_eq!(registry.event::<u32>().len(), 1);
+    /// ```
+    pub fn event<T: Send + Sync + 'static>(&self) -> Arc<SyncEvent<'static, T>> {
+        self.get_or_insert((TypeId::of::<T>(), None))
+    }
+
+    /// Returns the event for event arguments of type `T` with the given name,
+    /// creating it if needed.
+    ///
+    /// Names tell apart events that share a type of event arguments. A named
+    /// event is distinct from the unnamed event of the same type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventRegistry;
+    ///
+    /// let registry = EventRegistry::new();
+    /// registry.named_event::<String>("opened").add(|path| println!("Opened {}", path));
+    ///
+    /// assert_eq!(registry.named_event::<String>("opened").len(), 1);
+    /// assert!(registry.named_event::<String>("closed").is_empty());
+    /// assert!(registry.event::<String>().is_empty());
+    /// ```
+    pub fn named_event<T: Send + Sync + 'static>(&self, name: &str) -> Arc<SyncEvent<'static, T>> {
+        self.get_or_insert((TypeId::of::<T>(), Some(name.to_owned())))
+    }
+
+    fn get_or_insert<T: Send + Sync + 'static>(&self, key: Key) -> Arc<SyncEvent<'static, T>> {
+        let existing = self
+            .events
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+            .cloned();
+        let event = existing.unwrap_or_else(|| {
+            let mut events = self.events.write().unwrap_or_else(PoisonError::into_inner);
+            let event = events
+                .entry(key)
+                .or_insert_with(|| Arc::new(SyncEvent::<'static, T>::new()));
+            Arc::clone(event)
+        });
+
+        event
+            .downcast()
+            .unwrap_or_else(|_| unreachable!("events are keyed by their type"))
+    }
+}
+
+/// Returns the registry of events shared by the whole application.
+///
+/// The registry is created on first use. See [`EventRegistry`].
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+///
+/// use event_rs::global;
+///
+/// global().named_event::<u32>("progress").add(|percent| println!("{}% done", percent));
+///
+/// thread::spawn(|| global().named_event::<u32>("progress").invoke(&50))
+///     .join()
+///     .unwrap(); // Prints "50% done"
+/// ```
+pub fn global() -> &'static EventRegistry {
+    static GLOBAL: OnceLock<EventRegistry> = OnceLock::new();
+    GLOBAL.get_or_init(EventRegistry::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_events_by_key() {
+        let registry = EventRegistry::new();
+        assert!(Arc::ptr_eq(
+            &registry.event::<u8>(),
+            &registry.event::<u8>()
+        ));
+        assert!(Arc::ptr_eq(
+            &registry.named_event::<u8>("a"),
+            &registry.named_event::<u8>("a")
+        ));
+
+        registry.event::<u8>().add(|_| {});
+        assert!(registry.named_event::<u8>("a").is_empty());
+        assert!(registry.named_event::<u16>("a").is_empty());
+    }
+
+    #[test]
+    fn test_global_across_threads() {
+        static TOTAL: AtomicU32 = AtomicU32::new(0);
+        struct Ping(u32);
+
+        global().event::<Ping>().add(|ping| {
+            TOTAL.fetch_add(ping.0, Ordering::Relaxed);
+        });
+        let workers: Vec<_> = (1..=3)
+            .map(|i| thread::spawn(move || global().event::<Ping>().invoke(&Ping(i))))
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        assert_eq!(TOTAL.load(Ordering::Relaxed), 6);
+    }
+}
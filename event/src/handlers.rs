@@ -0,0 +1,470 @@
+use alloc::borrow::{Cow, ToOwned};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+#[cfg(feature = "futures")]
+use alloc::rc::Weak;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::Cell;
+#[cfg(feature = "futures")]
+use core::cell::RefCell;
+use core::time::Duration;
+#[cfg(feature = "time")]
+use std::time::Instant;
+
+use crate::EventHandler;
+use crate::id::{self, HandlerId};
+use crate::store::{HandlerStore, Storage};
+
+/// Type alias for one-shot event handlers.
+type OnceEventHandler<'a, TEventArgs, TResult> = Box<dyn FnOnce(&TEventArgs) -> TResult + 'a>;
+
+/// Type alias for handlers that decide on each invocation whether they run.
+///
+/// The handler returns `None` when it skipped the invocation, and sets the
+/// flag it is given once it should never run again.
+type GuardedEventHandler<'a, TEventArgs, TResult> =
+    Box<dyn Fn(&TEventArgs, &Cell<bool>) -> Option<TResult> + 'a>;
+
+/// A handler whose calls are deferred to [`Event::pump`](crate::Event::pump).
+#[cfg(feature = "time")]
+pub(crate) trait Deferred<TEventArgs> {
+    /// Records an invocation of the event.
+    fn record(&self, arg: &TEventArgs);
+
+    /// Runs the handler if a recorded invocation is due at `now`.
+    fn poll(&self, now: Instant);
+}
+
+/// A handler stored in an [`Event`](crate::Event).
+pub(crate) enum Handler<'a, TEventArgs, TResult> {
+    /// Stays attached until it is removed.
+    Persistent(EventHandler<'a, TEventArgs, TResult>),
+    /// Runs at most once; becomes `None` after it has fired.
+    Once(Cell<Option<OnceEventHandler<'a, TEventArgs, TResult>>>),
+    /// Runs when its guard allows it, until it reports that it has expired.
+    Guarded {
+        handler: GuardedEventHandler<'a, TEventArgs, TResult>,
+        expired: Cell<bool>,
+    },
+    /// Runs from [`Event::pump`](crate::Event::pump) rather than during invocation.
+    #[cfg(feature = "time")]
+    Deferred(Box<dyn Deferred<TEventArgs> + 'a>),
+}
+
+impl<'a, TEventArgs, TResult> Handler<'a, TEventArgs, TResult> {
+    pub(crate) fn persistent<F>(handler: F) -> Self
+    where
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        Handler::Persistent(Box::new(handler))
+    }
+
+    pub(crate) fn once<F>(handler: F) -> Self
+    where
+        F: FnOnce(&TEventArgs) -> TResult + 'a,
+    {
+        Handler::Once(Cell::new(Some(Box::new(handler))))
+    }
+
+    pub(crate) fn guarded<F>(handler: F) -> Self
+    where
+        F: Fn(&TEventArgs, &Cell<bool>) -> Option<TResult> + 'a,
+    {
+        Handler::Guarded {
+            handler: Box::new(handler),
+            expired: Cell::new(false),
+        }
+    }
+
+    /// Calls the handler, returning `None` if it did not run.
+    pub(crate) fn call(&self, arg: &TEventArgs) -> Option<TResult> {
+        match self {
+            Handler::Persistent(handler) => Some(handler(arg)),
+            Handler::Once(handler) => handler.take().map(|handler| handler(arg)),
+            Handler::Guarded { handler, expired } => {
+                if expired.get() {
+                    return None;
+                }
+                handler(arg, expired)
+            }
+            #[cfg(feature = "time")]
+            Handler::Deferred(handler) => {
+                handler.record(arg);
+                None
+            }
+        }
+    }
+
+    /// Runs a deferred handler if it is due.
+    #[cfg(feature = "time")]
+    pub(crate) fn poll(&self, now: Instant) {
+        if let Handler::Deferred(handler) = self {
+            handler.poll(now);
+        }
+    }
+
+    /// Returns `true` if the handler will never run again and can be dropped.
+    pub(crate) fn is_spent(&self) -> bool {
+        match self {
+            Handler::Persistent(_) => false,
+            #[cfg(feature = "time")]
+            Handler::Deferred(_) => false,
+            Handler::Guarded { expired, .. } => expired.get(),
+            Handler::Once(handler) => {
+                let inner = handler.take();
+                let spent = inner.is_none();
+                handler.set(inner);
+                spent
+            }
+        }
+    }
+}
+
+/// A handler as kept in its slot.
+///
+/// Plain functions are stored as they are, without an allocation. Other
+/// handlers are reference counted, so that a snapshot of the list can share
+/// them.
+pub(crate) enum SlotHandler<'a, TEventArgs, TResult> {
+    Shared(Rc<Handler<'a, TEventArgs, TResult>>),
+    Function(fn(&TEventArgs) -> TResult),
+}
+
+impl<'a, TEventArgs, TResult> Clone for SlotHandler<'a, TEventArgs, TResult> {
+    fn clone(&self) -> Self {
+        match self {
+            SlotHandler::Shared(handler) => SlotHandler::Shared(Rc::clone(handler)),
+            SlotHandler::Function(handler) => SlotHandler::Function(*handler),
+        }
+    }
+}
+
+impl<'a, TEventArgs, TResult> From<Handler<'a, TEventArgs, TResult>>
+    for SlotHandler<'a, TEventArgs, TResult>
+{
+    fn from(handler: Handler<'a, TEventArgs, TResult>) -> Self {
+        SlotHandler::Shared(Rc::new(handler))
+    }
+}
+
+impl<'a, TEventArgs, TResult> SlotHandler<'a, TEventArgs, TResult> {
+    /// Calls the handler, returning `None` if it did not run.
+    pub(crate) fn call(&self, arg: &TEventArgs) -> Option<TResult> {
+        match self {
+            SlotHandler::Shared(handler) => handler.call(arg),
+            SlotHandler::Function(handler) => Some(handler(arg)),
+        }
+    }
+
+    /// Runs a deferred handler if it is due.
+    #[cfg(feature = "time")]
+    pub(crate) fn poll(&self, now: Instant) {
+        if let SlotHandler::Shared(handler) = self {
+            handler.poll(now);
+        }
+    }
+
+    /// Returns `true` if the handler will never run again and can be dropped.
+    pub(crate) fn is_spent(&self) -> bool {
+        match self {
+            SlotHandler::Shared(handler) => handler.is_spent(),
+            SlotHandler::Function(_) => false,
+        }
+    }
+}
+
+/// Call statistics of a single handler, see [`Event::stats`](crate::Event::stats).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HandlerStats {
+    /// Number of times the handler ran.
+    pub calls: u64,
+    /// Total time spent in the handler.
+    pub total_time: Duration,
+    /// Time spent in the most recent call of the handler.
+    pub last_time: Duration,
+}
+
+/// Information about a registered handler, see [`Event::handler_info`](crate::Event::handler_info).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandlerInfo {
+    /// The priority the handler was added with.
+    pub priority: i32,
+    /// The name of the handler, if it was added with a name.
+    pub name: Option<String>,
+    /// The tag of the handler, if it was added with a tag.
+    pub tag: Option<String>,
+}
+
+struct Slot<'a, TEventArgs, TResult> {
+    handler: SlotHandler<'a, TEventArgs, TResult>,
+    generation: u64,
+    priority: i32,
+    paused: bool,
+    stats: HandlerStats,
+    name: Option<Cow<'static, str>>,
+    tag: Option<Cow<'static, str>>,
+}
+
+/// Handler storage that keeps handlers in invocation order.
+///
+/// Handlers live in the store chosen by `TStorage`, while `order` lists their keys sorted by descending
+/// priority and then by insertion. Keys are reused after removal, so the public
+/// [`HandlerId`] pairs a key with the generation of the handler stored under it.
+///
+/// Handlers are shared with the snapshot an invocation works on, so that it does
+/// not keep the list borrowed, which lets handlers add or remove
+/// handlers (including themselves) while the event is being invoked.
+pub(crate) struct Handlers<'a, TEventArgs, TResult, TStorage: Storage> {
+    slots: TStorage::Store<Slot<'a, TEventArgs, TResult>>,
+    order: Vec<usize>,
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> Handlers<'a, TEventArgs, TResult, TStorage> {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: HandlerStore::with_capacity(capacity),
+            order: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.slots.reserve(additional);
+        self.order.reserve(additional);
+    }
+
+    /// Shrinks storage as much as possible without invalidating any key.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.slots.shrink_to_fit();
+        self.order.shrink_to_fit();
+    }
+
+    /// Inserts a handler after all handlers of the same or higher priority.
+    pub(crate) fn insert(
+        &mut self,
+        handler: SlotHandler<'a, TEventArgs, TResult>,
+        priority: i32,
+    ) -> HandlerId<TEventArgs> {
+        let generation = id::next_generation();
+        let key = self.slots.insert(Slot {
+            handler,
+            generation,
+            priority,
+            paused: false,
+            stats: HandlerStats::default(),
+            name: None,
+            tag: None,
+        });
+        let index = self
+            .order
+            .partition_point(|&other| self.slot(other).priority >= priority);
+        self.order.insert(index, key);
+        HandlerId::new(key, generation)
+    }
+
+    pub(crate) fn remove(
+        &mut self,
+        id: HandlerId<TEventArgs>,
+    ) -> Option<SlotHandler<'a, TEventArgs, TResult>> {
+        let key = self.resolve(id)?;
+        let slot = self.slots.remove(key)?;
+        self.order.retain(|&other| other != key);
+        Some(slot.handler)
+    }
+
+    /// Returns the key of the handler identified by `id`, if it is still registered.
+    fn resolve(&self, id: HandlerId<TEventArgs>) -> Option<usize> {
+        self.slots
+            .get(id.key())
+            .is_some_and(|slot| slot.generation == id.generation())
+            .then_some(id.key())
+    }
+
+    fn id(&self, key: usize) -> HandlerId<TEventArgs> {
+        HandlerId::new(key, self.slot(key).generation)
+    }
+
+    /// Returns the slot under `key`, which must be registered.
+    fn slot(&self, key: usize) -> &Slot<'a, TEventArgs, TResult> {
+        self.slots.get(key).expect("registered handler key")
+    }
+
+    fn slot_mut(&mut self, key: usize) -> &mut Slot<'a, TEventArgs, TResult> {
+        self.slots.get_mut(key).expect("registered handler key")
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.slots.clear();
+        self.order.clear();
+    }
+
+    pub(crate) fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&SlotHandler<'a, TEventArgs, TResult>) -> bool,
+    {
+        self.slots.retain(|slot| f(&slot.handler));
+        let slots = &self.slots;
+        self.order.retain(|&key| slots.get(key).is_some());
+    }
+
+    /// Returns the current handlers and their handles in invocation order.
+    pub(crate) fn snapshot(
+        &self,
+    ) -> Vec<(HandlerId<TEventArgs>, SlotHandler<'a, TEventArgs, TResult>)> {
+        self.order
+            .iter()
+            .map(|&key| (self.id(key), self.slot(key).handler.clone()))
+            .collect()
+    }
+
+    /// Records a call of a handler that took `elapsed`, if it is still registered.
+    pub(crate) fn record(&mut self, id: HandlerId<TEventArgs>, elapsed: Duration) {
+        let Some(key) = self.resolve(id) else {
+            return;
+        };
+        let stats = &mut self.slot_mut(key).stats;
+        stats.calls += 1;
+        stats.total_time += elapsed;
+        stats.last_time = elapsed;
+    }
+
+    /// Gives a handler a name, returning the handler that previously had it, if any.
+    pub(crate) fn set_name(
+        &mut self,
+        id: HandlerId<TEventArgs>,
+        name: Cow<'static, str>,
+    ) -> Option<HandlerId<TEventArgs>> {
+        let previous = self.find_named(&name);
+        self.slot_mut(id.key()).name = Some(name);
+        previous
+    }
+
+    /// Returns the name of the handler under `key`, if it has one.
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    pub(crate) fn name(&self, key: usize) -> Option<&str> {
+        self.slots.get(key)?.name.as_deref()
+    }
+
+    /// Returns the handler with the given name.
+    pub(crate) fn find_named(&self, name: &str) -> Option<HandlerId<TEventArgs>> {
+        self.slots
+            .iter()
+            .find(|(_, slot)| slot.name.as_deref() == Some(name))
+            .map(|(key, slot)| HandlerId::new(key, slot.generation))
+    }
+
+    /// Returns the names of the named handlers in invocation order.
+    pub(crate) fn names(&self) -> Vec<String> {
+        self.order
+            .iter()
+            .filter_map(|&key| self.slot(key).name.as_deref().map(str::to_owned))
+            .collect()
+    }
+
+    /// Attaches a tag to a handler.
+    pub(crate) fn set_tag(&mut self, id: HandlerId<TEventArgs>, tag: Cow<'static, str>) {
+        self.slot_mut(id.key()).tag = Some(tag);
+    }
+
+    /// Returns the handlers with the given tag.
+    pub(crate) fn find_tagged(&self, tag: &str) -> Vec<HandlerId<TEventArgs>> {
+        self.slots
+            .iter()
+            .filter(|(_, slot)| slot.tag.as_deref() == Some(tag))
+            .map(|(key, slot)| HandlerId::new(key, slot.generation))
+            .collect()
+    }
+
+    /// Returns the number of handlers that can still run.
+    pub(crate) fn len(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|(_, slot)| !slot.handler.is_spent())
+            .count()
+    }
+
+    /// Returns the handlers that can still run, in invocation order.
+    pub(crate) fn ids(&self) -> Vec<HandlerId<TEventArgs>> {
+        self.order
+            .iter()
+            .filter(|&&key| !self.slot(key).handler.is_spent())
+            .map(|&key| self.id(key))
+            .collect()
+    }
+
+    /// Returns information about a handler.
+    pub(crate) fn info(&self, id: HandlerId<TEventArgs>) -> Option<HandlerInfo> {
+        let slot = self.slot(self.resolve(id)?);
+        if slot.handler.is_spent() {
+            return None;
+        }
+        Some(HandlerInfo {
+            priority: slot.priority,
+            name: slot.name.as_deref().map(str::to_owned),
+            tag: slot.tag.as_deref().map(str::to_owned),
+        })
+    }
+
+    /// Returns the call statistics of each handler in invocation order.
+    pub(crate) fn stats(&self) -> Vec<(HandlerId<TEventArgs>, HandlerStats)> {
+        self.order
+            .iter()
+            .map(|&key| (self.id(key), self.slot(key).stats))
+            .collect()
+    }
+
+    /// Pauses or resumes a handler, returning `false` if it is not registered.
+    pub(crate) fn set_paused(&mut self, id: HandlerId<TEventArgs>, paused: bool) -> bool {
+        match self.resolve(id) {
+            Some(key) => {
+                self.slot_mut(key).paused = paused;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the handler identified by `id` is registered and paused.
+    pub(crate) fn is_paused(&self, id: HandlerId<TEventArgs>) -> bool {
+        self.resolve(id).is_some_and(|key| self.slot(key).paused)
+    }
+
+    /// Returns `true` if the handler identified by `id` is registered and not
+    /// paused.
+    ///
+    /// Compares generations, so a different handler that reused the key of a
+    /// removed one is not mistaken for it.
+    pub(crate) fn is_active(&self, id: HandlerId<TEventArgs>) -> bool {
+        self.resolve(id).is_some_and(|key| !self.slot(key).paused)
+    }
+}
+
+/// Removes a handler from its event when dropped, for the futures and streams
+/// whose handler serves no purpose once they are gone.
+///
+/// It refers to the handlers weakly, so that it does not keep the event's
+/// handlers alive after the event is dropped.
+#[cfg(feature = "futures")]
+pub(crate) struct Attachment<'a, TEventArgs, TResult, TStorage: Storage> {
+    pub(crate) handlers: Weak<RefCell<Handlers<'a, TEventArgs, TResult, TStorage>>>,
+    pub(crate) id: HandlerId<TEventArgs>,
+}
+
+#[cfg(feature = "futures")]
+impl<TEventArgs, TResult, TStorage: Storage> Drop
+    for Attachment<'_, TEventArgs, TResult, TStorage>
+{
+    fn drop(&mut self) {
+        // While the handlers are borrowed, the handler is dropped the next time
+        // its guard finds it detached instead.
+        if let Some(handlers) = self.handlers.upgrade()
+            && let Ok(mut handlers) = handlers.try_borrow_mut()
+        {
+            handlers.remove(self.id);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use crate::{Event, Storage};
+
+/// An invocation recorded in the history of an event, see
+/// [`Event::enable_history`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryEntry<TEventArgs> {
+    /// A clone of the event arguments.
+    pub args: TEventArgs,
+    /// When the event was invoked.
+    pub at: Instant,
+}
+
+/// The last invocations of an event, oldest first.
+pub(crate) struct History<TEventArgs> {
+    entries: VecDeque<HistoryEntry<TEventArgs>>,
+    capacity: usize,
+    clone: fn(&TEventArgs) -> TEventArgs,
+}
+
+impl<TEventArgs> History<TEventArgs> {
+    pub(crate) fn record(&mut self, arg: &TEventArgs) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(HistoryEntry {
+            args: (self.clone)(arg),
+            at: Instant::now(),
+        });
+    }
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> Event<'a, TEventArgs, TResult, TStorage> {
+    /// Starts recording the last `capacity` invocations of the event, see
+    /// [`history`](Self::history).
+    ///
+    /// Each invocation records a clone of the event arguments and a timestamp,
+    /// discarding the oldest entry once the history is full. Enabling the
+    /// history again changes its capacity and keeps the latest entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.enable_history(50);
+    /// ```
+    pub fn enable_history(&self, capacity: usize)
+    where
+        TEventArgs: Clone,
+    {
+        let mut history = self.history.borrow_mut();
+        let mut entries = history.take().map(|h| h.entries).unwrap_or_default();
+        entries.drain(..entries.len().saturating_sub(capacity));
+        *history = Some(History {
+            entries,
+            capacity,
+            clone: TEventArgs::clone,
+        });
+    }
+
+    /// Stops recording invocations and discards the history.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.enable_history(50);
+    /// event.invoke(&1);
+    ///
+    /// event.disable_history();
+    /// assert!(event.history().is_empty());
+    /// ```
+    pub fn disable_history(&self) {
+        self.history.borrow_mut().take();
+    }
+
+    /// Returns the recorded invocations of the event, oldest first.
+    ///
+    /// The history is empty unless enabled with
+    /// [`enable_history`](Self::enable_history). Invocations are recorded
+    /// before the middleware runs, even if the event has no handlers, but not
+    /// while it is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.enable_history(2);
+    ///
+    /// event.invoke(&1);
+    /// event.invoke(&2);
+    /// event.invoke(&3);
+    ///
+    /// let args: Vec<_> = event.history().into_iter().map(|entry| entry.args).collect();
+    /// assert_eq!(args, vec![2, 3]);
+    /// ```
+    pub fn history(&self) -> Vec<HistoryEntry<TEventArgs>>
+    where
+        TEventArgs: Clone,
+    {
+        self.history
+            .borrow()
+            .as_ref()
+            .map_or_else(Vec::new, |history| {
+                history.entries.iter().cloned().collect()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history() {
+        let event = Event::new();
+        event.invoke(&0);
+        assert!(event.history().is_empty());
+
+        event.enable_history(3);
+        event.add(|_: &i32| {});
+        for i in 1..=5 {
+            event.invoke(&i);
+        }
+        event.invoke_all(&[6, 7]);
+
+        let history = event.history();
+        let args: Vec<_> = history.iter().map(|entry| entry.args).collect();
+        assert_eq!(args, vec![5, 6, 7]);
+        assert!(history.windows(2).all(|pair| pair[0].at <= pair[1].at));
+
+        event.enable_history(1);
+        assert_eq!(event.history()[0].args, 7);
+
+        event.set_enabled(false);
+        event.invoke(&8);
+        assert_eq!(event.history()[0].args, 7);
+    }
+}
@@ -0,0 +1,213 @@
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+
+use crate::{Event, Storage};
+
+/// Type alias for callbacks that run before the handlers of an invocation, see
+/// [`Event::before_invoke`].
+///
+/// The callback receives the event arguments and the number of handlers about
+/// to run.
+pub type BeforeInvoke<'a, TEventArgs> = dyn Fn(&TEventArgs, usize) + 'a;
+
+/// Type alias for callbacks that run after the handlers of an invocation, see
+/// [`Event::after_invoke`].
+pub type AfterInvoke<'a, TEventArgs> = dyn Fn(&TEventArgs, InvokeOutcome) + 'a;
+
+/// What happened during an invocation, as reported to [`Event::after_invoke`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InvokeOutcome {
+    /// The number of handlers registered when the invocation started.
+    pub handlers: usize,
+    /// The number of handlers that ran.
+    ///
+    /// This is less than `handlers` if the invocation stopped early, e.g. with
+    /// [`invoke_control`](Event::invoke_control), handlers were removed while
+    /// it ran, or handlers skipped it, e.g. throttled handlers or ones added with
+    /// [`add_until`](Event::add_until) that just ended.
+    pub called: usize,
+}
+
+pub(crate) struct Hooks<'a, TEventArgs> {
+    before: Vec<Rc<BeforeInvoke<'a, TEventArgs>>>,
+    after: Vec<Rc<AfterInvoke<'a, TEventArgs>>>,
+}
+
+impl<'a, TEventArgs> Hooks<'a, TEventArgs> {
+    pub(crate) fn new() -> Self {
+        Self {
+            before: Vec::new(),
+            after: Vec::new(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.before.is_empty() && self.after.is_empty()
+    }
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> Event<'a, TEventArgs, TResult, TStorage> {
+    /// Adds a callback that runs before the handlers of every invocation.
+    ///
+    /// The callback is not a handler: it does not count towards
+    /// [`len`](Self::len) and cannot be removed on its own. It receives the
+    /// event arguments as the handlers see them, after the middleware, and the
+    /// number of handlers about to run. This suits concerns that must happen
+    /// once per invocation, such as acquiring a lock or starting a profiling
+    /// scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.add(|args| println!("Handler"));
+    /// event.before_invoke(|args, handlers| println!("Running {} handlers", handlers));
+    ///
+    /// event.invoke(&1); // Prints "Running 1 handlers", "Handler"
+    /// ```
+    pub fn before_invoke<F>(&self, hook: F)
+    where
+        F: Fn(&TEventArgs, usize) + 'a,
+    {
+        self.hooks.borrow_mut().before.push(Rc::new(hook));
+    }
+
+    /// Adds a callback that runs after the handlers of every invocation.
+    ///
+    /// Like [`before_invoke`](Self::before_invoke), the callback is not a
+    /// handler. It receives the event arguments and the [`InvokeOutcome`],
+    /// e.g. to mark a frame dirty only if a handler ran.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let dirty = Cell::new(false);
+    /// let event = Event::<u32>::new();
+    /// event.after_invoke(|_, outcome| dirty.set(dirty.get() || outcome.called > 0));
+    ///
+    /// event.invoke(&1);
+    /// assert!(!dirty.get());
+    ///
+    /// event.add(|args| println!("Handler"));
+    /// event.invoke(&2);
+    /// assert!(dirty.get());
+    /// ```
+    pub fn after_invoke<F>(&self, hook: F)
+    where
+        F: Fn(&TEventArgs, InvokeOutcome) + 'a,
+    {
+        self.hooks.borrow_mut().after.push(Rc::new(hook));
+    }
+
+    /// Removes all callbacks added with [`before_invoke`](Self::before_invoke)
+    /// and [`after_invoke`](Self::after_invoke).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.before_invoke(|args, handlers| println!("Before"));
+    ///
+    /// event.clear_hooks();
+    /// event.invoke(&1); // Prints nothing
+    /// ```
+    pub fn clear_hooks(&self) {
+        *self.hooks.borrow_mut() = Hooks::new();
+    }
+
+    /// Runs the before hooks, returning the number of handlers about to run.
+    pub(crate) fn run_before_hooks(&self, arg: &TEventArgs) -> usize {
+        if self.hooks.borrow().is_empty() {
+            return 0;
+        }
+        let handlers = self.dispatch().count();
+        let hooks = self.hooks.borrow().before.clone();
+        for hook in hooks {
+            hook(arg, handlers);
+        }
+        handlers
+    }
+
+    pub(crate) fn run_after_hooks(&self, arg: &TEventArgs, outcome: InvokeOutcome) {
+        if self.hooks.borrow().after.is_empty() {
+            return;
+        }
+        let hooks = self.hooks.borrow().after.clone();
+        for hook in hooks {
+            hook(arg, outcome);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::ops::ControlFlow;
+
+    use super::*;
+
+    #[test]
+    fn test_hooks() {
+        let log = RefCell::new(vec![]);
+        let event = Event::<i32, ControlFlow<()>>::new();
+        event.add(|arg| {
+            log.borrow_mut().push(format!("handler {}", arg));
+            if *arg < 0 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        event.add(|_| ControlFlow::Continue(()));
+        event.wrap(|arg, next| next(&(arg * 10)));
+        event.before_invoke(|arg, handlers| {
+            log.borrow_mut()
+                .push(format!("before {} {}", arg, handlers))
+        });
+        event.after_invoke(|arg, outcome| {
+            log.borrow_mut()
+                .push(format!("after {} {:?}", arg, outcome))
+        });
+
+        event.invoke(&1);
+        let _ = event.invoke_control(&-1);
+        event.clear_hooks();
+        event.invoke(&2);
+
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                "before 10 2",
+                "handler 10",
+                "after 10 InvokeOutcome { handlers: 2, called: 2 }",
+                "before -10 2",
+                "handler -10",
+                "after -10 InvokeOutcome { handlers: 2, called: 1 }",
+                "handler 20",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_called_counts_handlers_that_ran() {
+        let called = RefCell::new(vec![]);
+        let event = Event::<i32>::new();
+        event.add(|_| {});
+        event.add_until(|arg| *arg > 1, |_| {});
+        event.after_invoke(|_, outcome| called.borrow_mut().push(outcome.called));
+
+        event.invoke(&1);
+        event.invoke(&2);
+        event.add_until(|arg| *arg > 3, |_| {});
+        event.invoke_all(&[3, 4]);
+        assert_eq!(*called.borrow(), vec![2, 1, 2, 1]);
+    }
+}
@@ -0,0 +1,88 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::AtomicU64;
+#[cfg(not(target_has_atomic = "64"))]
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+
+/// A handle to an event handler, used to remove it later.
+///
+/// Handles are typed by the event arguments, so a handle from one event cannot be
+/// passed to an event of a different type, and they can only be obtained by adding
+/// a handler. Each handle is unique for the lifetime of the process: once its
+/// handler is removed, a handle never refers to another handler, even one that
+/// reuses the same storage slot, and it never refers to a handler of another event.
+pub struct HandlerId<TEventArgs> {
+    key: usize,
+    generation: u64,
+    marker: PhantomData<fn(&TEventArgs)>,
+}
+
+impl<TEventArgs> HandlerId<TEventArgs> {
+    pub(crate) fn new(key: usize, generation: u64) -> Self {
+        Self {
+            key,
+            generation,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the storage slot of the handler.
+    pub(crate) fn key(&self) -> usize {
+        self.key
+    }
+
+    /// Returns the process-wide unique number of the handler.
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+/// Returns a number that has not been handed out before in this process.
+#[cfg(target_has_atomic = "64")]
+pub(crate) fn next_generation() -> u64 {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    NEXT.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Returns a number that has not been handed out before in this process,
+/// counting with the native word size on targets without 64-bit atomics.
+#[cfg(not(target_has_atomic = "64"))]
+pub(crate) fn next_generation() -> u64 {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    NEXT.fetch_add(1, Ordering::Relaxed) as u64
+}
+
+impl<TEventArgs> Clone for HandlerId<TEventArgs> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<TEventArgs> Copy for HandlerId<TEventArgs> {}
+
+impl<TEventArgs> PartialEq for HandlerId<TEventArgs> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.generation == other.generation
+    }
+}
+
+impl<TEventArgs> Eq for HandlerId<TEventArgs> {}
+
+impl<TEventArgs> Hash for HandlerId<TEventArgs> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+        self.generation.hash(state);
+    }
+}
+
+impl<TEventArgs> fmt::Debug for HandlerId<TEventArgs> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HandlerId")
+            .field("key", &self.key)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
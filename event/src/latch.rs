@@ -0,0 +1,272 @@
+use core::cell::Cell;
+
+use crate::{HandlerId, OnceEvent};
+
+/// An event that fires once it has been signalled a given number of times, like
+/// a countdown latch.
+///
+/// The latch starts with a count, which [`signal`](Self::signal) decrements and
+/// [`add_count`](Self::add_count) or [`participant`](Self::participant) increment.
+/// When the count reaches zero, the latch opens and invokes its handlers. Like a
+/// [`OnceEvent`], handlers added after that are called right away, so the latch
+/// suits barriers such as "all assets loaded".
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::LatchEvent;
+///
+/// let loaded = LatchEvent::new(2);
+/// loaded.add(|| println!("All assets loaded"));
+///
+/// loaded.signal(); // Textures
+/// loaded.signal(); // Sounds, prints "All assets loaded"
+/// assert!(loaded.is_open());
+/// ```
+pub struct LatchEvent<'a> {
+    event: OnceEvent<'a, ()>,
+    remaining: Cell<usize>,
+}
+
+impl<'a> LatchEvent<'a> {
+    /// Creates a new LatchEvent that opens after `count` signals
+    ///
+    /// A latch with a count of zero is open from the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LatchEvent;
+    ///
+    /// let latch = LatchEvent::new(3);
+    /// assert_eq!(latch.remaining(), 3);
+    /// ```
+    pub fn new(count: usize) -> Self {
+        let latch = Self {
+            event: OnceEvent::new(),
+            remaining: Cell::new(count),
+        };
+        if count == 0 {
+            latch.event.invoke(());
+        }
+        latch
+    }
+
+    /// Adds an event handler to the latch.
+    ///
+    /// If the latch is open, the handler is called right away and is not stored,
+    /// and `None` is returned. Otherwise, returns a handle that can be used to
+    /// remove the handler before it fires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LatchEvent;
+    ///
+    /// let latch = LatchEvent::new(1);
+    /// let handle = latch.add(|| println!("Latch opened"));
+    /// assert!(handle.is_some());
+    /// ```
+    pub fn add<F>(&self, handler: F) -> Option<HandlerId<()>>
+    where
+        F: FnOnce() + 'a,
+    {
+        self.event.add(|_| handler())
+    }
+
+    /// Removes an event handler that has not fired yet using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LatchEvent;
+    ///
+    /// let latch = LatchEvent::new(1);
+    /// let handle = latch.add(|| println!("Latch opened")).unwrap();
+    ///
+    /// assert!(latch.remove(handle));
+    /// assert!(!latch.remove(handle)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<()>) -> bool {
+        self.event.remove(handle)
+    }
+
+    /// Decrements the count, and opens the latch when it reaches zero.
+    ///
+    /// Returns `true` if this signal opened the latch. Signals to an open latch
+    /// are ignored and return `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LatchEvent;
+    ///
+    /// let latch = LatchEvent::new(2);
+    /// assert!(!latch.signal());
+    /// assert!(latch.signal()); // Opens the latch
+    /// assert!(!latch.signal()); // Ignored
+    /// ```
+    pub fn signal(&self) -> bool {
+        match self.remaining.get() {
+            0 => false,
+            1 => {
+                self.remaining.set(0);
+                self.event.invoke(())
+            }
+            remaining => {
+                self.remaining.set(remaining - 1);
+                false
+            }
+        }
+    }
+
+    /// Increments the count by `count`, so the latch waits for more signals.
+    ///
+    /// Returns `false` and leaves the count unchanged if the latch is already open.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LatchEvent;
+    ///
+    /// let latch = LatchEvent::new(1);
+    /// assert!(latch.add_count(2));
+    /// assert_eq!(latch.remaining(), 3);
+    /// ```
+    pub fn add_count(&self, count: usize) -> bool {
+        if self.is_open() {
+            return false;
+        }
+        self.remaining.set(self.remaining.get() + count);
+        true
+    }
+
+    /// Registers a participant, which increments the count until it signals.
+    ///
+    /// The participant signals when [`LatchParticipant::signal`] is called or
+    /// when it is dropped, so the latch opens once every participant is done.
+    /// Returns `None` if the latch is already open.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LatchEvent;
+    ///
+    /// let latch = LatchEvent::new(0);
+    /// assert!(latch.participant().is_none()); // Already open
+    ///
+    /// let latch = LatchEvent::new(1);
+    /// let worker = latch.participant().unwrap();
+    /// latch.signal();
+    /// assert!(!latch.is_open()); // Still waiting for the worker
+    ///
+    /// drop(worker);
+    /// assert!(latch.is_open());
+    /// ```
+    pub fn participant(&self) -> Option<LatchParticipant<'_, 'a>> {
+        self.add_count(1)
+            .then_some(LatchParticipant { latch: self })
+    }
+
+    /// Returns the number of signals the latch is still waiting for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LatchEvent;
+    ///
+    /// let latch = LatchEvent::new(2);
+    /// latch.signal();
+    /// assert_eq!(latch.remaining(), 1);
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.remaining.get()
+    }
+
+    /// Returns `true` if the latch has opened.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LatchEvent;
+    ///
+    /// let latch = LatchEvent::new(1);
+    /// assert!(!latch.is_open());
+    ///
+    /// latch.signal();
+    /// assert!(latch.is_open());
+    /// ```
+    pub fn is_open(&self) -> bool {
+        self.event.is_invoked()
+    }
+}
+
+/// A participant of a [`LatchEvent`], which signals the latch once when it is
+/// done or dropped.
+///
+/// Created by [`LatchEvent::participant`].
+#[must_use = "dropping a LatchParticipant immediately signals its latch"]
+pub struct LatchParticipant<'l, 'a> {
+    latch: &'l LatchEvent<'a>,
+}
+
+impl<'l, 'a> LatchParticipant<'l, 'a> {
+    /// Signals the latch now.
+    ///
+    /// Returns `true` if this signal opened the latch.
+    pub fn signal(self) -> bool {
+        let latch = self.latch;
+        core::mem::forget(self);
+        latch.signal()
+    }
+}
+
+impl<'l, 'a> Drop for LatchParticipant<'l, 'a> {
+    fn drop(&mut self) {
+        self.latch.signal();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_countdown() {
+        let log = RefCell::new(vec![]);
+        let latch = LatchEvent::new(2);
+        latch.add(|| log.borrow_mut().push("early"));
+
+        assert!(!latch.signal());
+        assert!(log.borrow().is_empty());
+        assert!(latch.add_count(1));
+        assert!(!latch.signal());
+        assert!(latch.signal());
+        assert!(!latch.add_count(1));
+        latch.add(|| log.borrow_mut().push("late"));
+
+        assert_eq!(*log.borrow(), vec!["early", "late"]);
+    }
+
+    #[test]
+    fn test_participants() {
+        let opened = RefCell::new(false);
+        let latch = LatchEvent::new(0);
+        assert!(latch.is_open());
+
+        let latch = LatchEvent::new(1);
+        latch.add(|| *opened.borrow_mut() = true);
+        let first = latch.participant().unwrap();
+        let second = latch.participant().unwrap();
+        latch.signal();
+
+        assert!(!first.signal());
+        assert!(!*opened.borrow());
+        drop(second);
+        assert!(*opened.borrow());
+    }
+}
@@ -1,18 +1,201 @@
-use std::cell::RefCell;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-use slab::Slab;
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::any::Any;
+#[cfg(feature = "std")]
+use core::cell::OnceCell;
+use core::cell::{Cell, RefCell};
+use core::fmt;
+use core::ops::ControlFlow;
+#[cfg(feature = "std")]
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+pub mod aggregate;
+mod any;
+mod backpressure;
+#[cfg(feature = "tokio")]
+mod broadcast;
+#[cfg(feature = "std")]
+mod bus;
+mod cancel;
+#[cfg(feature = "std")]
+mod channel;
+mod collection;
+mod combinators;
+mod composite;
+#[cfg(feature = "critical-section")]
+mod critical;
+#[cfg(feature = "crossbeam")]
+mod crossbeam;
+#[cfg(feature = "std")]
+mod dispatch;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "std")]
+mod global;
+mod handlers;
+#[cfg(feature = "history")]
+mod history;
+mod hooks;
+mod id;
+mod latch;
+#[cfg(feature = "arc-swap")]
+mod lock_free;
+mod macros;
+mod mut_args;
+mod mux;
+#[cfg(feature = "futures")]
+mod next;
+mod once;
+mod phase;
+mod propagation;
+mod property;
+mod query;
+#[cfg(feature = "serde")]
+mod record;
+mod registry;
+#[cfg(feature = "tokio")]
+mod runtime;
+mod sender;
+mod shared;
+#[cfg(feature = "futures")]
+mod sink;
+mod split;
+mod static_event;
+mod sticky;
+mod store;
+#[cfg(feature = "futures")]
+mod stream;
+mod subscribe;
+mod subscription;
+mod suppress;
+#[cfg(feature = "std")]
+mod sync;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "std")]
+mod topic;
+#[cfg(feature = "std")]
+mod wait;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+#[cfg(feature = "futures")]
+use handlers::Attachment;
+use handlers::{Handler, Handlers, SlotHandler};
+
+pub use any::{AnyEvent, AnyEventHandler, AnyHandlerId};
+pub use backpressure::Backpressure;
+#[cfg(feature = "std")]
+pub use bus::EventBus;
+pub use cancel::Cancelable;
+#[cfg(feature = "std")]
+pub use channel::{EventRaiser, EventReceiver};
+pub use collection::{CollectionChanged, ObservableVec};
+pub use composite::CompositeEvent;
+#[cfg(feature = "critical-section")]
+pub use critical::CriticalSectionEvent;
+#[cfg(feature = "std")]
+pub use dispatch::{DispatchTask, Dispatcher};
+pub use error::EventError;
+#[cfg(feature = "derive")]
+pub use event_rs_derive::EventEmitter;
+#[cfg(feature = "std")]
+pub use global::{EventRegistry, global};
+pub use handlers::{HandlerInfo, HandlerStats};
+#[cfg(feature = "history")]
+pub use history::HistoryEntry;
+pub use hooks::{AfterInvoke, BeforeInvoke, InvokeOutcome};
+pub use id::HandlerId;
+pub use latch::{LatchEvent, LatchParticipant};
+#[cfg(feature = "arc-swap")]
+pub use lock_free::LockFreeEvent;
+#[doc(hidden)]
+pub use macros::__Downgrade;
+pub use mut_args::{EventMutArgs, EventMutArgsHandler};
+pub use mux::{EventMux, Multiplex, Variant};
+#[cfg(feature = "futures")]
+pub use next::EventNext;
+pub use once::OnceEvent;
+pub use phase::Phase;
+pub use propagation::{EventTarget, PropagatingEvent, PropagationArgs, PropagationPhase};
+pub use property::{NotifyPropertyChanged, ObservableProperty, PropertyChangedArgs, ValueChanged};
+pub use query::QueryEvent;
+#[cfg(feature = "serde")]
+pub use record::{EventRecorder, replay};
+pub use sender::{SenderEvent, SenderEventHandler};
+pub use shared::SharedEvent;
+#[cfg(feature = "std")]
+pub use shared::SharedSyncEvent;
+#[cfg(feature = "futures")]
+pub use sink::EventSink;
+#[cfg(all(feature = "futures", feature = "std"))]
+pub use sink::SyncEventSink;
+pub use split::{EventHandle, EventSource};
+pub use static_event::{CapacityError, StaticEvent};
+pub use sticky::StickyEvent;
+#[cfg(feature = "slab")]
+pub use store::SlabStorage;
+pub use store::{
+    DefaultStorage, HandlerStore, InlineStorage, InlineStore, Storage, VecStorage, VecStore,
+};
+#[cfg(feature = "futures")]
+pub use stream::EventStream;
+pub use subscribe::{Subscribe, SubscribeSync};
+pub use subscription::Subscription;
+pub use suppress::Suppression;
+#[cfg(feature = "std")]
+pub use sync::{SyncEvent, SyncEventHandler};
+#[cfg(feature = "std")]
+pub use topic::TopicBus;
 
 /// Type alias for event handlers.
 ///
-/// Each handler is a boxed function that takes a reference to event arguments.
-pub type EventHandler<'a, TEventArgs> = Box<dyn Fn(&TEventArgs) + 'a>;
+/// Each handler is a boxed function that takes a reference to event arguments
+/// and returns a `TResult`, which is `()` for plain notifications.
+pub type EventHandler<'a, TEventArgs, TResult = ()> = Box<dyn Fn(&TEventArgs) -> TResult + 'a>;
+
+/// Type alias for event middleware.
+///
+/// Middleware receives the event arguments and a `next` function that runs the
+/// rest of the invocation. See [`Event::wrap`].
+pub type Middleware<'a, TEventArgs> = dyn Fn(&TEventArgs, &dyn Fn(&TEventArgs)) + 'a;
 
 /// An event that allows multiple handlers to be attached.
 ///
 /// This structure is similar to the C# `event` pattern.
 /// You can add, remove, and invoke handlers in order.
 ///
-/// Internally, it uses a `Slab` for efficient handler storage and indexing.
+/// Handlers run in descending priority order, and in the order they were added
+/// among handlers of the same priority. Handlers added with [`add`](Self::add)
+/// have priority `0`, so without priorities handlers always run in insertion
+/// order, regardless of earlier removals.
+///
+/// Handlers may add or remove handlers, including themselves, while the event
+/// is being invoked. Such changes take effect from the next invocation, except
+/// that removed handlers that have not run yet are skipped.
+///
+/// Internally, it keeps handlers in a `Slab` for efficient storage and indexing,
+/// or in the [`Storage`] chosen with the `TStorage` type parameter.
+///
+/// Every method takes `&self`, since the handlers sit behind a `RefCell`. An
+/// observer can therefore subscribe to an event owned by a struct it only has
+/// shared access to. `SyncEvent` does the same behind a lock.
+///
+/// Handlers may return a value of type `TResult` (`()` by default), like a C#
+/// multicast delegate with a return type. Use [`invoke_collect`](Self::invoke_collect)
+/// to gather the results.
 ///
 /// # Examples
 ///
@@ -34,19 +217,70 @@ pub type EventHandler<'a, TEventArgs> = Box<dyn Fn(&TEventArgs) + 'a>;
 /// let arg = EventArgs {id: 0, message: ""};
 /// event.invoke(&arg);
 /// ```
-pub struct Event<'a, TEventArgs> {
-    handlers: RefCell<Slab<EventHandler<'a, TEventArgs>>>,
+pub struct Event<'a, TEventArgs, TResult = (), TStorage: Storage = DefaultStorage> {
+    handlers: Rc<RefCell<Handlers<'a, TEventArgs, TResult, TStorage>>>,
+    middleware: RefCell<Vec<Rc<Middleware<'a, TEventArgs>>>>,
+    queue: RefCell<VecDeque<TEventArgs>>,
+    #[cfg(feature = "std")]
+    raised: OnceCell<channel::QueueGuard<TEventArgs>>,
+    name: Option<Cow<'static, str>>,
+    max_handlers: Option<usize>,
+    stats_enabled: Cell<bool>,
+    backpressure: Cell<Backpressure>,
+    enabled: Cell<bool>,
+    held: RefCell<Option<suppress::Held<'a, TEventArgs>>>,
+    hooks: RefCell<hooks::Hooks<'a, TEventArgs>>,
+    #[cfg(feature = "history")]
+    history: RefCell<Option<history::History<TEventArgs>>>,
 }
 
-impl<'a, TEventArgs> Default for Event<'a, TEventArgs> {
+impl<'a, TEventArgs, TResult, TStorage: Storage> Default
+    for Event<'a, TEventArgs, TResult, TStorage>
+{
     fn default() -> Self {
-        Self::new()
+        Self::from_capacity(0)
+    }
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> fmt::Debug
+    for Event<'a, TEventArgs, TResult, TStorage>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let handlers = self.handlers.borrow();
+        let infos: Vec<_> = handlers
+            .ids()
+            .into_iter()
+            .filter_map(|id| Some((id, handlers.info(id)?)))
+            .collect();
+
+        f.debug_struct("Event")
+            .field("name", &self.name())
+            .field("len", &infos.len())
+            .field("handlers", &DebugMap(&infos))
+            .field("middleware", &self.middleware.borrow().len())
+            .field("queued", &self.queue.borrow().len())
+            .field("enabled", &self.is_enabled())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Formats pairs as a map.
+struct DebugMap<'a, K, V>(&'a [(K, V)]);
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for DebugMap<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(k, v)| (k, v)))
+            .finish()
     }
 }
 
-impl<'a, TEventArgs> Event<'a, TEventArgs> {
+impl<'a, TEventArgs, TResult> Event<'a, TEventArgs, TResult> {
     /// Creates a new, empty Event
     ///
+    /// The event keeps its handlers in the [`DefaultStorage`]. Events with another
+    /// [`Storage`] are created with [`Default::default`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -55,15 +289,107 @@ impl<'a, TEventArgs> Event<'a, TEventArgs> {
     /// let event: Event<()> = Event::new();
     /// ```
     pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new, empty Event with room for at least `capacity` handlers
+    ///
+    /// Handlers can be added up to that number without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event: Event<()> = Event::with_capacity(10);
+    /// assert!(event.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_capacity(capacity)
+    }
+
+    /// Creates a new, empty Event with a name used in diagnostics
+    ///
+    /// With the `tracing` feature, the name is recorded on the spans emitted
+    /// for each invocation. With the `log` feature, it appears in the debug
+    /// log lines for adding, removing and invoking handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event: Event<()> = Event::with_name("clicked");
+    /// assert_eq!(event.name(), Some("clicked"));
+    /// ```
+    pub fn with_name(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Self::new()
+        }
+    }
+
+    /// Creates a new, empty Event that accepts at most `max` handlers through
+    /// [`try_add`](Self::try_add)
+    ///
+    /// This guards against subscription leaks, e.g. a caller that subscribes
+    /// again on every request of a long-running server.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event: Event<()> = Event::with_max_handlers(100);
+    /// assert_eq!(event.max_handlers(), Some(100));
+    /// ```
+    pub fn with_max_handlers(max: usize) -> Self {
+        Self {
+            max_handlers: Some(max),
+            ..Self::new()
+        }
+    }
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> Event<'a, TEventArgs, TResult, TStorage> {
+    fn from_capacity(capacity: usize) -> Self {
         Self {
-            handlers: Slab::new().into(),
+            handlers: Rc::new(RefCell::new(Handlers::with_capacity(capacity))),
+            middleware: Vec::new().into(),
+            queue: VecDeque::new().into(),
+            #[cfg(feature = "std")]
+            raised: OnceCell::new(),
+            name: None,
+            max_handlers: None,
+            stats_enabled: Cell::new(false),
+            backpressure: Cell::new(Backpressure::Unbounded),
+            enabled: Cell::new(true),
+            held: RefCell::new(None),
+            hooks: RefCell::new(hooks::Hooks::new()),
+            #[cfg(feature = "history")]
+            history: RefCell::new(None),
         }
     }
 
+    /// Returns the name of the event, if it has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event: Event<()> = Event::new();
+    /// assert_eq!(event.name(), None);
+    /// ```
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     /// Adds an event handler to the event.
     ///
     /// The handler should be a closure that accepts a reference to the event arguments
-    /// and returns nothing. The closure will be executed when the event is invoked.
+    /// and returns a `TResult` (nothing, by default). The closure will be executed when
+    /// the event is invoked.
     ///
     /// Returns a handle that can be used to remove the handler later.
     ///
@@ -77,16 +403,88 @@ impl<'a, TEventArgs> Event<'a, TEventArgs> {
     ///     println!("Event invoked");
     /// });
     /// ```
-    pub fn add<F>(&self, handler: F) -> usize
+    pub fn add<F>(&self, handler: F) -> HandlerId<TEventArgs>
     where
-        F: Fn(&TEventArgs) + 'a,
+        F: Fn(&TEventArgs) -> TResult + 'a,
     {
-        self.handlers.borrow_mut().insert(Box::new(handler))
+        self.add_with_priority(0, handler)
     }
 
-    /// Removes an event handler using its handle.
+    /// Adds an event handler to the event with the given priority.
     ///
-    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    /// Handlers with a higher priority are invoked first. Handlers with the same
+    /// priority are invoked in the order they were added.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<(), &str>::new();
+    /// event.add(|_| "normal");
+    /// event.add_with_priority(10, |_| "first");
+    /// event.add_with_priority(-10, |_| "last");
+    ///
+    /// assert_eq!(event.invoke_collect(&()), vec!["first", "normal", "last"]);
+    /// ```
+    pub fn add_with_priority<F>(&self, priority: i32, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        self.insert_with_priority(Handler::persistent(handler), priority)
+    }
+
+    /// Adds an event handler to the event, unless it already has the maximum
+    /// number of handlers set with [`with_max_handlers`](Event::with_max_handlers).
+    ///
+    /// Returns a handle that can be used to remove the handler later, or
+    /// [`EventError::CapacityExceeded`] if the event is full. Without a maximum,
+    /// this is the same as [`add`](Self::add).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Event, EventError};
+    ///
+    /// let event = Event::<u32>::with_max_handlers(1);
+    /// assert!(event.try_add(|args| println!("Handler 1")).is_ok());
+    /// assert!(matches!(
+    ///     event.try_add(|args| println!("Handler 2")),
+    ///     Err(EventError::CapacityExceeded)
+    /// ));
+    /// ```
+    pub fn try_add<F>(&self, handler: F) -> Result<HandlerId<TEventArgs>, EventError>
+    where
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        if self.max_handlers.is_some_and(|max| self.len() >= max) {
+            return Err(EventError::CapacityExceeded);
+        }
+        Ok(self.add(handler))
+    }
+
+    /// Returns the maximum number of handlers set with
+    /// [`with_max_handlers`](Event::with_max_handlers), if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event: Event<()> = Event::new();
+    /// assert_eq!(event.max_handlers(), None);
+    /// ```
+    pub fn max_handlers(&self) -> Option<usize> {
+        self.max_handlers
+    }
+
+    /// Adds an event handler and returns a guard that removes it when dropped.
+    ///
+    /// This is the RAII alternative to [`add`](Self::add): the handler cannot
+    /// outlive the [`Subscription`], so it cannot be leaked by forgetting to
+    /// call [`remove`](Self::remove).
     ///
     /// # Examples
     ///
@@ -94,18 +492,32 @@ impl<'a, TEventArgs> Event<'a, TEventArgs> {
     /// use event_rs::Event;
     ///
     /// let event = Event::<()>::new();
-    /// let handle = event.add(|args| {
+    /// let subscription = event.subscribe(|args| {
     ///     println!("Event invoked");
     /// });
     ///
-    /// assert!(event.remove(handle));
-    /// assert!(!event.remove(handle)); // Already removed
+    /// event.invoke(&()); // Prints the message
+    /// drop(subscription);
+    /// event.invoke(&()); // Does nothing, the handler is gone
     /// ```
-    pub fn remove(&self, handle: usize) -> bool {
-        self.handlers.borrow_mut().try_remove(handle).is_some()
+    pub fn subscribe<F>(&self, handler: F) -> Subscription<'_, 'a, TEventArgs, TResult, TStorage>
+    where
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        Subscription::new(self, self.add(handler))
     }
 
-    /// Removes all event handlers.
+    /// Adds a stateful event handler to the event.
+    ///
+    /// Unlike [`add`](Self::add), the handler may be a `FnMut` closure that mutates
+    /// its captured state directly, without wrapping it in a `Cell` or `RefCell`.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handler is invoked again while it is still running,
+    /// e.g. when it re-raises the same event from within itself.
     ///
     /// # Examples
     ///
@@ -113,61 +525,2022 @@ impl<'a, TEventArgs> Event<'a, TEventArgs> {
     /// use event_rs::Event;
     ///
     /// let event = Event::<()>::new();
-    /// event.add(|args| { println!("Handler 1"); });
-    /// event.add(|args| { println!("Handler 2"); });
+    /// let mut count = 0;
+    /// event.add_mut(move |args| {
+    ///     count += 1;
+    ///     println!("Event invoked {} times", count);
+    /// });
+    /// ```
+    pub fn add_mut<F>(&self, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: FnMut(&TEventArgs) -> TResult + 'a,
+    {
+        let handler = RefCell::new(handler);
+        self.add(move |arg| (handler.borrow_mut())(arg))
+    }
+
+    /// Adds a one-shot event handler to the event.
+    ///
+    /// The handler is automatically removed after the first invocation, so it
+    /// may be a `FnOnce` closure that consumes its captured state.
+    ///
+    /// Returns a handle that can be used to remove the handler before it fires.
+    ///
+    /// # Examples
     ///
-    /// event.clear(); // Remove all handlers
     /// ```
-    pub fn clear(&self) {
-        self.handlers.borrow_mut().clear();
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// let message = String::from("Event invoked");
+    /// event.once(move |args| {
+    ///     println!("{}", message);
+    /// });
+    ///
+    /// event.invoke(&()); // Prints the message
+    /// event.invoke(&()); // Does nothing, the handler is gone
+    /// ```
+    pub fn once<F>(&self, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: FnOnce(&TEventArgs) -> TResult + 'a,
+    {
+        self.insert(Handler::once(handler))
     }
 
-    /// Invokes all event handlers sequentially (one after another).
+    /// Adds a plain function as an event handler.
     ///
-    /// Each handler is awaited before the next one is executed.
+    /// This behaves like [`add`](Self::add), but stores the function pointer
+    /// itself instead of a shared closure, so adding it does not allocate once
+    /// the event has room, and calling it does not go through a vtable. It suits
+    /// events with many handlers that are free functions.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
     ///
     /// # Examples
     ///
     /// ```
     /// use event_rs::Event;
     ///
-    /// let event = Event::new();
-    /// event.add(|args| { println!("Handler 1"); });
-    /// event.add(|args| { println!("Handler 2"); });
+    /// fn on_saved(path: &&str) {
+    ///     println!("Saved {}", path);
+    /// }
     ///
-    /// event.invoke(&()); // Execute all handlers in order
+    /// let event = Event::<&str>::new();
+    /// event.add_fn(on_saved);
+    ///
+    /// event.invoke(&"notes.txt"); // Prints "Saved notes.txt"
     /// ```
-    pub fn invoke(&self, arg: &TEventArgs) {
-        for (_, handler) in self.handlers.borrow().iter() {
-            handler(arg);
-        }
+    pub fn add_fn(&self, handler: fn(&TEventArgs) -> TResult) -> HandlerId<TEventArgs> {
+        self.insert(SlotHandler::Function(handler))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::cell::RefCell;
+    /// Adds an event handler that is automatically removed after it has run `times`
+    /// times.
+    ///
+    /// This generalizes [`once`](Self::once), e.g. for sampling the first few
+    /// invocations or for a bounded number of retries. A handler added with
+    /// `times` equal to `0` never runs.
+    ///
+    /// Returns a handle that can be used to remove the handler earlier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.add_times(2, |args| println!("Sample {}", args));
+    ///
+    /// event.invoke(&1); // Prints "Sample 1"
+    /// event.invoke(&2); // Prints "Sample 2"
+    /// event.invoke(&3); // Does nothing, the handler is gone
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn add_times<F>(&self, times: usize, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        let remaining = Cell::new(times);
+        self.insert(Handler::Guarded {
+            handler: Box::new(move |arg, expired| {
+                let left = remaining.get().checked_sub(1)?;
+                remaining.set(left);
+                expired.set(left == 0);
+                Some(handler(arg))
+            }),
+            expired: Cell::new(times == 0),
+        })
+    }
 
-    use super::*;
+    /// Adds an event handler that is tied to the lifetime of an `Rc` owner.
+    ///
+    /// The event only keeps a weak reference to the owner, so it does not keep the
+    /// owner alive. The handler receives the owner along with the event arguments,
+    /// and is skipped and removed once the owner has been dropped.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// struct Counter {
+    ///     count: Cell<u32>,
+    /// }
+    ///
+    /// let event = Event::<()>::new();
+    /// let counter = Rc::new(Counter { count: Cell::new(0) });
+    /// event.add_weak(&counter, |counter, args| {
+    ///     counter.count.set(counter.count.get() + 1);
+    /// });
+    ///
+    /// event.invoke(&());
+    /// assert_eq!(counter.count.get(), 1);
+    ///
+    /// drop(counter);
+    /// event.invoke(&()); // The handler is skipped and removed
+    /// ```
+    pub fn add_weak<O, F>(&self, owner: &Rc<O>, handler: F) -> HandlerId<TEventArgs>
+    where
+        O: 'a,
+        F: Fn(&O, &TEventArgs) -> TResult + 'a,
+    {
+        let owner = Rc::downgrade(owner);
+        self.insert(Handler::guarded(move |arg, expired| {
+            match owner.upgrade() {
+                Some(owner) => Some(handler(&owner, arg)),
+                None => {
+                    expired.set(true);
+                    None
+                }
+            }
+        }))
+    }
 
-    #[test]
-    fn test_invoke() {
-        let counter = RefCell::new(0);
-        let event = Event::new();
+    /// Adds an event handler that is tied to the lifetime of an `Rc` owner, and
+    /// returns a guard that removes it when dropped.
+    ///
+    /// This combines [`add_weak`](Self::add_weak) with [`subscribe`](Self::subscribe),
+    /// which is the usual shape of a connection from an event to a method of
+    /// its listener, see [`connect!`](crate::connect).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// struct Window;
+    ///
+    /// impl Window {
+    ///     fn on_resized(&self, size: &(u32, u32)) {
+    ///         println!("Resized to {:?}", size);
+    ///     }
+    /// }
+    ///
+    /// let resized = Event::new();
+    /// let window = Rc::new(Window);
+    /// let subscription = resized.subscribe_weak(&window, Window::on_resized);
+    ///
+    /// resized.invoke(&(800, 600)); // Prints "Resized to (800, 600)"
+    /// ```
+    pub fn subscribe_weak<O, F>(
+        &self,
+        owner: &Rc<O>,
+        handler: F,
+    ) -> Subscription<'_, 'a, TEventArgs, TResult, TStorage>
+    where
+        O: 'a,
+        F: Fn(&O, &TEventArgs) -> TResult + 'a,
+    {
+        Subscription::new(self, self.add_weak(owner, handler))
+    }
 
-        event.invoke(&());
+    /// Adds an event handler that only runs when `predicate` returns `true`
+    /// for the event arguments.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.add_filtered(|value| value % 2 == 0, |value| {
+    ///     println!("Even value: {}", value);
+    /// });
+    ///
+    /// event.invoke(&1); // Filtered out
+    /// event.invoke(&2); // Prints "Even value: 2"
+    /// ```
+    pub fn add_filtered<P, F>(&self, predicate: P, handler: F) -> HandlerId<TEventArgs>
+    where
+        P: Fn(&TEventArgs) -> bool + 'a,
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        self.insert(Handler::guarded(move |arg, _| {
+            predicate(arg).then(|| handler(arg))
+        }))
+    }
 
-        event.add(|_| {
-            *counter.borrow_mut() += 1;
-        });
+    /// Adds an event handler that removes itself once `predicate` returns `true`
+    /// for the event arguments.
+    ///
+    /// The predicate is checked before the handler runs, so the handler does not
+    /// see the event arguments that end the subscription. See
+    /// [`add_until_inclusive`](Self::add_until_inclusive) for the opposite.
+    ///
+    /// Returns a handle that can be used to remove the handler earlier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let keys = Event::<char>::new();
+    /// keys.add_until(|key| *key == '\n', |key| println!("Typed {}", key));
+    ///
+    /// keys.invoke(&'a'); // Prints "Typed a"
+    /// keys.invoke(&'\n'); // Removes the handler
+    /// assert!(keys.is_empty());
+    /// ```
+    pub fn add_until<P, F>(&self, predicate: P, handler: F) -> HandlerId<TEventArgs>
+    where
+        P: Fn(&TEventArgs) -> bool + 'a,
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        self.insert(Handler::guarded(move |arg, expired| {
+            if predicate(arg) {
+                expired.set(true);
+                None
+            } else {
+                Some(handler(arg))
+            }
+        }))
+    }
 
-        event.add(|_| {
-            *counter.borrow_mut() += 1;
-        });
+    /// Adds an event handler that removes itself once `predicate` returns `true`
+    /// for the event arguments, after running for them.
+    ///
+    /// Unlike [`add_until`](Self::add_until), the predicate is checked after the
+    /// handler runs, so the handler also sees the event arguments that end the
+    /// subscription.
+    ///
+    /// Returns a handle that can be used to remove the handler earlier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let progress = Event::<u32>::new();
+    /// progress.add_until_inclusive(|percent| *percent == 100, |percent| {
+    ///     println!("{}% done", percent);
+    /// });
+    ///
+    /// progress.invoke(&50); // Prints "50% done"
+    /// progress.invoke(&100); // Prints "100% done" and removes the handler
+    /// assert!(progress.is_empty());
+    /// ```
+    pub fn add_until_inclusive<P, F>(&self, predicate: P, handler: F) -> HandlerId<TEventArgs>
+    where
+        P: Fn(&TEventArgs) -> bool + 'a,
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        self.insert(Handler::guarded(move |arg, expired| {
+            let result = handler(arg);
+            expired.set(predicate(arg));
+            Some(result)
+        }))
+    }
 
-        event.invoke(&());
-        event.invoke(&());
-        assert_eq!(*counter.borrow(), 4);
+    /// Adds an event handler under the given name.
+    ///
+    /// Names identify handlers where plumbing handles around is impractical,
+    /// e.g. handlers registered by plugins from configuration. A name refers to at
+    /// most one handler: adding a handler under a name that is already taken
+    /// replaces the previous handler.
+    ///
+    /// Returns a handle that can be used to remove the handler later, either
+    /// with [`remove`](Self::remove) or by name with [`remove_named`](Self::remove_named).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// event.add_named("autosave", |args| println!("Saving"));
+    /// event.add_named("autosave", |args| println!("Saving again")); // Replaces the first one
+    ///
+    /// assert_eq!(event.names(), vec!["autosave"]);
+    /// ```
+    pub fn add_named<F>(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+        handler: F,
+    ) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        let handle = self.add(handler);
+        let previous = self.handlers.borrow_mut().set_name(handle, name.into());
+        if let Some(previous) = previous {
+            self.remove(previous);
+        }
+        handle
+    }
+
+    /// Removes the event handler with the given name.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// event.add_named("autosave", |args| println!("Saving"));
+    ///
+    /// assert!(event.remove_named("autosave"));
+    /// assert!(!event.remove_named("autosave")); // Already removed
+    /// ```
+    pub fn remove_named(&self, name: &str) -> bool {
+        let handle = self.handlers.borrow().find_named(name);
+        handle.is_some_and(|handle| self.remove(handle))
+    }
+
+    /// Returns the names of the named handlers in invocation order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// event.add_named("autosave", |args| println!("Saving"));
+    /// event.add(|args| println!("Anonymous"));
+    /// event.add_with_priority(1, |args| println!("Also anonymous"));
+    /// event.add_named("backup", |args| println!("Backing up"));
+    ///
+    /// assert_eq!(event.names(), vec!["autosave", "backup"]);
+    /// ```
+    pub fn names(&self) -> Vec<String> {
+        self.handlers.borrow().names()
+    }
+
+    /// Adds an event handler with a tag attached.
+    ///
+    /// Any number of handlers can share a tag, e.g. the ID of the plugin that
+    /// registered them, so that they can all be removed at once with
+    /// [`remove_by_tag`](Self::remove_by_tag).
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// let handle = event.add_tagged("spell-checker", |args| {
+    ///     println!("Checking spelling");
+    /// });
+    /// ```
+    pub fn add_tagged<F>(
+        &self,
+        tag: impl Into<Cow<'static, str>>,
+        handler: F,
+    ) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        let handle = self.add(handler);
+        self.handlers.borrow_mut().set_tag(handle, tag.into());
+        handle
+    }
+
+    /// Removes all event handlers with the given tag.
+    ///
+    /// Returns the number of handlers removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// event.add_tagged("spell-checker", |args| println!("Checking spelling"));
+    /// event.add_tagged("spell-checker", |args| println!("Checking grammar"));
+    /// event.add(|args| println!("Untagged"));
+    ///
+    /// assert_eq!(event.remove_by_tag("spell-checker"), 2); // Unload the plugin
+    /// ```
+    pub fn remove_by_tag(&self, tag: &str) -> usize {
+        let handles = self.handlers.borrow().find_tagged(tag);
+        handles
+            .into_iter()
+            .filter(|&handle| self.remove(handle))
+            .count()
+    }
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    /// A handler that can no longer run (a one-shot handler that has already
+    /// fired, or a weak handler whose owner was dropped) counts as removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// let handle = event.add(|args| {
+    ///     println!("Event invoked");
+    /// });
+    ///
+    /// assert!(event.remove(handle));
+    /// assert!(!event.remove(handle)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        let removed = self
+            .handlers
+            .borrow_mut()
+            .remove(handle)
+            .is_some_and(|handler| !handler.is_spent());
+        #[cfg(feature = "log")]
+        if removed {
+            log::debug!(
+                "removed handler {:?} from event {}",
+                handle,
+                self.log_name()
+            );
+        }
+        removed
+    }
+
+    /// Pauses an event handler using its handle, so that it is skipped by
+    /// invocations until it is resumed.
+    ///
+    /// Unlike removing and adding it again, this keeps the handle of the handler
+    /// and its place in the invocation order.
+    ///
+    /// Returns `true` if the handler was found, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<(), &str>::new();
+    /// let first = event.add(|_| "first");
+    /// event.add(|_| "second");
+    ///
+    /// assert!(event.pause(first));
+    /// assert_eq!(event.invoke_collect(&()), vec!["second"]);
+    ///
+    /// assert!(event.resume(first));
+    /// assert_eq!(event.invoke_collect(&()), vec!["first", "second"]);
+    /// ```
+    pub fn pause(&self, handle: HandlerId<TEventArgs>) -> bool {
+        self.handlers.borrow_mut().set_paused(handle, true)
+    }
+
+    /// Resumes an event handler paused with [`pause`](Self::pause).
+    ///
+    /// Returns `true` if the handler was found, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// let handle = event.add(|args| println!("Event invoked"));
+    ///
+    /// event.pause(handle);
+    /// assert!(event.resume(handle));
+    /// assert!(!event.is_paused(handle));
+    /// ```
+    pub fn resume(&self, handle: HandlerId<TEventArgs>) -> bool {
+        self.handlers.borrow_mut().set_paused(handle, false)
+    }
+
+    /// Returns `true` if the handler is registered and paused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// let handle = event.add(|args| println!("Event invoked"));
+    ///
+    /// event.pause(handle);
+    /// assert!(event.is_paused(handle));
+    /// ```
+    pub fn is_paused(&self, handle: HandlerId<TEventArgs>) -> bool {
+        self.handlers.borrow().is_paused(handle)
+    }
+
+    /// Returns the number of event handlers.
+    ///
+    /// Handlers that can no longer run, such as one-shot handlers that already fired,
+    /// are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// event.add(|args| println!("Handler 1"));
+    /// event.add(|args| println!("Handler 2"));
+    ///
+    /// assert_eq!(event.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.handlers.borrow().len()
+    }
+
+    /// Returns `true` if the event has no handlers.
+    ///
+    /// This lets callers skip building expensive event arguments nobody listens to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<String>::new();
+    /// if !event.is_empty() {
+    ///     event.invoke(&"expensive".repeat(1000));
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of handlers the event can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event: Event<()> = Event::with_capacity(10);
+    /// assert!(event.capacity() >= 10);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.handlers.borrow().capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event: Event<()> = Event::new();
+    /// event.reserve(10);
+    /// assert!(event.capacity() >= 10);
+    /// ```
+    pub fn reserve(&self, additional: usize) {
+        self.handlers.borrow_mut().reserve(additional);
+    }
+
+    /// Shrinks the capacity of the event as much as possible.
+    ///
+    /// Existing handlers keep their storage slots, so the capacity cannot shrink
+    /// below the highest slot still in use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event: Event<()> = Event::with_capacity(100);
+    /// event.add(|args| println!("Handler 1"));
+    ///
+    /// event.shrink_to_fit();
+    /// assert!(event.capacity() < 100);
+    /// ```
+    pub fn shrink_to_fit(&self) {
+        self.handlers.borrow_mut().shrink_to_fit();
+    }
+
+    /// Returns the handles of all event handlers in invocation order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// let first = event.add(|args| println!("Handler 1"));
+    /// let second = event.add_with_priority(1, |args| println!("Handler 2"));
+    ///
+    /// assert_eq!(event.handles(), vec![second, first]);
+    /// ```
+    pub fn handles(&self) -> Vec<HandlerId<TEventArgs>> {
+        self.handlers.borrow().ids()
+    }
+
+    /// Returns information about the event handler with the given handle,
+    /// or `None` if there is no such handler.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// let handle = event.add_named("autosave", |args| println!("Saving"));
+    ///
+    /// let info = event.handler_info(handle).unwrap();
+    /// assert_eq!(info.priority, 0);
+    /// assert_eq!(info.name.as_deref(), Some("autosave"));
+    /// ```
+    pub fn handler_info(&self, handle: HandlerId<TEventArgs>) -> Option<HandlerInfo> {
+        self.handlers.borrow().info(handle)
+    }
+
+    /// Removes all event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// event.add(|args| { println!("Handler 1"); });
+    /// event.add(|args| { println!("Handler 2"); });
+    ///
+    /// event.clear(); // Remove all handlers
+    /// ```
+    pub fn clear(&self) {
+        self.handlers.borrow_mut().clear();
+        #[cfg(feature = "log")]
+        log::debug!("cleared event {}", self.log_name());
+    }
+
+    /// Invokes all event handlers sequentially (one after another).
+    ///
+    /// Each handler returns before the next one is executed, and its result is
+    /// discarded. For handlers that return futures, see the `async-event-rs` crate.
+    ///
+    /// The invocation runs over a snapshot of the handlers taken when it starts,
+    /// so handlers may modify the event or invoke it again without invalidating
+    /// it. Handlers added in the meantime are never part of the running dispatch,
+    /// even if they take the storage slot of a removed one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::new();
+    /// event.add(|args| { println!("Handler 1"); });
+    /// event.add(|args| { println!("Handler 2"); });
+    ///
+    /// event.invoke(&()); // Execute all handlers in order
+    /// ```
+    pub fn invoke(&self, arg: &TEventArgs) {
+        self.invoke_with(arg, |call| {
+            call();
+            ControlFlow::Continue(())
+        });
+    }
+
+    /// Invokes the event once for each of the event arguments in `args`, in order.
+    ///
+    /// This is equivalent to calling [`invoke`](Self::invoke) for each of them,
+    /// except that the handlers are looked up once for the whole batch, which
+    /// saves work when many invocations are emitted at once, e.g. per frame.
+    /// Handlers added during the batch are not called until the next invocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.add(|args| { println!("Particle {} moved", args); });
+    ///
+    /// event.invoke_all(&[1, 2, 3]); // Prints for particles 1, 2 and 3
+    /// ```
+    pub fn invoke_all(&self, args: &[TEventArgs]) {
+        if !self.is_enabled() {
+            args.iter().for_each(|arg| self.hold(arg));
+            return;
+        }
+
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("invoke_all", event = self.name(), count = args.len()).entered();
+        #[cfg(feature = "log")]
+        log::debug!(
+            "invoking event {} {} times with {} handlers",
+            self.log_name(),
+            args.len(),
+            self.len()
+        );
+
+        let snapshot = self.handlers.borrow().snapshot();
+        for arg in args {
+            self.intercept(arg, &|arg| {
+                let handlers = self.run_before_hooks(arg);
+                let mut called = 0;
+                for (handle, handler) in &snapshot {
+                    if self.handlers.borrow().is_active(*handle)
+                        && self.call(*handle, handler, arg).is_some()
+                    {
+                        called += 1;
+                    }
+                }
+                self.run_after_hooks(arg, InvokeOutcome { handlers, called });
+            });
+        }
+
+        self.purge_spent();
+    }
+
+    /// Invokes all event handlers sequentially and collects their results
+    /// in invocation order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<&str, usize>::new();
+    /// event.add(|args| args.len());
+    /// event.add(|args| args.matches('l').count());
+    ///
+    /// assert_eq!(event.invoke_collect(&"hello"), vec![5, 2]);
+    /// ```
+    pub fn invoke_collect(&self, arg: &TEventArgs) -> Vec<TResult> {
+        let mut results = Vec::new();
+        self.invoke_with(arg, |call| {
+            results.extend(call());
+            ControlFlow::Continue(())
+        });
+        results
+    }
+
+    /// Invokes all event handlers sequentially, isolating them from each other's panics.
+    ///
+    /// Each handler call is wrapped in [`catch_unwind`](std::panic::catch_unwind), so a
+    /// panicking handler does not prevent the remaining handlers from running.
+    /// Returns `Ok(())` if no handler panicked, or `Err` with the panic payloads in
+    /// invocation order otherwise. The panic hook still runs for each panic.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::new();
+    /// event.add(|args| panic!("Handler 1 failed"));
+    /// event.add(|args| { println!("Handler 2 still runs"); });
+    ///
+    /// let panics = event.invoke_isolated(&()).unwrap_err();
+    /// assert_eq!(panics.len(), 1);
+    /// assert_eq!(panics[0].downcast_ref::<&str>(), Some(&"Handler 1 failed"));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn invoke_isolated(&self, arg: &TEventArgs) -> Result<(), Vec<Box<dyn Any + Send>>> {
+        let mut panics = Vec::new();
+        self.invoke_with(arg, |call| {
+            if let Err(panic) = panic::catch_unwind(AssertUnwindSafe(call)) {
+                panics.push(panic);
+            }
+            ControlFlow::Continue(())
+        });
+
+        if panics.is_empty() {
+            Ok(())
+        } else {
+            Err(panics)
+        }
+    }
+
+    /// Adds middleware that runs around every invocation of the event.
+    ///
+    /// The middleware receives the event arguments and a `next` function which runs
+    /// the rest of the invocation: inner middleware, then the handlers. It can act
+    /// before and after calling `next`, call `next` with different arguments, or not
+    /// call it at all to suppress the invocation. Middleware added later wraps
+    /// middleware added earlier.
+    ///
+    /// This applies to every way of invoking the event, and suits cross-cutting
+    /// concerns such as logging or timing that should not be repeated in each handler.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<i32>::new();
+    /// event.add(|args| { println!("Handler sees {}", args); });
+    ///
+    /// event.wrap(|args, next| {
+    ///     println!("Before");
+    ///     next(&args.abs());
+    ///     println!("After");
+    /// });
+    ///
+    /// event.invoke(&-1); // Prints "Before", "Handler sees 1", "After"
+    /// ```
+    pub fn wrap<F>(&self, middleware: F)
+    where
+        F: Fn(&TEventArgs, &dyn Fn(&TEventArgs)) + 'a,
+    {
+        self.middleware.borrow_mut().push(Rc::new(middleware));
+    }
+
+    /// Queues an invocation to be delivered by the next call to [`pump`](Self::pump).
+    ///
+    /// This defers event delivery to a point of the owner's choosing, e.g. a
+    /// specific phase of a game loop or GUI frame. How many invocations the
+    /// queue holds is set with [`set_backpressure`](Self::set_backpressure).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::new();
+    /// event.add(|args| { println!("Frame {}", args); });
+    ///
+    /// event.post(1); // Nothing is printed yet
+    /// event.post(2);
+    /// event.pump(); // Prints "Frame 1", then "Frame 2"
+    /// ```
+    pub fn post(&self, arg: TEventArgs) {
+        let mut arg = arg;
+        loop {
+            let rejected = self
+                .backpressure
+                .get()
+                .push(&mut self.queue.borrow_mut(), arg);
+            let Err(rejected) = rejected else {
+                return;
+            };
+
+            // The queue is full and blocks the poster, who delivers the oldest
+            // invocation to make room, or the rejected one if nothing is queued.
+            let oldest = self.queue.borrow_mut().pop_front();
+            match oldest {
+                Some(oldest) => self.invoke(&oldest),
+                None => return self.invoke(&rejected),
+            }
+            arg = rejected;
+        }
+    }
+
+    /// Sets what [`post`](Self::post) does once the queue is full.
+    ///
+    /// With [`Backpressure::Block`], the poster is held up by delivering the
+    /// oldest queued invocation itself, since the event is confined to one
+    /// thread. Invocations are queued without a limit by default.
+    ///
+    /// The policy also applies to the invocations raised through an
+    /// [`EventRaiser`], which are queued separately. There,
+    /// [`Backpressure::Block`] holds up the raising thread until the owner
+    /// calls [`pump`](Self::pump), and with a capacity of 0 until the owner has
+    /// taken the raised invocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Backpressure, Event};
+    ///
+    /// let sample = Event::<f32>::new();
+    /// sample.add(|value| println!("Sample {}", value));
+    /// sample.set_backpressure(Backpressure::DropOldest(2));
+    ///
+    /// sample.post(1.0);
+    /// sample.post(2.0);
+    /// sample.post(3.0); // Drops 1.0
+    /// assert_eq!(sample.pump(), 2); // Prints "Sample 2", then "Sample 3"
+    /// ```
+    pub fn set_backpressure(&self, policy: Backpressure) {
+        self.backpressure.set(policy);
+        #[cfg(feature = "std")]
+        if let Some(raised) = self.raised.get() {
+            raised.0.set_policy(policy);
+        }
+    }
+
+    /// Enables or disables coalescing of posted invocations.
+    ///
+    /// While coalescing, each call to [`post`](Self::post) replaces the invocations
+    /// waiting in the queue, so the next [`pump`](Self::pump) only delivers the
+    /// latest one. This suits events where only the current state matters, such as
+    /// window resizes. Coalescing is disabled by default.
+    ///
+    /// This is the same as setting [`Backpressure::Coalesce`] with
+    /// [`set_backpressure`](Self::set_backpressure), or [`Backpressure::Unbounded`]
+    /// to disable it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let resized = Event::<(u32, u32)>::new();
+    /// resized.add(|(width, height)| println!("Resized to {}x{}", width, height));
+    /// resized.set_coalescing(true);
+    ///
+    /// resized.post((640, 480));
+    /// resized.post((800, 600));
+    /// assert_eq!(resized.pump(), 1); // Prints "Resized to 800x600"
+    /// ```
+    pub fn set_coalescing(&self, enabled: bool) {
+        self.set_backpressure(if enabled {
+            Backpressure::Coalesce
+        } else {
+            Backpressure::Unbounded
+        });
+    }
+
+    /// Enables or disables the event.
+    ///
+    /// While the event is disabled, invoking it in any way does nothing, so
+    /// neither middleware nor handlers run. Handlers can still be added and
+    /// removed. Events are enabled by default. See also [`suppress`](Self::suppress).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<(), &str>::new();
+    /// event.add(|_| "called");
+    ///
+    /// event.set_enabled(false);
+    /// assert!(event.invoke_collect(&()).is_empty());
+    ///
+    /// event.set_enabled(true);
+    /// assert_eq!(event.invoke_collect(&()), vec!["called"]);
+    /// ```
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.set(enabled);
+    }
+
+    /// Returns `true` if the event is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// assert!(event.is_enabled());
+    /// ```
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.get()
+    }
+
+    /// Invokes the event once for each queued invocation, in the order they were posted.
+    ///
+    /// Invocations posted while pumping are kept for the next call, so a handler
+    /// that posts to its own event cannot keep the pump running forever.
+    ///
+    /// With the `std` feature, this then delivers the invocations raised from other
+    /// threads through an [`EventRaiser`], in the order they were raised.
+    ///
+    /// With the `time` feature, this also runs debounced handlers whose quiet
+    /// period has elapsed.
+    ///
+    /// Returns the number of invocations delivered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::new();
+    /// event.add(|args| { println!("Frame {}", args); });
+    ///
+    /// event.post(1);
+    /// assert_eq!(event.pump(), 1);
+    /// assert_eq!(event.pump(), 0); // The queue is empty
+    /// ```
+    pub fn pump(&self) -> usize {
+        let queue = self.queue.take();
+        let count = queue.len();
+        for arg in queue {
+            self.invoke(&arg);
+        }
+
+        #[cfg(feature = "std")]
+        let count = match self.raised.get() {
+            // Only what has been raised so far, so that a busy raiser cannot keep
+            // the pump running forever either.
+            Some(raised) => {
+                let raised = raised.0.take();
+                for arg in &raised {
+                    self.invoke(arg);
+                }
+                count + raised.len()
+            }
+            None => count,
+        };
+
+        #[cfg(feature = "time")]
+        {
+            let now = std::time::Instant::now();
+            for (_, handler) in self.dispatch() {
+                handler.poll(now);
+            }
+        }
+
+        count
+    }
+
+    /// Registers a handler with the default priority.
+    pub(crate) fn insert(
+        &self,
+        handler: impl Into<SlotHandler<'a, TEventArgs, TResult>>,
+    ) -> HandlerId<TEventArgs> {
+        self.insert_with_priority(handler, 0)
+    }
+
+    /// Adds a handler that is removed again when the returned attachment is
+    /// dropped.
+    #[cfg(feature = "futures")]
+    pub(crate) fn attach(
+        &self,
+        handler: impl Into<SlotHandler<'a, TEventArgs, TResult>>,
+    ) -> Attachment<'a, TEventArgs, TResult, TStorage> {
+        Attachment {
+            handlers: Rc::downgrade(&self.handlers),
+            id: self.insert(handler),
+        }
+    }
+
+    fn insert_with_priority(
+        &self,
+        handler: impl Into<SlotHandler<'a, TEventArgs, TResult>>,
+        priority: i32,
+    ) -> HandlerId<TEventArgs> {
+        let handle = self.handlers.borrow_mut().insert(handler.into(), priority);
+        #[cfg(feature = "log")]
+        log::debug!("added handler {:?} to event {}", handle, self.log_name());
+        handle
+    }
+
+    /// Returns the name of the event for log lines.
+    #[cfg(feature = "log")]
+    fn log_name(&self) -> &str {
+        self.name().unwrap_or("<unnamed>")
+    }
+
+    /// Enables or disables recording of per-handler call statistics.
+    ///
+    /// Statistics are disabled by default, since timing each handler call has a
+    /// small cost. Disabling them keeps the statistics recorded so far. Without
+    /// the `std` feature there is no clock, so only calls are counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// event.set_stats_enabled(true);
+    /// ```
+    pub fn set_stats_enabled(&self, enabled: bool) {
+        self.stats_enabled.set(enabled);
+    }
+
+    /// Returns the call statistics of each handler, with its handle, in invocation order.
+    ///
+    /// Only calls made while statistics were enabled with
+    /// [`set_stats_enabled`](Self::set_stats_enabled) are counted, and only calls
+    /// in which the handler actually ran, e.g. not those skipped by a filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<()>::new();
+    /// let handle = event.add(|args| { println!("Handler 1"); });
+    /// event.set_stats_enabled(true);
+    ///
+    /// event.invoke(&());
+    /// event.invoke(&());
+    ///
+    /// let stats = event.stats();
+    /// assert_eq!(stats[0].0, handle);
+    /// assert_eq!(stats[0].1.calls, 2);
+    /// assert!(stats[0].1.total_time >= stats[0].1.last_time);
+    /// ```
+    pub fn stats(&self) -> Vec<(HandlerId<TEventArgs>, HandlerStats)> {
+        self.handlers.borrow().stats()
+    }
+
+    /// Runs one invocation through the middleware.
+    ///
+    /// For each handler, `f` is given a function that calls the handler and
+    /// returns its result, or `None` if it did not run. The invocation stops
+    /// early if `f` returns `ControlFlow::Break`.
+    fn invoke_with<F>(&self, arg: &TEventArgs, f: F)
+    where
+        F: FnMut(&mut dyn FnMut() -> Option<TResult>) -> ControlFlow<()>,
+    {
+        if !self.is_enabled() {
+            self.hold(arg);
+            return;
+        }
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("invoke", event = self.name()).entered();
+        #[cfg(feature = "log")]
+        log::debug!(
+            "invoking event {} with {} handlers",
+            self.log_name(),
+            self.len()
+        );
+
+        let f = RefCell::new(f);
+        self.intercept(arg, &|arg| {
+            let mut f = f.borrow_mut();
+            let handlers = self.run_before_hooks(arg);
+            let mut called = 0;
+            for (handle, handler) in self.dispatch() {
+                #[cfg(feature = "tracing")]
+                let _span = {
+                    let handlers = self.handlers.borrow();
+                    let key = handle.key();
+                    tracing::trace_span!("handler", handle = key, name = handlers.name(key))
+                        .entered()
+                };
+
+                let flow = f(&mut || {
+                    let result = self.call(handle, &handler, arg);
+                    called += usize::from(result.is_some());
+                    result
+                });
+                if flow.is_break() {
+                    break;
+                }
+            }
+            self.run_after_hooks(arg, InvokeOutcome { handlers, called });
+        });
+
+        self.purge_spent();
+    }
+
+    /// Calls a handler, recording its statistics if enabled.
+    fn call(
+        &self,
+        handle: HandlerId<TEventArgs>,
+        handler: &SlotHandler<'a, TEventArgs, TResult>,
+        arg: &TEventArgs,
+    ) -> Option<TResult> {
+        if !self.stats_enabled.get() {
+            return handler.call(arg);
+        }
+
+        #[cfg(feature = "std")]
+        let start = Instant::now();
+        let result = handler.call(arg);
+        if result.is_some() {
+            #[cfg(feature = "std")]
+            let elapsed = start.elapsed();
+            #[cfg(not(feature = "std"))]
+            let elapsed = core::time::Duration::ZERO;
+            self.handlers.borrow_mut().record(handle, elapsed);
+        }
+        result
+    }
+
+    /// Passes the event arguments through the middleware chain down to `body`.
+    fn intercept(&self, arg: &TEventArgs, body: &dyn Fn(&TEventArgs)) {
+        fn chain<TEventArgs>(
+            middleware: &[Rc<Middleware<'_, TEventArgs>>],
+            arg: &TEventArgs,
+            body: &dyn Fn(&TEventArgs),
+        ) {
+            match middleware.split_last() {
+                Some((outer, inner)) => outer(arg, &|arg| chain(inner, arg, body)),
+                None => body(arg),
+            }
+        }
+
+        #[cfg(feature = "history")]
+        if let Some(history) = self.history.borrow_mut().as_mut() {
+            history.record(arg);
+        }
+
+        if self.middleware.borrow().is_empty() {
+            return body(arg);
+        }
+        let middleware = self.middleware.borrow().clone();
+        chain(&middleware, arg, body);
+    }
+
+    /// Iterates over the handlers registered when the invocation started.
+    ///
+    /// The handler list is not kept borrowed while handlers run, so they may add
+    /// or remove handlers. Handlers added during the invocation are not called
+    /// until the next one, and handlers removed or paused during it are skipped.
+    fn dispatch(
+        &self,
+    ) -> impl Iterator<Item = (HandlerId<TEventArgs>, SlotHandler<'a, TEventArgs, TResult>)> + '_
+    {
+        let snapshot = self.handlers.borrow().snapshot();
+        snapshot
+            .into_iter()
+            .filter(|(handle, _)| self.handlers.borrow().is_active(*handle))
+    }
+
+    /// Drops handlers that can no longer run, such as one-shot handlers that fired.
+    fn purge_spent(&self) {
+        self.handlers
+            .borrow_mut()
+            .retain(|handler| !handler.is_spent());
+    }
+}
+
+impl<'a, TEventArgs, B, TStorage: Storage> Event<'a, TEventArgs, ControlFlow<B>, TStorage> {
+    /// Invokes event handlers sequentially until one of them returns `ControlFlow::Break`.
+    ///
+    /// Handlers after the one that broke are not called. Returns the `Break` value,
+    /// or `ControlFlow::Continue(())` if every handler let the event propagate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ops::ControlFlow;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<char, ControlFlow<&str>>::new();
+    /// event.add(|key| match key {
+    ///     'q' => ControlFlow::Break("quit"),
+    ///     _ => ControlFlow::Continue(()),
+    /// });
+    /// event.add(|key| {
+    ///     println!("Unhandled key: {}", key);
+    ///     ControlFlow::Continue(())
+    /// });
+    ///
+    /// assert_eq!(event.invoke_control(&'q'), ControlFlow::Break("quit"));
+    /// assert_eq!(event.invoke_control(&'x'), ControlFlow::Continue(()));
+    /// ```
+    pub fn invoke_control(&self, arg: &TEventArgs) -> ControlFlow<B> {
+        let mut flow = ControlFlow::Continue(());
+        self.invoke_with(arg, |call| match call() {
+            Some(ControlFlow::Break(value)) => {
+                flow = ControlFlow::Break(value);
+                ControlFlow::Break(())
+            }
+            _ => ControlFlow::Continue(()),
+        });
+        flow
+    }
+}
+
+impl<'a, TEventArgs, E, TStorage: Storage> Event<'a, TEventArgs, Result<(), E>, TStorage> {
+    /// Invokes all fallible event handlers sequentially and collects their errors.
+    ///
+    /// Every handler runs, even if an earlier one failed. Returns `Ok(())` if all
+    /// handlers succeeded, or `Err` with the errors in invocation order otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<i32, Result<(), String>>::new();
+    /// event.add(|value| if *value < 0 { Err("negative".into()) } else { Ok(()) });
+    /// event.add(|value| if *value % 2 != 0 { Err("odd".into()) } else { Ok(()) });
+    ///
+    /// assert_eq!(event.invoke_try(&2), Ok(()));
+    /// assert_eq!(event.invoke_try(&-1), Err(vec!["negative".into(), "odd".into()]));
+    /// ```
+    pub fn invoke_try(&self, arg: &TEventArgs) -> Result<(), Vec<E>> {
+        let errors: Vec<E> = self
+            .invoke_collect(arg)
+            .into_iter()
+            .filter_map(Result::err)
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Invokes fallible event handlers sequentially until one of them fails.
+    ///
+    /// Handlers after the one that failed are not called. Returns the first error,
+    /// or `Ok(())` if all handlers succeeded. This suits validation, where the
+    /// remaining handlers must not be notified once one has rejected the event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<i32, Result<(), String>>::new();
+    /// event.add(|value| if *value < 0 { Err("negative".into()) } else { Ok(()) });
+    /// event.add(|value| if *value % 2 != 0 { Err("odd".into()) } else { Ok(()) });
+    ///
+    /// assert_eq!(event.invoke_until_err(&2), Ok(()));
+    /// assert_eq!(event.invoke_until_err(&-1), Err("negative".into()));
+    /// ```
+    pub fn invoke_until_err(&self, arg: &TEventArgs) -> Result<(), E> {
+        let mut result = Ok(());
+        self.invoke_with(arg, |call| match call() {
+            Some(Err(error)) => {
+                result = Err(error);
+                ControlFlow::Break(())
+            }
+            _ => ControlFlow::Continue(()),
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn test_invoke() {
+        let counter = RefCell::new(0);
+        let event = Event::new();
+
+        event.invoke(&());
+
+        event.add(|_| {
+            *counter.borrow_mut() += 1;
+        });
+
+        event.add(|_| {
+            *counter.borrow_mut() += 1;
+        });
+
+        event.invoke(&());
+        event.invoke(&());
+        assert_eq!(*counter.borrow(), 4);
+    }
+
+    #[test]
+    fn test_add_mut() {
+        let mut log = vec![];
+
+        {
+            let event = Event::new();
+            event.add_mut(|arg: &i32| log.push(*arg));
+
+            event.invoke(&1);
+            event.invoke(&2);
+        }
+
+        assert_eq!(log, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_once() {
+        let counter = RefCell::new(0);
+        let event = Event::new();
+
+        let handle = event.once(|_| {
+            *counter.borrow_mut() += 1;
+        });
+
+        event.invoke(&());
+        event.invoke(&());
+        assert_eq!(*counter.borrow(), 1);
+        assert!(!event.remove(handle));
+    }
+
+    #[test]
+    fn test_once_nested_invoke() {
+        let counter = Rc::new(RefCell::new(0));
+        let event = Rc::new(Event::new());
+
+        let counter_clone = Rc::clone(&counter);
+        event.once(move |_| {
+            *counter_clone.borrow_mut() += 1;
+        });
+        let weak = Rc::downgrade(&event);
+        event.once(move |_| weak.upgrade().unwrap().invoke(&()));
+
+        event.invoke(&());
+        event.invoke(&());
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[test]
+    fn test_invoke_all() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = Rc::new(Event::new());
+
+        let weak = Rc::downgrade(&event);
+        let log_clone = Rc::clone(&log);
+        event.add(move |arg: &i32| {
+            log_clone.borrow_mut().push(*arg);
+            let log = Rc::clone(&log_clone);
+            weak.upgrade()
+                .unwrap()
+                .add(move |arg: &i32| log.borrow_mut().push(arg * 10));
+        });
+        event.once(|arg: &i32| log.borrow_mut().push(-arg));
+        event.wrap(|arg, next| next(&(arg + 1)));
+
+        event.invoke_all(&[1, 2]);
+        assert_eq!(*log.borrow(), vec![2, -2, 3]);
+        assert_eq!(event.len(), 3);
+    }
+
+    #[test]
+    fn test_post_blocks_when_full() {
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+        event.add(|arg: &i32| log.borrow_mut().push(*arg));
+        event.set_backpressure(Backpressure::Block(2));
+
+        for arg in 1..=4 {
+            event.post(arg);
+        }
+        assert_eq!(*log.borrow(), vec![1, 2]);
+        assert_eq!(event.pump(), 2);
+        assert_eq!(*log.borrow(), vec![1, 2, 3, 4]);
+
+        event.set_backpressure(Backpressure::Block(0));
+        event.post(5);
+        assert_eq!(*log.borrow(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_coalescing() {
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+        event.add(|arg: &i32| log.borrow_mut().push(*arg));
+
+        event.post(1);
+        event.post(2);
+        event.set_coalescing(true);
+        event.post(3);
+        event.post(4);
+        assert_eq!(event.pump(), 1);
+
+        event.set_coalescing(false);
+        event.post(5);
+        event.post(6);
+        assert_eq!(event.pump(), 2);
+        assert_eq!(*log.borrow(), vec![4, 5, 6]);
+    }
+
+    #[test]
+    fn test_pause_keeps_order() {
+        let event = Event::<(), i32>::new();
+        let first = event.add(|_| 1);
+        event.add(|_| 2);
+        let once = event.once(|_| 3);
+
+        assert!(event.pause(first));
+        assert!(event.pause(once));
+        assert_eq!(event.invoke_collect(&()), vec![2]);
+        assert_eq!(event.len(), 3);
+
+        assert!(event.resume(first));
+        assert!(event.resume(once));
+        assert_eq!(event.invoke_collect(&()), vec![1, 2, 3]);
+
+        assert!(event.remove(first));
+        assert!(!event.pause(first));
+        assert!(!event.is_paused(first));
+    }
+
+    #[test]
+    fn test_add_until() {
+        let event = Event::<i32, i32>::new();
+        event.add_until(|arg| *arg < 0, |arg| *arg);
+        event.add_until_inclusive(|arg| *arg < 0, |arg| arg * 10);
+
+        assert_eq!(event.invoke_collect(&1), vec![1, 10]);
+        assert_eq!(event.invoke_collect(&-1), vec![-10]);
+        assert!(event.is_empty());
+        assert!(event.invoke_collect(&2).is_empty());
+    }
+
+    #[test]
+    fn test_add_times() {
+        let event = Event::<i32, i32>::new();
+        event.add_times(2, |arg| *arg);
+        event.add_times(0, |arg| arg * 10);
+        assert_eq!(event.len(), 1);
+
+        assert_eq!(event.invoke_collect(&1), vec![1]);
+        assert_eq!(event.invoke_collect(&2), vec![2]);
+        assert!(event.invoke_collect(&3).is_empty());
+        assert!(event.is_empty());
+    }
+
+    #[test]
+    fn test_add_fn() {
+        fn double(arg: &i32) -> i32 {
+            arg * 2
+        }
+
+        let event = Event::new();
+        let handle = event.add_fn(double);
+        event.add(|arg| arg + 1);
+        event.add_fn(|arg| -arg);
+
+        assert_eq!(event.invoke_collect(&3), vec![6, 4, -3]);
+        assert!(event.remove(handle));
+        assert_eq!(event.invoke_collect(&3), vec![4, -3]);
+    }
+
+    #[test]
+    fn test_debug() {
+        #[derive(Debug)]
+        struct Button<'a> {
+            clicked: Event<'a, ()>,
+        }
+
+        let button = Button {
+            clicked: Event::with_name("clicked"),
+        };
+        let named = button.clicked.add_named("log", |_| {});
+        button.clicked.add_tagged("ui", |_| {});
+
+        let debug = format!("{:?}", button);
+        assert!(debug.starts_with("Button { clicked: Event { name: Some(\"clicked\"), len: 2, "));
+        assert!(debug.contains(&format!("{:?}: HandlerInfo", named)));
+        assert!(debug.contains("name: Some(\"log\"), tag: None"));
+        assert!(debug.contains("name: None, tag: Some(\"ui\")"));
+        assert!(debug.ends_with("enabled: true, .. } }"));
+    }
+
+    #[test]
+    fn test_invoke_collect() {
+        let event = Event::new();
+
+        assert!(event.invoke_collect(&2).is_empty());
+
+        event.add(|arg: &i32| arg + 1);
+        event.once(|arg: &i32| arg * 10);
+        event.add(|arg: &i32| arg - 1);
+
+        assert_eq!(event.invoke_collect(&2), vec![3, 20, 1]);
+        assert_eq!(event.invoke_collect(&2), vec![3, 1]);
+    }
+
+    #[test]
+    fn test_invoke_control() {
+        let counter = RefCell::new(0);
+        let event = Event::new();
+
+        assert_eq!(event.invoke_control(&0), ControlFlow::Continue(()));
+
+        event.add(|arg: &i32| {
+            *counter.borrow_mut() += 1;
+            if *arg > 0 {
+                ControlFlow::Break(*arg)
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        event.add(|_| {
+            *counter.borrow_mut() += 1;
+            ControlFlow::Continue(())
+        });
+
+        assert_eq!(event.invoke_control(&0), ControlFlow::Continue(()));
+        assert_eq!(*counter.borrow(), 2);
+
+        assert_eq!(event.invoke_control(&7), ControlFlow::Break(7));
+        assert_eq!(*counter.borrow(), 3);
+    }
+
+    #[test]
+    fn test_priority_order() {
+        let event = Event::new();
+
+        event.add_with_priority(-1, |_: &()| 'e');
+        let handle = event.add(|_| 'x');
+        event.add_with_priority(5, |_| 'a');
+        event.add(|_| 'c');
+        event.add_with_priority(5, |_| 'b');
+
+        assert!(event.remove(handle));
+        event.add(|_| 'd');
+
+        assert_eq!(event.invoke_collect(&()), vec!['a', 'b', 'c', 'd', 'e']);
+    }
+
+    #[test]
+    fn test_add_weak() {
+        let event = Event::new();
+        let owner = Rc::new(RefCell::new(vec![]));
+
+        let handle = event.add_weak(&owner, |owner, arg: &i32| owner.borrow_mut().push(*arg));
+        assert_eq!(Rc::strong_count(&owner), 1);
+
+        event.invoke(&1);
+        assert_eq!(*owner.borrow(), vec![1]);
+
+        drop(owner);
+        assert!(event.invoke_collect(&2).is_empty());
+        assert!(!event.remove(handle));
+    }
+
+    #[test]
+    fn test_invoke_try() {
+        let counter = RefCell::new(0);
+        let event = Event::new();
+
+        assert_eq!(event.invoke_try(&()), Ok::<(), Vec<i32>>(()));
+
+        for i in 0..3 {
+            let counter = &counter;
+            event.add(move |_| {
+                *counter.borrow_mut() += 1;
+                if i == 1 { Ok(()) } else { Err(i) }
+            });
+        }
+
+        assert_eq!(event.invoke_try(&()), Err(vec![0, 2]));
+        assert_eq!(*counter.borrow(), 3);
+
+        assert_eq!(event.invoke_until_err(&()), Err(0));
+        assert_eq!(*counter.borrow(), 4);
+    }
+
+    #[test]
+    fn test_invoke_until_err_skips_remaining() {
+        let log = RefCell::new(vec![]);
+        let event = Event::<i32, Result<(), &str>>::new();
+        event.add(|_| {
+            log.borrow_mut().push("first");
+            Ok(())
+        });
+        event.add(|value| {
+            log.borrow_mut().push("validate");
+            if *value < 0 { Err("negative") } else { Ok(()) }
+        });
+        event.add(|_| {
+            log.borrow_mut().push("commit");
+            Ok(())
+        });
+
+        assert_eq!(event.invoke_until_err(&-1), Err("negative"));
+        assert_eq!(*log.borrow(), vec!["first", "validate"]);
+
+        log.borrow_mut().clear();
+        assert_eq!(event.invoke_until_err(&1), Ok(()));
+        assert_eq!(*log.borrow(), vec!["first", "validate", "commit"]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_invoke_isolated() {
+        let counter = RefCell::new(0);
+        let event = Event::new();
+
+        assert!(event.invoke_isolated(&()).is_ok());
+
+        event.add(|_| {
+            *counter.borrow_mut() += 1;
+        });
+        event.once(|_| panic!("first"));
+        event.add(|_| {
+            *counter.borrow_mut() += 1;
+        });
+        event.add(|_| panic!("second"));
+
+        let panics = event.invoke_isolated(&()).unwrap_err();
+        let messages: Vec<_> = panics
+            .iter()
+            .map(|panic| *panic.downcast_ref::<&str>().unwrap())
+            .collect();
+        assert_eq!(messages, vec!["first", "second"]);
+        assert_eq!(*counter.borrow(), 2);
+
+        assert_eq!(event.invoke_isolated(&()).unwrap_err().len(), 1);
+        assert_eq!(*counter.borrow(), 4);
+    }
+
+    #[test]
+    fn test_reentrant_add_remove() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = Rc::new(Event::new());
+
+        let weak = Rc::downgrade(&event);
+        let log_clone = Rc::clone(&log);
+        let second = Rc::new(RefCell::new(None));
+        let second_clone = Rc::clone(&second);
+        let first = event.add(move |_| {
+            let event = weak.upgrade().unwrap();
+            log_clone.borrow_mut().push("first");
+
+            // Removing a later handler skips it in the running invocation
+            if let Some(handle) = second_clone.borrow_mut().take() {
+                assert!(event.remove(handle));
+            }
+
+            // Added handlers only run from the next invocation on
+            let log = Rc::clone(&log_clone);
+            event.once(move |_| log.borrow_mut().push("added"));
+        });
+
+        let log_clone = Rc::clone(&log);
+        *second.borrow_mut() = Some(event.add(move |_| log_clone.borrow_mut().push("second")));
+
+        event.invoke(&());
+        assert_eq!(*log.borrow(), vec!["first"]);
+
+        assert!(event.remove(first));
+        event.invoke(&());
+        assert_eq!(*log.borrow(), vec!["first", "added"]);
+    }
+
+    #[test]
+    fn test_snapshot_survives_slot_reuse() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = Rc::new(Event::new());
+
+        let weak = Rc::downgrade(&event);
+        let log_clone = Rc::clone(&log);
+        let second = Rc::new(RefCell::new(None));
+        let second_clone = Rc::clone(&second);
+        event.add(move |depth: &u32| {
+            let event = weak.upgrade().unwrap();
+            log_clone.borrow_mut().push(format!("first {}", depth));
+
+            // The replacement may take the slot of the removed handler
+            if let Some(handle) = second_clone.borrow_mut().take() {
+                assert!(event.remove(handle));
+                let log = Rc::clone(&log_clone);
+                event.add(move |depth| log.borrow_mut().push(format!("third {}", depth)));
+            }
+
+            // A nested invocation takes its own snapshot
+            if *depth == 0 {
+                event.invoke(&1);
+            }
+        });
+
+        let log_clone = Rc::clone(&log);
+        *second.borrow_mut() =
+            Some(event.add(move |depth| log_clone.borrow_mut().push(format!("second {}", depth))));
+
+        event.invoke(&0);
+        assert_eq!(*log.borrow(), vec!["first 0", "first 1", "third 1"]);
+    }
+
+    #[test]
+    fn test_remove_self_during_invoke() {
+        let counter = Rc::new(RefCell::new(0));
+        let event = Rc::new(Event::new());
+
+        let handle = Rc::new(RefCell::new(None));
+        let weak = Rc::downgrade(&event);
+        let handle_clone = Rc::clone(&handle);
+        let counter_clone = Rc::clone(&counter);
+        *handle.borrow_mut() = Some(event.add(move |_| {
+            *counter_clone.borrow_mut() += 1;
+            let handle = handle_clone.borrow_mut().take().unwrap();
+            assert!(weak.upgrade().unwrap().remove(handle));
+        }));
+
+        event.invoke(&());
+        event.invoke(&());
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[test]
+    fn test_post_and_pump() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = Rc::new(Event::new());
+
+        let weak = Rc::downgrade(&event);
+        let log_clone = Rc::clone(&log);
+        event.add(move |arg: &i32| {
+            log_clone.borrow_mut().push(*arg);
+            if *arg < 10 {
+                weak.upgrade().unwrap().post(arg * 10);
+            }
+        });
+
+        event.post(1);
+        event.post(2);
+        assert!(log.borrow().is_empty());
+
+        assert_eq!(event.pump(), 2);
+        assert_eq!(*log.borrow(), vec![1, 2]);
+
+        assert_eq!(event.pump(), 2);
+        assert_eq!(*log.borrow(), vec![1, 2, 10, 20]);
+        assert_eq!(event.pump(), 0);
+    }
+
+    #[test]
+    fn test_add_filtered() {
+        let event = Event::new();
+
+        event.add_filtered(|arg: &i32| *arg > 0, |arg| *arg);
+        event.add(|arg| -arg);
+
+        assert_eq!(event.invoke_collect(&2), vec![2, -2]);
+        assert_eq!(event.invoke_collect(&-3), vec![3]);
+    }
+
+    #[test]
+    fn test_wrap() {
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+
+        event.add(|arg: &i32| {
+            log.borrow_mut().push(format!("handler {}", arg));
+            *arg
+        });
+        event.wrap(|arg, next| {
+            log.borrow_mut().push("inner".to_string());
+            next(&(arg + 1));
+        });
+        event.wrap(|arg, next| {
+            log.borrow_mut().push("outer before".to_string());
+            if *arg >= 0 {
+                next(arg);
+            }
+            log.borrow_mut().push("outer after".to_string());
+        });
+
+        assert_eq!(event.invoke_collect(&1), vec![2]);
+        assert_eq!(
+            *log.borrow(),
+            vec!["outer before", "inner", "handler 2", "outer after"]
+        );
+
+        assert!(event.invoke_collect(&-1).is_empty());
+    }
+
+    #[test]
+    fn test_stats() {
+        let event = Event::new();
+
+        let first = event.add(|_: &i32| {});
+        let second = event.add_filtered(|arg| *arg > 0, |_| {});
+
+        event.invoke(&1);
+        assert!(event.stats().iter().all(|(_, stats)| stats.calls == 0));
+
+        event.set_stats_enabled(true);
+        event.invoke(&1);
+        event.invoke(&-1);
+
+        let stats = event.stats();
+        assert_eq!(stats[0].0, first);
+        assert_eq!(stats[0].1.calls, 2);
+        assert_eq!(stats[1].0, second);
+        assert_eq!(stats[1].1.calls, 1);
+    }
+
+    #[test]
+    fn test_named_handlers() {
+        let event = Event::new();
+
+        event.add_named("a", |_: &()| 'a');
+        event.add_named("b", |_| 'b');
+        event.add(|_| 'x');
+        event.add_named("a", |_| 'c');
+
+        assert_eq!(event.names(), vec!["b", "a"]);
+        assert_eq!(event.invoke_collect(&()), vec!['b', 'x', 'c']);
+
+        assert!(event.remove_named("b"));
+        assert!(!event.remove_named("b"));
+        assert_eq!(event.invoke_collect(&()), vec!['x', 'c']);
+    }
+
+    #[test]
+    fn test_remove_by_tag() {
+        let event = Event::new();
+
+        event.add_tagged("a", |_: &()| 1);
+        event.add(|_| 2);
+        event.add_tagged("b", |_| 3);
+        event.add_tagged("a", |_| 4);
+
+        assert_eq!(event.remove_by_tag("a"), 2);
+        assert_eq!(event.remove_by_tag("a"), 0);
+        assert_eq!(event.invoke_collect(&()), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_max_handlers() {
+        let event = Event::with_max_handlers(2);
+        let first = event.try_add(|_: &()| {}).unwrap();
+        event.try_add(|_| {}).unwrap();
+        assert!(matches!(
+            event.try_add(|_| {}),
+            Err(EventError::CapacityExceeded)
+        ));
+
+        event.remove(first);
+        assert!(event.try_add(|_| {}).is_ok());
+        assert_eq!(event.len(), 2);
+    }
+
+    #[test]
+    fn test_introspection() {
+        let event = Event::new();
+        assert!(event.is_empty());
+
+        let first = event.add_tagged("t", |_: &()| {});
+        let second = event.once(|_| {});
+        assert_eq!(event.len(), 2);
+        assert_eq!(event.handles(), vec![first, second]);
+        assert_eq!(
+            event.handler_info(first),
+            Some(HandlerInfo {
+                priority: 0,
+                name: None,
+                tag: Some("t".to_string()),
+            })
+        );
+
+        event.invoke(&());
+        assert_eq!(event.len(), 1);
+        assert_eq!(event.handles(), vec![first]);
+        assert_eq!(event.handler_info(second), None);
+    }
+
+    #[test]
+    fn test_insertion_order_after_reuse() {
+        let event = Event::new();
+        let first = event.add(|_: &()| 1);
+        let second = event.add(|_| 2);
+        assert!(event.remove(first));
+        event.add(|_| 3);
+        assert!(event.remove(second));
+        event.add(|_| 4);
+        event.add(|_| 5);
+
+        assert_eq!(event.invoke_collect(&()), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_stale_handle() {
+        let event = Event::new();
+        let stale = event.add(|_: &()| 1);
+        assert!(event.remove(stale));
+
+        let fresh = event.add(|_| 2);
+        assert_ne!(stale, fresh);
+        assert!(!event.remove(stale));
+        assert_eq!(event.handler_info(stale), None);
+        assert_eq!(event.invoke_collect(&()), vec![2]);
+
+        let other = Event::new();
+        other.add(|_: &()| 3);
+        assert!(!other.remove(fresh));
+        assert_eq!(other.len(), 1);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let event = Event::with_capacity(16);
+        assert!(event.capacity() >= 16);
+
+        let handles: Vec<_> = (0..16).map(|i| event.add(move |_: &()| i)).collect();
+        for &handle in &handles[..15] {
+            assert!(event.remove(handle));
+        }
+
+        event.shrink_to_fit();
+        assert!(event.capacity() >= 16);
+        assert_eq!(event.invoke_collect(&()), vec![15]);
+
+        event.reserve(32);
+        assert!(event.capacity() >= 33);
     }
 
     #[test]
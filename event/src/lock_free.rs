@@ -0,0 +1,248 @@
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+
+use crate::id::next_generation;
+use crate::{HandlerId, SyncEventHandler};
+
+/// The handler list of a [`LockFreeEvent`], which is never modified in place.
+type HandlerList<'a, TEventArgs> = Vec<(u64, Arc<SyncEventHandler<'a, TEventArgs>>)>;
+
+/// A thread-safe event whose invocations never take a lock.
+///
+/// This is a variant of [`SyncEvent`](crate::SyncEvent) for latency-sensitive
+/// threads, such as audio callbacks. The handler list is immutable: invoking
+/// the event only loads the current list, while adding or removing a handler
+/// copies the list and atomically swaps the copy in. Invocations are therefore
+/// lock-free and allocation-free, at the cost of slower changes to the list.
+/// Handlers run in the order they were added.
+///
+/// An invocation that is running while the list is swapped out finishes with the
+/// handlers it started with. The old list, along with any removed handler, is
+/// dropped by whichever thread releases it last.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::thread;
+///
+/// use event_rs::LockFreeEvent;
+///
+/// let counter = AtomicUsize::new(0);
+/// let event = LockFreeEvent::<u32>::new();
+/// event.add(|args| {
+///     counter.fetch_add(*args as usize, Ordering::SeqCst);
+/// });
+///
+/// thread::scope(|s| {
+///     s.spawn(|| event.invoke(&1));
+///     s.spawn(|| event.invoke(&2));
+/// });
+///
+/// assert_eq!(counter.load(Ordering::SeqCst), 3);
+/// ```
+pub struct LockFreeEvent<'a, TEventArgs> {
+    handlers: ArcSwap<HandlerList<'a, TEventArgs>>,
+}
+
+impl<'a, TEventArgs> Default for LockFreeEvent<'a, TEventArgs> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, TEventArgs> LockFreeEvent<'a, TEventArgs> {
+    /// Creates a new, empty LockFreeEvent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LockFreeEvent;
+    ///
+    /// let event: LockFreeEvent<()> = LockFreeEvent::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            handlers: ArcSwap::from_pointee(Vec::new()),
+        }
+    }
+
+    /// Adds an event handler to the event.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LockFreeEvent;
+    ///
+    /// let event = LockFreeEvent::<()>::new();
+    /// let handle = event.add(|args| {
+    ///     println!("Event invoked");
+    /// });
+    /// ```
+    pub fn add<F>(&self, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) + Send + Sync + 'a,
+    {
+        let generation = next_generation();
+        let handler: Arc<SyncEventHandler<'a, TEventArgs>> = Arc::new(Box::new(handler));
+        let previous = self.handlers.rcu(|handlers| {
+            let mut handlers = HandlerList::clone(handlers);
+            handlers.push((generation, Arc::clone(&handler)));
+            handlers
+        });
+        HandlerId::new(previous.len(), generation)
+    }
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LockFreeEvent;
+    ///
+    /// let event = LockFreeEvent::<()>::new();
+    /// let handle = event.add(|args| {
+    ///     println!("Event invoked");
+    /// });
+    ///
+    /// assert!(event.remove(handle));
+    /// assert!(!event.remove(handle)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        let previous = self.handlers.rcu(|handlers| {
+            let mut handlers = HandlerList::clone(handlers);
+            handlers.retain(|&(generation, _)| generation != handle.generation());
+            handlers
+        });
+        previous
+            .iter()
+            .any(|&(generation, _)| generation == handle.generation())
+    }
+
+    /// Returns the number of event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LockFreeEvent;
+    ///
+    /// let event = LockFreeEvent::<()>::new();
+    /// event.add(|args| println!("Handler 1"));
+    ///
+    /// assert_eq!(event.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.handlers.load().len()
+    }
+
+    /// Returns `true` if the event has no handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LockFreeEvent;
+    ///
+    /// let event: LockFreeEvent<()> = LockFreeEvent::new();
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LockFreeEvent;
+    ///
+    /// let event = LockFreeEvent::<()>::new();
+    /// event.add(|args| { println!("Handler 1"); });
+    /// event.add(|args| { println!("Handler 2"); });
+    ///
+    /// event.clear(); // Remove all handlers
+    /// ```
+    pub fn clear(&self) {
+        self.handlers.store(Arc::new(Vec::new()));
+    }
+
+    /// Invokes all event handlers sequentially on the calling thread, without
+    /// taking a lock.
+    ///
+    /// Handlers may add or remove handlers, which takes effect from the next
+    /// invocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::LockFreeEvent;
+    ///
+    /// let event = LockFreeEvent::new();
+    /// event.add(|args| { println!("Handler 1"); });
+    /// event.add(|args| { println!("Handler 2"); });
+    ///
+    /// event.invoke(&()); // Execute all handlers in order
+    /// ```
+    pub fn invoke(&self, arg: &TEventArgs) {
+        for (_, handler) in self.handlers.load().iter() {
+            handler(arg);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_concurrent_access() {
+        let counter = AtomicUsize::new(0);
+        let event = LockFreeEvent::new();
+
+        thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    let handle = event.add(|_| {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                    });
+                    event.invoke(&());
+                    assert!(event.remove(handle));
+                });
+            }
+        });
+
+        assert!(event.is_empty());
+        assert!(counter.load(Ordering::SeqCst) >= 4);
+        assert!(counter.load(Ordering::SeqCst) <= 16);
+    }
+
+    #[test]
+    fn test_change_during_invoke() {
+        let log = Mutex::new(vec![]);
+        let event = Arc::new(LockFreeEvent::new());
+
+        let weak = Arc::downgrade(&event);
+        let log_clone = &log;
+        let first = event.add(move |_| {
+            log_clone.lock().unwrap().push(1);
+            let event = weak.upgrade().unwrap();
+            event.clear();
+            event.add(move |_| log_clone.lock().unwrap().push(3));
+        });
+        event.add(|_| log.lock().unwrap().push(2));
+
+        event.invoke(&());
+        assert!(!event.remove(first));
+        event.invoke(&());
+        assert_eq!(*log.lock().unwrap(), vec![1, 2, 3]);
+    }
+}
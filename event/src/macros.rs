@@ -0,0 +1,252 @@
+/// Connects an event to a handler and returns the [`Subscription`](crate::Subscription)
+/// that keeps it connected.
+///
+/// `connect!(event => owner.method)` connects `event` to a method of `owner`,
+/// an `Rc` of the listener. The event only holds a weak reference to the
+/// listener, so the connection also ends when the listener is dropped.
+/// `connect!(event => closure)` connects `event` to a closure.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// use event_rs::{Event, connect};
+///
+/// struct Button {
+///     clicked: Event<'static, u32>,
+/// }
+///
+/// struct Dialog {
+///     clicks: Cell<u32>,
+/// }
+///
+/// impl Dialog {
+///     fn on_clicked(&self, count: &u32) {
+///         self.clicks.set(self.clicks.get() + count);
+///     }
+/// }
+///
+/// let button = Button { clicked: Event::new() };
+/// let dialog = Rc::new(Dialog { clicks: Cell::new(0) });
+///
+/// let _clicked = connect!(button.clicked => dialog.on_clicked);
+/// let _logged = connect!(button.clicked => |count| println!("Clicked {} times", count));
+///
+/// button.clicked.invoke(&2);
+/// assert_eq!(dialog.clicks.get(), 2);
+/// ```
+#[macro_export]
+macro_rules! connect {
+    ($event:expr => $owner:ident . $method:ident) => {
+        $event.subscribe_weak(&$owner, |owner, args| owner.$method(args))
+    };
+    ($event:expr => $handler:expr) => {
+        $event.subscribe($handler)
+    };
+}
+
+/// Raises [`NotifyPropertyChanged::property_changed`](crate::NotifyPropertyChanged::property_changed)
+/// for a property, using the property's name as written.
+///
+/// `raise_property_changed!(self.title)` raises the event of `self` with the
+/// property name `"title"`, so the name cannot drift from the field it names.
+///
+/// See [`NotifyPropertyChanged`](crate::NotifyPropertyChanged) for an example.
+#[macro_export]
+macro_rules! raise_property_changed {
+    ($owner:ident . $property:ident) => {{
+        use $crate::NotifyPropertyChanged as _;
+        $owner
+            .property_changed()
+            .invoke(&$crate::PropertyChangedArgs::new(::core::stringify!(
+                $property
+            )))
+    }};
+}
+
+/// Clones `Rc` or `Arc` captures into a closure, optionally as weak references.
+///
+/// `clone!(a, @weak b => move |args| body)` clones `a` and downgrades `b` before
+/// creating the closure, so `body` can use both without moving them out of the
+/// surrounding scope. Each call of the closure upgrades `b` first; if it has been
+/// dropped, the closure returns `Default::default()` without running `body`. Weak
+/// captures let a handler refer to the object that owns its event without a
+/// reference cycle.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// use event_rs::{Event, clone};
+///
+/// let clicks = Rc::new(Cell::new(0));
+/// let label = Rc::new(String::from("OK"));
+/// let event = Event::<u32>::new();
+///
+/// event.add(clone!(clicks, @weak label => move |count| {
+///     clicks.set(clicks.get() + count);
+///     println!("{} clicked", label);
+/// }));
+///
+/// event.invoke(&1);
+/// drop(label);
+/// event.invoke(&1); // Does nothing, the label is gone
+/// assert_eq!(clicks.get(), 1);
+/// ```
+#[macro_export]
+macro_rules! clone {
+    ($($(@$kind:ident)? $name:ident),+ $(,)? => move || $body:expr) => {{
+        $($crate::__clone_capture!($(@$kind)? $name);)+
+        move || {
+            $($crate::__clone_upgrade!($(@$kind)? $name);)+
+            $body
+        }
+    }};
+    ($($(@$kind:ident)? $name:ident),+ $(,)? => move |$($arg:tt $(: $ty:ty)?),*| $body:expr) => {{
+        $($crate::__clone_capture!($(@$kind)? $name);)+
+        move |$($arg $(: $ty)?),*| {
+            $($crate::__clone_upgrade!($(@$kind)? $name);)+
+            $body
+        }
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __clone_capture {
+    (@weak $name:ident) => {
+        let $name = $crate::__Downgrade::downgrade(&$name);
+    };
+    ($(@strong)? $name:ident) => {
+        let $name = ::core::clone::Clone::clone(&$name);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __clone_upgrade {
+    (@weak $name:ident) => {
+        let ::core::option::Option::Some($name) = $name.upgrade() else {
+            return ::core::default::Default::default();
+        };
+    };
+    ($(@strong)? $name:ident) => {};
+}
+
+/// Downgrades `Rc` and `Arc` for [`clone!`].
+#[doc(hidden)]
+pub trait __Downgrade {
+    type Weak;
+
+    fn downgrade(this: &Self) -> Self::Weak;
+}
+
+impl<T: ?Sized> __Downgrade for alloc::rc::Rc<T> {
+    type Weak = alloc::rc::Weak<T>;
+
+    fn downgrade(this: &Self) -> Self::Weak {
+        alloc::rc::Rc::downgrade(this)
+    }
+}
+
+impl<T: ?Sized> __Downgrade for alloc::sync::Arc<T> {
+    type Weak = alloc::sync::Weak<T>;
+
+    fn downgrade(this: &Self) -> Self::Weak {
+        alloc::sync::Arc::downgrade(this)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::Event;
+
+    struct Listener {
+        log: RefCell<Vec<i32>>,
+    }
+
+    impl Listener {
+        fn on_event(&self, value: &i32) {
+            self.log.borrow_mut().push(*value);
+        }
+
+        fn listen_once(self: &Rc<Self>, event: &Event<'static, i32>) {
+            let _subscription = connect!(event => self.on_event);
+            event.invoke(&1);
+        }
+    }
+
+    #[test]
+    fn test_connect_method() {
+        let event = Event::new();
+        let listener = Rc::new(Listener {
+            log: RefCell::new(vec![]),
+        });
+
+        let subscription = connect!(event => listener.on_event);
+        event.invoke(&1);
+        drop(subscription);
+        event.invoke(&2);
+        assert_eq!(*listener.log.borrow(), vec![1]);
+
+        let _subscription = connect!(event => listener.on_event);
+        drop(listener);
+        event.invoke(&3);
+        assert!(event.is_empty());
+    }
+
+    #[test]
+    fn test_connect_self() {
+        let listener = Rc::new(Listener {
+            log: RefCell::new(vec![]),
+        });
+
+        let event = Event::new();
+        listener.listen_once(&event);
+        event.invoke(&2);
+        assert_eq!(*listener.log.borrow(), vec![1]);
+    }
+
+    #[test]
+    fn test_connect_closure() {
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+
+        let _subscription = connect!(event => |value: &i32| log.borrow_mut().push(*value));
+        event.invoke(&1);
+        assert_eq!(*log.borrow(), vec![1]);
+    }
+
+    #[test]
+    fn test_clone() {
+        use std::sync::{Arc, Mutex};
+
+        let log = Rc::new(RefCell::new(vec![]));
+        let shared = Arc::new(Mutex::new(0));
+        let owner = Rc::new(Event::<i32, i32>::new());
+
+        owner.add(
+            clone!(log, @weak owner, @strong shared => move |value: &i32| {
+                log.borrow_mut().push(*value);
+                *shared.lock().unwrap() += value;
+                owner.len() as i32
+            }),
+        );
+        assert_eq!(owner.invoke_collect(&1), vec![1]);
+        assert_eq!(*log.borrow(), vec![1]);
+        assert_eq!(*shared.lock().unwrap(), 1);
+
+        let weak_shared = clone!(@weak shared => move || *shared.lock().unwrap());
+        assert_eq!(weak_shared(), 1);
+        drop(owner);
+        drop(shared);
+        assert_eq!(weak_shared(), 0);
+    }
+}
@@ -0,0 +1,271 @@
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+use crate::HandlerId;
+use crate::registry::Registry;
+
+/// Type alias for event handlers that receive mutable event arguments.
+///
+/// Each handler is a shared function that takes a mutable reference to event arguments.
+pub type EventMutArgsHandler<'a, TEventArgs> = Rc<dyn Fn(&mut TEventArgs) + 'a>;
+
+/// An event whose handlers can modify the event arguments.
+///
+/// Handlers run in order, and each one observes the changes made by the
+/// handlers before it. After [`invoke_mut`](Self::invoke_mut) returns, the
+/// invoker sees the final state, e.g. a `handled` flag or an accumulated result.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::EventMutArgs;
+///
+/// #[derive(Debug, Default)]
+/// struct KeyEventArgs {
+///     key: char,
+///     handled: bool,
+/// }
+///
+/// let event = EventMutArgs::<KeyEventArgs>::new();
+/// event.add(|args| {
+///     if args.key == 'q' {
+///         args.handled = true;
+///     }
+/// });
+///
+/// let mut args = KeyEventArgs { key: 'q', ..Default::default() };
+/// event.invoke_mut(&mut args);
+/// assert!(args.handled);
+/// ```
+pub struct EventMutArgs<'a, TEventArgs> {
+    handlers: RefCell<Registry<TEventArgs, EventMutArgsHandler<'a, TEventArgs>>>,
+}
+
+impl<'a, TEventArgs> Default for EventMutArgs<'a, TEventArgs> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, TEventArgs> EventMutArgs<'a, TEventArgs> {
+    /// Creates a new, empty EventMutArgs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventMutArgs;
+    ///
+    /// let event: EventMutArgs<()> = EventMutArgs::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            handlers: Registry::new().into(),
+        }
+    }
+
+    /// Adds an event handler to the event.
+    ///
+    /// The handler should be a closure that accepts a mutable reference to the event
+    /// arguments and returns nothing. The closure will be executed when the event is invoked.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventMutArgs;
+    ///
+    /// let event = EventMutArgs::<u32>::new();
+    /// let handle = event.add(|args| {
+    ///     *args += 1;
+    /// });
+    /// ```
+    pub fn add<F>(&self, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&mut TEventArgs) + 'a,
+    {
+        self.handlers.borrow_mut().insert(Rc::new(handler))
+    }
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventMutArgs;
+    ///
+    /// let event = EventMutArgs::<u32>::new();
+    /// let handle = event.add(|args| {
+    ///     *args += 1;
+    /// });
+    ///
+    /// assert!(event.remove(handle));
+    /// assert!(!event.remove(handle)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        self.handlers.borrow_mut().remove(handle)
+    }
+
+    /// Returns the number of event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventMutArgs;
+    ///
+    /// let event = EventMutArgs::<u32>::new();
+    /// event.add(|args| *args += 1);
+    ///
+    /// assert_eq!(event.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.handlers.borrow().len()
+    }
+
+    /// Returns `true` if the event has no handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventMutArgs;
+    ///
+    /// let event: EventMutArgs<()> = EventMutArgs::new();
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventMutArgs;
+    ///
+    /// let event = EventMutArgs::<u32>::new();
+    /// event.add(|args| { *args += 1; });
+    /// event.add(|args| { *args += 2; });
+    ///
+    /// event.clear(); // Remove all handlers
+    /// ```
+    pub fn clear(&self) {
+        self.handlers.borrow_mut().clear();
+    }
+
+    /// Invokes all event handlers sequentially (one after another),
+    /// passing each of them the same mutable event arguments.
+    ///
+    /// Handlers may add or remove handlers, which takes effect from the next
+    /// invocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventMutArgs;
+    ///
+    /// let event = EventMutArgs::new();
+    /// event.add(|args| { *args += 1; });
+    /// event.add(|args| { *args *= 10; });
+    ///
+    /// let mut value = 1;
+    /// event.invoke_mut(&mut value); // Execute all handlers in order
+    /// assert_eq!(value, 20);
+    /// ```
+    pub fn invoke_mut(&self, arg: &mut TEventArgs) {
+        self.invoke_mut_while(arg, |_| true);
+    }
+
+    /// Invokes the handlers in order as long as `proceed` returns `true` for the
+    /// event arguments before each call.
+    pub(crate) fn invoke_mut_while<P>(&self, arg: &mut TEventArgs, proceed: P)
+    where
+        P: Fn(&TEventArgs) -> bool,
+    {
+        let handlers: Vec<_> = self.handlers.borrow().iter().cloned().collect();
+        for handler in handlers {
+            if !proceed(arg) {
+                break;
+            }
+            handler(arg);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn test_invoke_mut() {
+        let event = EventMutArgs::new();
+
+        let mut log = vec![];
+        event.invoke_mut(&mut log);
+        assert!(log.is_empty());
+
+        event.add(|log: &mut Vec<i32>| log.push(1));
+        event.add(|log: &mut Vec<i32>| log.push(log.len() as i32 + 1));
+
+        event.invoke_mut(&mut log);
+        assert_eq!(log, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_insertion_order_after_reuse() {
+        let event = EventMutArgs::new();
+        let first = event.add(|log: &mut Vec<i32>| log.push(1));
+        event.add(|log| log.push(2));
+        assert!(event.remove(first));
+        event.add(|log| log.push(3));
+
+        let mut log = vec![];
+        event.invoke_mut(&mut log);
+        assert_eq!(log, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_remove_during_invoke() {
+        let event = Rc::new(EventMutArgs::new());
+        let second = Rc::new(Cell::new(None));
+        event.add({
+            let (event, second) = (Rc::downgrade(&event), Rc::clone(&second));
+            move |log: &mut Vec<i32>| {
+                log.push(1);
+                let event = event.upgrade().unwrap();
+                if let Some(handle) = second.take() {
+                    assert!(event.remove(handle));
+                }
+                event.add(|log| log.push(3));
+            }
+        });
+        second.set(Some(event.add(|log| log.push(2))));
+
+        let mut log = vec![];
+        event.invoke_mut(&mut log);
+        assert_eq!(log, vec![1, 2]);
+        assert_eq!(event.len(), 2);
+
+        event.clear();
+        event.invoke_mut(&mut log);
+        assert_eq!(log, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_remove_handler() {
+        let event = EventMutArgs::new();
+
+        let handle = event.add(|counter: &mut i32| *counter += 1);
+        assert!(event.remove(handle));
+
+        let mut counter = 0;
+        event.invoke_mut(&mut counter);
+        assert_eq!(counter, 0);
+    }
+}
@@ -0,0 +1,367 @@
+use alloc::vec::Vec;
+
+use crate::{Event, HandlerId};
+
+/// An event arguments enum that an [`EventMux`] can route by variant.
+///
+/// Variants are numbered from `0` to `VARIANTS - 1`, e.g. in declaration order.
+pub trait Multiplex {
+    /// The number of variants.
+    const VARIANTS: usize;
+
+    /// Returns the number of the variant of `self`.
+    fn variant(&self) -> usize;
+}
+
+/// The payload of one variant of a [`Multiplex`] enum, which handlers added with
+/// [`EventMux::on`] receive.
+pub trait Variant<E: Multiplex> {
+    /// The number of the variant, as returned by [`Multiplex::variant`].
+    const INDEX: usize;
+
+    /// Returns the payload if `args` is this variant.
+    fn get(args: &E) -> Option<&Self>;
+}
+
+/// An event for an arguments enum whose handlers subscribe to a single variant.
+///
+/// Each variant has its own list of handlers, so an invocation only looks up
+/// and runs the handlers of its variant, instead of running every handler and
+/// having each `match` on the arguments.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{EventMux, Multiplex, Variant};
+///
+/// struct MouseDown {
+///     button: u8,
+/// }
+///
+/// struct MouseMove {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// enum MouseEvent {
+///     Down(MouseDown),
+///     Move(MouseMove),
+/// }
+///
+/// impl Multiplex for MouseEvent {
+///     const VARIANTS: usize = 2;
+///
+///     fn variant(&self) -> usize {
+///         match self {
+///             MouseEvent::Down(_) => 0,
+///             MouseEvent::Move(_) => 1,
+///         }
+///     }
+/// }
+///
+/// impl Variant<MouseEvent> for MouseDown {
+///     const INDEX: usize = 0;
+///
+///     fn get(args: &MouseEvent) -> Option<&Self> {
+///         match args {
+///             MouseEvent::Down(down) => Some(down),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let mux = EventMux::<MouseEvent>::new();
+/// mux.on::<MouseDown>(|down| println!("Button {} pressed", down.button));
+///
+/// mux.invoke(&MouseEvent::Down(MouseDown { button: 1 })); // Prints "Button 1 pressed"
+/// mux.invoke(&MouseEvent::Move(MouseMove { x: 1, y: 2 })); // Runs no handler
+/// ```
+pub struct EventMux<'a, E> {
+    routes: Vec<Event<'a, E>>,
+}
+
+impl<'a, E: Multiplex> Default for EventMux<'a, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, E: Multiplex> EventMux<'a, E> {
+    /// Creates a new, empty EventMux
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{EventMux, Multiplex};
+    ///
+    /// enum Signal {
+    ///     Start,
+    ///     Stop,
+    /// }
+    ///
+    /// impl Multiplex for Signal {
+    ///     const VARIANTS: usize = 2;
+    ///
+    ///     fn variant(&self) -> usize {
+    ///         match self {
+    ///             Signal::Start => 0,
+    ///             Signal::Stop => 1,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mux: EventMux<Signal> = EventMux::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            routes: (0..E::VARIANTS).map(|_| Event::new()).collect(),
+        }
+    }
+
+    /// Adds an event handler for the variant whose payload is `V`.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// See [`EventMux`] for an example.
+    pub fn on<V>(&self, handler: impl Fn(&V) + 'a) -> HandlerId<E>
+    where
+        V: Variant<E> + ?Sized + 'a,
+    {
+        self.route(V::INDEX).add(move |args| {
+            if let Some(payload) = V::get(args) {
+                handler(payload);
+            }
+        })
+    }
+
+    /// Adds an event handler for the variant numbered `variant`, which receives
+    /// the whole event arguments.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `variant` is not less than [`Multiplex::VARIANTS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{EventMux, Multiplex};
+    ///
+    /// enum Signal {
+    ///     Start,
+    ///     Stop,
+    /// }
+    ///
+    /// impl Multiplex for Signal {
+    ///     const VARIANTS: usize = 2;
+    ///
+    ///     fn variant(&self) -> usize {
+    ///         match self {
+    ///             Signal::Start => 0,
+    ///             Signal::Stop => 1,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mux = EventMux::new();
+    /// mux.on_variant(1, |_| println!("Stopped"));
+    ///
+    /// mux.invoke(&Signal::Start); // Runs no handler
+    /// mux.invoke(&Signal::Stop); // Prints "Stopped"
+    /// ```
+    pub fn on_variant<F>(&self, variant: usize, handler: F) -> HandlerId<E>
+    where
+        F: Fn(&E) + 'a,
+    {
+        self.route(variant).add(handler)
+    }
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{EventMux, Multiplex};
+    ///
+    /// struct Tick;
+    ///
+    /// impl Multiplex for Tick {
+    ///     const VARIANTS: usize = 1;
+    ///
+    ///     fn variant(&self) -> usize {
+    ///         0
+    ///     }
+    /// }
+    ///
+    /// let mux = EventMux::new();
+    /// let handle = mux.on_variant(0, |_: &Tick| println!("Tick"));
+    ///
+    /// assert!(mux.remove(handle));
+    /// assert!(!mux.remove(handle)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<E>) -> bool {
+        // Handles are unique across events, so at most one route holds it.
+        self.routes.iter().any(|route| route.remove(handle))
+    }
+
+    /// Returns the number of event handlers of all variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{EventMux, Multiplex};
+    ///
+    /// struct Tick;
+    ///
+    /// impl Multiplex for Tick {
+    ///     const VARIANTS: usize = 1;
+    ///
+    ///     fn variant(&self) -> usize {
+    ///         0
+    ///     }
+    /// }
+    ///
+    /// let mux = EventMux::new();
+    /// mux.on_variant(0, |_: &Tick| println!("Tick"));
+    ///
+    /// assert_eq!(mux.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.routes.iter().map(Event::len).sum()
+    }
+
+    /// Returns `true` if no variant has handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{EventMux, Multiplex};
+    ///
+    /// struct Tick;
+    ///
+    /// impl Multiplex for Tick {
+    ///     const VARIANTS: usize = 1;
+    ///
+    ///     fn variant(&self) -> usize {
+    ///         0
+    ///     }
+    /// }
+    ///
+    /// let mux: EventMux<Tick> = EventMux::new();
+    /// assert!(mux.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all event handlers of all variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{EventMux, Multiplex};
+    ///
+    /// struct Tick;
+    ///
+    /// impl Multiplex for Tick {
+    ///     const VARIANTS: usize = 1;
+    ///
+    ///     fn variant(&self) -> usize {
+    ///         0
+    ///     }
+    /// }
+    ///
+    /// let mux = EventMux::new();
+    /// mux.on_variant(0, |_: &Tick| println!("Tick"));
+    ///
+    /// mux.clear(); // Remove all handlers
+    /// assert!(mux.is_empty());
+    /// ```
+    pub fn clear(&self) {
+        self.routes.iter().for_each(Event::clear);
+    }
+
+    /// Invokes the event handlers of the variant of `args` sequentially.
+    ///
+    /// See [`EventMux`] for an example.
+    pub fn invoke(&self, args: &E) {
+        self.route(args.variant()).invoke(args);
+    }
+
+    fn route(&self, variant: usize) -> &Event<'a, E> {
+        self.routes.get(variant).unwrap_or_else(|| {
+            panic!(
+                "variant {} out of range for {} variants",
+                variant,
+                E::VARIANTS
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    enum Input {
+        Key(char),
+        Click(i32, i32),
+        Quit,
+    }
+
+    impl Multiplex for Input {
+        const VARIANTS: usize = 3;
+
+        fn variant(&self) -> usize {
+            match self {
+                Input::Key(_) => 0,
+                Input::Click(..) => 1,
+                Input::Quit => 2,
+            }
+        }
+    }
+
+    impl Variant<Input> for char {
+        const INDEX: usize = 0;
+
+        fn get(args: &Input) -> Option<&Self> {
+            match args {
+                Input::Key(key) => Some(key),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_routes_by_variant() {
+        let log = RefCell::new(vec![]);
+        let mux = EventMux::new();
+        let key = mux.on::<char>(|key| log.borrow_mut().push(format!("key {}", key)));
+        mux.on_variant(1, |args| {
+            if let Input::Click(x, y) = args {
+                log.borrow_mut().push(format!("click {} {}", x, y));
+            }
+        });
+        assert_eq!(mux.len(), 2);
+
+        mux.invoke(&Input::Key('a'));
+        mux.invoke(&Input::Click(1, 2));
+        mux.invoke(&Input::Quit);
+        assert!(mux.remove(key));
+        assert!(!mux.remove(key));
+        mux.invoke(&Input::Key('b'));
+
+        assert_eq!(*log.borrow(), vec!["key a", "click 1 2"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "variant 3 out of range for 3 variants")]
+    fn test_variant_out_of_range() {
+        EventMux::<Input>::new().on_variant(3, |_| {});
+    }
+}
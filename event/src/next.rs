@@ -0,0 +1,149 @@
+use alloc::rc::{Rc, Weak};
+use core::cell::RefCell;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use crate::handlers::{Attachment, Handler};
+use crate::{DefaultStorage, Event};
+
+struct Slot<TEventArgs> {
+    value: Option<TEventArgs>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+/// The handler side of an [`EventNext`], which resolves the future when the
+/// handler is dropped, either after it ran or because the event was dropped.
+struct Sender<TEventArgs> {
+    slot: Weak<RefCell<Slot<TEventArgs>>>,
+}
+
+impl<TEventArgs> Drop for Sender<TEventArgs> {
+    fn drop(&mut self) {
+        if let Some(slot) = self.slot.upgrade() {
+            let mut slot = slot.borrow_mut();
+            slot.closed = true;
+            if let Some(waker) = slot.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// A future that resolves with the arguments of the next invocation of an
+/// event, see [`Event::next`].
+///
+/// Dropping the future before it resolves removes its handler from the event.
+#[must_use = "futures do nothing unless polled"]
+pub struct EventNext<'a, TEventArgs> {
+    slot: Rc<RefCell<Slot<TEventArgs>>>,
+    _attachment: Attachment<'a, TEventArgs, (), DefaultStorage>,
+}
+
+impl<TEventArgs> Future for EventNext<'_, TEventArgs> {
+    type Output = Option<TEventArgs>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.slot.borrow_mut();
+        match slot.value.take() {
+            Some(arg) => Poll::Ready(Some(arg)),
+            None if slot.closed => Poll::Ready(None),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<'a, TEventArgs: Clone + 'a> Event<'a, TEventArgs> {
+    /// Returns a future that resolves with a clone of the event arguments the
+    /// next time the event is invoked.
+    ///
+    /// The invocation is recorded even if it happens before the future is first
+    /// polled. The future resolves with `None` if the event is dropped or cleared
+    /// before it is invoked. Dropping the future first, for example when it
+    /// loses a `select!`, removes its handler from the event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// let next = event.next();
+    ///
+    /// event.invoke(&1);
+    /// event.invoke(&2);
+    ///
+    /// # futures::executor::block_on(async {
+    /// assert_eq!(next.await, Some(1));
+    /// # });
+    /// ```
+    pub fn next(&self) -> EventNext<'a, TEventArgs> {
+        let slot = Rc::new(RefCell::new(Slot {
+            value: None,
+            waker: None,
+            closed: false,
+        }));
+        let sender = Sender {
+            slot: Rc::downgrade(&slot),
+        };
+        let attachment = self.attach(Handler::once(move |arg: &TEventArgs| {
+            if let Some(slot) = sender.slot.upgrade() {
+                slot.borrow_mut().value = Some(arg.clone());
+            }
+        }));
+        EventNext {
+            slot,
+            _attachment: attachment,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+
+    use super::*;
+
+    #[test]
+    fn test_next() {
+        let event = Event::new();
+        let first = event.next();
+        let second = event.next();
+        assert_eq!(event.len(), 2);
+
+        event.invoke(&1);
+        let third = event.next();
+        event.invoke(&2);
+        assert!(event.is_empty());
+
+        assert_eq!(block_on(first), Some(1));
+        assert_eq!(block_on(second), Some(1));
+        assert_eq!(block_on(third), Some(2));
+
+        let pending = event.next();
+        drop(event);
+        assert_eq!(block_on(pending), None);
+    }
+
+    #[test]
+    fn test_drop_next() {
+        let event = Event::new();
+        let next = event.next();
+        assert_eq!(event.len(), 1);
+        drop(next);
+        assert_eq!(event.len(), 0);
+
+        let handle = event.add(|_: &i32| {});
+        drop(event.next());
+        assert_eq!(event.handles(), vec![handle]);
+
+        let resolved = event.next();
+        event.invoke(&1);
+        assert_eq!(block_on(resolved), Some(1));
+        assert_eq!(event.handles(), vec![handle]);
+    }
+}
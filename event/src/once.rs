@@ -0,0 +1,235 @@
+use core::cell::OnceCell;
+
+use crate::{Event, HandlerId};
+
+/// An event that can be invoked only once, like a promise.
+///
+/// The event keeps the event arguments of its single invocation. Handlers added
+/// afterwards are called right away with them instead of being stored, and
+/// further invocations are refused. This suits signals such as "initialization
+/// complete", which late subscribers must not miss.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::OnceEvent;
+///
+/// let ready = OnceEvent::<&str>::new();
+/// ready.add(|version| println!("Ready, version {}", version));
+///
+/// assert!(ready.invoke("1.0")); // Prints "Ready, version 1.0"
+/// assert!(!ready.invoke("2.0")); // Refused, already invoked
+///
+/// ready.add(|version| println!("Late, version {}", version)); // Prints "Late, version 1.0"
+/// ```
+pub struct OnceEvent<'a, TEventArgs> {
+    event: Event<'a, TEventArgs>,
+    value: OnceCell<TEventArgs>,
+}
+
+impl<'a, TEventArgs> Default for OnceEvent<'a, TEventArgs> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, TEventArgs> OnceEvent<'a, TEventArgs> {
+    /// Creates a new, empty OnceEvent that has not been invoked yet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::OnceEvent;
+    ///
+    /// let event: OnceEvent<()> = OnceEvent::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            event: Event::new(),
+            value: OnceCell::new(),
+        }
+    }
+
+    /// Adds an event handler to the event.
+    ///
+    /// If the event has been invoked, the handler is called right away with the
+    /// event arguments and is not stored, and `None` is returned. Otherwise,
+    /// returns a handle that can be used to remove the handler before it fires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::OnceEvent;
+    ///
+    /// let event = OnceEvent::<u32>::new();
+    /// let handle = event.add(|args| println!("Event invoked with {}", args));
+    /// assert!(handle.is_some());
+    ///
+    /// event.invoke(1);
+    /// assert!(event.add(|args| println!("Event invoked with {}", args)).is_none());
+    /// ```
+    pub fn add<F>(&self, handler: F) -> Option<HandlerId<TEventArgs>>
+    where
+        F: FnOnce(&TEventArgs) + 'a,
+    {
+        match self.value.get() {
+            Some(arg) => {
+                handler(arg);
+                None
+            }
+            None => Some(self.event.once(handler)),
+        }
+    }
+
+    /// Removes an event handler that has not fired yet using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::OnceEvent;
+    ///
+    /// let event = OnceEvent::<u32>::new();
+    /// let handle = event.add(|args| println!("Event invoked with {}", args)).unwrap();
+    ///
+    /// assert!(event.remove(handle));
+    /// assert!(!event.remove(handle)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        self.event.remove(handle)
+    }
+
+    /// Returns the number of event handlers waiting for the invocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::OnceEvent;
+    ///
+    /// let event = OnceEvent::<u32>::new();
+    /// event.add(|args| println!("Handler 1"));
+    /// assert_eq!(event.len(), 1);
+    ///
+    /// event.invoke(1);
+    /// assert_eq!(event.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.event.len()
+    }
+
+    /// Returns `true` if no event handlers are waiting for the invocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::OnceEvent;
+    ///
+    /// let event: OnceEvent<()> = OnceEvent::new();
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.event.is_empty()
+    }
+
+    /// Stores the event arguments and invokes all event handlers, if the event
+    /// has not been invoked before.
+    ///
+    /// Returns `true` if the event was invoked, or `false` if it had already been
+    /// invoked, in which case `arg` is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::OnceEvent;
+    ///
+    /// let event = OnceEvent::new();
+    /// event.add(|args| { println!("Handler 1"); });
+    ///
+    /// assert!(event.invoke(())); // Prints "Handler 1"
+    /// assert!(!event.invoke(())); // Does nothing
+    /// ```
+    pub fn invoke(&self, arg: TEventArgs) -> bool {
+        if self.value.set(arg).is_err() {
+            return false;
+        }
+        if let Some(arg) = self.value.get() {
+            self.event.invoke(arg);
+        }
+        true
+    }
+
+    /// Returns `true` if the event has been invoked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::OnceEvent;
+    ///
+    /// let event = OnceEvent::new();
+    /// assert!(!event.is_invoked());
+    ///
+    /// event.invoke(());
+    /// assert!(event.is_invoked());
+    /// ```
+    pub fn is_invoked(&self) -> bool {
+        self.value.get().is_some()
+    }
+
+    /// Returns the event arguments of the invocation, if the event has been invoked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::OnceEvent;
+    ///
+    /// let event = OnceEvent::new();
+    /// assert_eq!(event.value(), None);
+    ///
+    /// event.invoke(42);
+    /// assert_eq!(event.value(), Some(&42));
+    /// ```
+    pub fn value(&self) -> Option<&TEventArgs> {
+        self.value.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn test_invoke_once() {
+        let log = RefCell::new(vec![]);
+        let event = OnceEvent::new();
+
+        event.add(|arg: &i32| log.borrow_mut().push(("early", *arg)));
+        assert!(event.invoke(1));
+        assert!(!event.invoke(2));
+        assert!(event.is_empty());
+        event.add(|arg: &i32| log.borrow_mut().push(("late", *arg)));
+
+        assert_eq!(*log.borrow(), vec![("early", 1), ("late", 1)]);
+    }
+
+    #[test]
+    fn test_add_and_invoke_from_handler() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = Rc::new(OnceEvent::new());
+
+        let weak = Rc::downgrade(&event);
+        let log_clone = Rc::clone(&log);
+        event.add(move |arg: &i32| {
+            let event = weak.upgrade().unwrap();
+            assert!(!event.invoke(*arg + 1));
+            let log = Rc::clone(&log_clone);
+            event.add(move |arg| log.borrow_mut().push(*arg));
+        });
+
+        event.invoke(1);
+        assert_eq!(*log.borrow(), vec![1]);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{Event, HandlerId, Storage};
+
+/// A coarse stage of an invocation, for handlers that must run before or after
+/// all others without agreeing on priority numbers.
+///
+/// Phases are the extreme priorities: handlers in [`Pre`](Self::Pre) run before
+/// handlers of any other priority, and handlers in [`Post`](Self::Post) after
+/// them. Within a phase, handlers run in the order they were added.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// Runs first, e.g. for validation or setup by plugins.
+    Pre,
+    /// Runs in between, like handlers added with [`Event::add`].
+    #[default]
+    Normal,
+    /// Runs last, e.g. for cleanup or logging by plugins.
+    Post,
+}
+
+impl Phase {
+    /// Returns the priority that handlers in this phase are added with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Phase;
+    ///
+    /// assert_eq!(Phase::Normal.priority(), 0);
+    /// assert!(Phase::Pre.priority() > Phase::Post.priority());
+    /// ```
+    pub const fn priority(self) -> i32 {
+        match self {
+            Phase::Pre => i32::MAX,
+            Phase::Normal => 0,
+            Phase::Post => i32::MIN,
+        }
+    }
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> Event<'a, TEventArgs, TResult, TStorage> {
+    /// Adds an event handler to the event in the given phase.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Event, Phase};
+    ///
+    /// let event = Event::<(), &str>::new();
+    /// event.add_in_phase(Phase::Post, |_| "post");
+    /// event.add(|_| "normal");
+    /// event.add_in_phase(Phase::Pre, |_| "pre");
+    ///
+    /// assert_eq!(event.invoke_collect(&()), vec!["pre", "normal", "post"]);
+    /// ```
+    pub fn add_in_phase<F>(&self, phase: Phase, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        self.add_with_priority(phase.priority(), handler)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phases_around_priorities() {
+        let event = Event::<(), &str>::new();
+        event.add_in_phase(Phase::Post, |_| "post 1");
+        event.add_with_priority(-100, |_| "low");
+        event.add_in_phase(Phase::Pre, |_| "pre 1");
+        event.add_with_priority(100, |_| "high");
+        event.add_in_phase(Phase::Normal, |_| "normal");
+        event.add_in_phase(Phase::Pre, |_| "pre 2");
+        event.add_in_phase(Phase::Post, |_| "post 2");
+
+        assert_eq!(
+            event.invoke_collect(&()),
+            vec![
+                "pre 1", "pre 2", "high", "normal", "low", "post 1", "post 2"
+            ]
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use alloc::vec::Vec;
+use core::cell::Cell;
+
+use crate::{Event, HandlerId};
+
+/// The phase of a [`PropagatingEvent`] dispatch that a handler runs in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PropagationPhase {
+    /// Running capture handlers on an ancestor of the target, from the root down.
+    Capturing,
+    /// Running the handlers of the target itself.
+    AtTarget,
+    /// Running bubble handlers on an ancestor of the target, back up to the root.
+    Bubbling,
+}
+
+/// The event arguments of a [`PropagatingEvent`], wrapped with the state of the
+/// dispatch.
+pub struct PropagationArgs<TEventArgs> {
+    args: TEventArgs,
+    phase: Cell<PropagationPhase>,
+    stopped: Cell<bool>,
+}
+
+impl<TEventArgs> PropagationArgs<TEventArgs> {
+    /// Returns the event arguments.
+    pub fn args(&self) -> &TEventArgs {
+        &self.args
+    }
+
+    /// Returns the phase of the dispatch.
+    pub fn phase(&self) -> PropagationPhase {
+        self.phase.get()
+    }
+
+    /// Stops the dispatch from reaching further nodes, once the handlers of the
+    /// current node have run.
+    pub fn stop_propagation(&self) {
+        self.stopped.set(true);
+    }
+
+    /// Returns `true` if a handler stopped the propagation.
+    pub fn is_propagation_stopped(&self) -> bool {
+        self.stopped.get()
+    }
+}
+
+/// An event of a node in a tree, which propagates through the path from the root
+/// to that node like a DOM event.
+///
+/// Each node of the tree holds a `PropagatingEvent` and implements
+/// [`EventTarget`] to expose it and its parent. Dispatching on a node runs the
+/// capture handlers of its ancestors from the root down, then the handlers of
+/// the node itself, then the bubble handlers of its ancestors back up to the
+/// root. Any handler can stop the propagation at its node.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{EventTarget, PropagatingEvent};
+///
+/// struct Widget<'t> {
+///     name: &'static str,
+///     parent: Option<&'t Widget<'t>>,
+///     clicked: PropagatingEvent<'static, (i32, i32)>,
+/// }
+///
+/// impl<'t> EventTarget<'static, (i32, i32)> for &'t Widget<'t> {
+///     fn parent(&self) -> Option<Self> {
+///         self.parent
+///     }
+///
+///     fn event(&self) -> &PropagatingEvent<'static, (i32, i32)> {
+///         &self.clicked
+///     }
+/// }
+///
+/// let window = Widget { name: "window", parent: None, clicked: PropagatingEvent::new() };
+/// let button = Widget { name: "button", parent: Some(&window), clicked: PropagatingEvent::new() };
+///
+/// window.clicked.add_capture(|e| println!("Window sees the click first, at {:?}", e.args()));
+/// button.clicked.add(|e| println!("Button clicked"));
+/// window.clicked.add(|e| println!("Window sees the click last"));
+///
+/// assert!((&button).dispatch_event((1, 2)));
+/// ```
+pub struct PropagatingEvent<'a, TEventArgs> {
+    capture: Event<'a, PropagationArgs<TEventArgs>>,
+    bubble: Event<'a, PropagationArgs<TEventArgs>>,
+}
+
+impl<'a, TEventArgs> Default for PropagatingEvent<'a, TEventArgs> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, TEventArgs> PropagatingEvent<'a, TEventArgs> {
+    /// Creates a new, empty PropagatingEvent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::PropagatingEvent;
+    ///
+    /// let event: PropagatingEvent<()> = PropagatingEvent::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            capture: Event::new(),
+            bubble: Event::new(),
+        }
+    }
+
+    /// Adds a bubble handler, which runs when the event is dispatched on this node
+    /// or bubbles up from one of its descendants.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::PropagatingEvent;
+    ///
+    /// let event = PropagatingEvent::<u32>::new();
+    /// let handle = event.add(|e| println!("Bubbled with {}", e.args()));
+    /// ```
+    pub fn add<F>(&self, handler: F) -> HandlerId<PropagationArgs<TEventArgs>>
+    where
+        F: Fn(&PropagationArgs<TEventArgs>) + 'a,
+    {
+        self.bubble.add(handler)
+    }
+
+    /// Adds a capture handler, which runs when the event is dispatched on this node
+    /// or is on its way down to one of its descendants.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::PropagatingEvent;
+    ///
+    /// let event = PropagatingEvent::<u32>::new();
+    /// let handle = event.add_capture(|e| e.stop_propagation());
+    /// ```
+    pub fn add_capture<F>(&self, handler: F) -> HandlerId<PropagationArgs<TEventArgs>>
+    where
+        F: Fn(&PropagationArgs<TEventArgs>) + 'a,
+    {
+        self.capture.add(handler)
+    }
+
+    /// Removes a bubble or capture handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::PropagatingEvent;
+    ///
+    /// let event = PropagatingEvent::<u32>::new();
+    /// let handle = event.add_capture(|e| e.stop_propagation());
+    ///
+    /// assert!(event.remove(handle));
+    /// assert!(!event.remove(handle)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<PropagationArgs<TEventArgs>>) -> bool {
+        self.capture.remove(handle) || self.bubble.remove(handle)
+    }
+
+    /// Returns the number of bubble and capture handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::PropagatingEvent;
+    ///
+    /// let event = PropagatingEvent::<u32>::new();
+    /// event.add(|e| println!("Handler 1"));
+    /// event.add_capture(|e| println!("Handler 2"));
+    ///
+    /// assert_eq!(event.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.capture.len() + self.bubble.len()
+    }
+
+    /// Returns `true` if the event has no handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::PropagatingEvent;
+    ///
+    /// let event: PropagatingEvent<()> = PropagatingEvent::new();
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::PropagatingEvent;
+    ///
+    /// let event = PropagatingEvent::<u32>::new();
+    /// event.add(|e| println!("Handler 1"));
+    /// event.add_capture(|e| println!("Handler 2"));
+    ///
+    /// event.clear(); // Remove all handlers
+    /// ```
+    pub fn clear(&self) {
+        self.capture.clear();
+        self.bubble.clear();
+    }
+}
+
+/// A node of a tree whose events propagate, see [`PropagatingEvent`].
+///
+/// This is usually implemented for a cheap handle to a node, such as a reference
+/// or an `Rc`, so that [`parent`](Self::parent) can return one.
+pub trait EventTarget<'a, TEventArgs>: Sized {
+    /// Returns the parent of the node, or `None` for the root.
+    fn parent(&self) -> Option<Self>;
+
+    /// Returns the event of the node.
+    fn event(&self) -> &PropagatingEvent<'a, TEventArgs>;
+
+    /// Dispatches the event on this node, through the capture, target and bubble
+    /// phases.
+    ///
+    /// Returns `true` if the event reached the end of the bubble phase, or
+    /// `false` if a handler stopped the propagation.
+    fn dispatch_event(&self, args: TEventArgs) -> bool {
+        let mut ancestors = Vec::new();
+        let mut node = self.parent();
+        while let Some(parent) = node {
+            node = parent.parent();
+            ancestors.push(parent);
+        }
+
+        let args = PropagationArgs {
+            args,
+            phase: Cell::new(PropagationPhase::Capturing),
+            stopped: Cell::new(false),
+        };
+        let run = |event: &Event<'a, PropagationArgs<TEventArgs>>, phase| {
+            args.phase.set(phase);
+            event.invoke(&args);
+            !args.is_propagation_stopped()
+        };
+
+        ancestors
+            .iter()
+            .rev()
+            .all(|node| run(&node.event().capture, PropagationPhase::Capturing))
+            && run(&self.event().capture, PropagationPhase::AtTarget)
+            && run(&self.event().bubble, PropagationPhase::AtTarget)
+            && ancestors
+                .iter()
+                .all(|node| run(&node.event().bubble, PropagationPhase::Bubbling))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::{Rc, Weak};
+
+    use super::*;
+
+    struct Node {
+        parent: Weak<Node>,
+        event: PropagatingEvent<'static, u32>,
+    }
+
+    struct Handle(Rc<Node>);
+
+    impl EventTarget<'static, u32> for Handle {
+        fn parent(&self) -> Option<Self> {
+            self.0.parent.upgrade().map(Handle)
+        }
+
+        fn event(&self) -> &PropagatingEvent<'static, u32> {
+            &self.0.event
+        }
+    }
+
+    fn tree(log: &Rc<RefCell<Vec<String>>>) -> Vec<Rc<Node>> {
+        let mut nodes: Vec<Rc<Node>> = vec![];
+        for name in ["root", "panel", "button"] {
+            let node = Rc::new(Node {
+                parent: nodes.last().map(Rc::downgrade).unwrap_or_default(),
+                event: PropagatingEvent::new(),
+            });
+            for capture in [true, false] {
+                let log = Rc::clone(log);
+                let handler = move |e: &PropagationArgs<u32>| {
+                    log.borrow_mut()
+                        .push(format!("{} {:?} {}", name, e.phase(), e.args()));
+                };
+                if capture {
+                    node.event.add_capture(handler);
+                } else {
+                    node.event.add(handler);
+                }
+            }
+            nodes.push(node);
+        }
+        nodes
+    }
+
+    #[test]
+    fn test_capture_and_bubble() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let nodes = tree(&log);
+
+        assert!(Handle(Rc::clone(&nodes[2])).dispatch_event(7));
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                "root Capturing 7",
+                "panel Capturing 7",
+                "button AtTarget 7",
+                "button AtTarget 7",
+                "panel Bubbling 7",
+                "root Bubbling 7",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stop_propagation() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let nodes = tree(&log);
+        nodes[1].event.add(|e| e.stop_propagation());
+
+        assert!(!Handle(Rc::clone(&nodes[2])).dispatch_event(1));
+        assert_eq!(log.borrow().last().unwrap(), "panel Bubbling 1");
+
+        log.borrow_mut().clear();
+        nodes[0].event.add_capture(|e| e.stop_propagation());
+        assert!(!Handle(Rc::clone(&nodes[1])).dispatch_event(2));
+        assert_eq!(*log.borrow(), vec!["root Capturing 2"]);
+    }
+}
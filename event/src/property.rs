@@ -0,0 +1,263 @@
+use alloc::borrow::Cow;
+use core::cell::RefCell;
+
+use crate::Event;
+
+/// Event arguments of [`ObservableProperty::changed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueChanged<T> {
+    /// The value before the change.
+    pub old: T,
+    /// The value after the change.
+    pub new: T,
+}
+
+/// A value that raises an event whenever it changes.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::ObservableProperty;
+///
+/// let volume = ObservableProperty::new(50);
+/// volume.changed().add(|change| {
+///     println!("Volume changed from {} to {}", change.old, change.new);
+/// });
+///
+/// volume.set(80); // Prints "Volume changed from 50 to 80"
+/// volume.set(80); // Does nothing, the value is unchanged
+/// assert_eq!(volume.get(), 80);
+/// ```
+pub struct ObservableProperty<'a, T> {
+    value: RefCell<T>,
+    changed: Event<'a, ValueChanged<T>>,
+}
+
+impl<'a, T: Default> Default for ObservableProperty<'a, T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<'a, T> ObservableProperty<'a, T> {
+    /// Creates a new ObservableProperty holding `value`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableProperty;
+    ///
+    /// let title = ObservableProperty::new(String::from("Untitled"));
+    /// ```
+    pub fn new(value: T) -> Self {
+        Self {
+            value: RefCell::new(value),
+            changed: Event::new(),
+        }
+    }
+
+    /// Returns the event raised with the old and new values after the value changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableProperty;
+    ///
+    /// let enabled = ObservableProperty::new(false);
+    /// enabled.changed().add(|change| println!("Enabled: {}", change.new));
+    /// ```
+    pub fn changed(&self) -> &Event<'a, ValueChanged<T>> {
+        &self.changed
+    }
+
+    /// Returns a copy of the current value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableProperty;
+    ///
+    /// let count = ObservableProperty::new(1);
+    /// assert_eq!(count.get(), 1);
+    /// ```
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.value.borrow().clone()
+    }
+
+    /// Sets the value, and raises [`changed`](Self::changed) if it differs from
+    /// the current one.
+    ///
+    /// Handlers see the new value through [`get`](Self::get) and may set the
+    /// property again.
+    ///
+    /// Returns `true` if the value changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::ObservableProperty;
+    ///
+    /// let count = ObservableProperty::new(1);
+    /// assert!(count.set(2));
+    /// assert!(!count.set(2));
+    /// ```
+    pub fn set(&self, value: T) -> bool
+    where
+        T: Clone + PartialEq,
+    {
+        if *self.value.borrow() == value {
+            return false;
+        }
+        let old = self.value.replace(value.clone());
+        self.changed.invoke(&ValueChanged { old, new: value });
+        true
+    }
+}
+
+/// Event arguments of [`NotifyPropertyChanged::property_changed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyChangedArgs {
+    /// The name of the property that changed.
+    pub property_name: Cow<'static, str>,
+}
+
+impl PropertyChangedArgs {
+    /// Creates event arguments for a change to the property named `property_name`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::PropertyChangedArgs;
+    ///
+    /// let args = PropertyChangedArgs::new("title");
+    /// assert_eq!(args.property_name, "title");
+    /// ```
+    pub fn new(property_name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            property_name: property_name.into(),
+        }
+    }
+}
+
+/// A type that raises an event whenever one of its properties changes, like
+/// C#'s `INotifyPropertyChanged`.
+///
+/// This lets binding code observe any implementor without knowing its type.
+/// Implementors raise the event with [`raise_property_changed!`](crate::raise_property_changed).
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+///
+/// use event_rs::{Event, NotifyPropertyChanged, PropertyChangedArgs, raise_property_changed};
+///
+/// struct Document {
+///     title: RefCell<String>,
+///     property_changed: Event<'static, PropertyChangedArgs>,
+/// }
+///
+/// impl Document {
+///     fn set_title(&self, title: &str) {
+///         *self.title.borrow_mut() = title.to_string();
+///         raise_property_changed!(self.title);
+///     }
+/// }
+///
+/// impl NotifyPropertyChanged<'static> for Document {
+///     fn property_changed(&self) -> &Event<'static, PropertyChangedArgs> {
+///         &self.property_changed
+///     }
+/// }
+///
+/// fn bind(source: &impl NotifyPropertyChanged<'static>) {
+///     source.property_changed().add(|args| println!("{} changed", args.property_name));
+/// }
+///
+/// let document = Document {
+///     title: RefCell::new(String::new()),
+///     property_changed: Event::new(),
+/// };
+/// bind(&document);
+/// document.set_title("Notes"); // Prints "title changed"
+/// ```
+pub trait NotifyPropertyChanged<'a> {
+    /// Returns the event raised after a property changes.
+    fn property_changed(&self) -> &Event<'a, PropertyChangedArgs>;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn test_set() {
+        let log = RefCell::new(vec![]);
+        let property = ObservableProperty::new(1);
+        property
+            .changed()
+            .add(|change| log.borrow_mut().push((change.old, change.new)));
+
+        assert!(property.set(2));
+        assert!(!property.set(2));
+        assert!(property.set(3));
+        assert_eq!(property.get(), 3);
+        assert_eq!(*log.borrow(), vec![(1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn test_set_from_handler() {
+        let property = Rc::new(ObservableProperty::new(0));
+        let weak = Rc::downgrade(&property);
+        property.changed().add(move |change| {
+            let property = weak.upgrade().unwrap();
+            assert_eq!(property.get(), change.new);
+            if change.new > 10 {
+                property.set(10);
+            }
+        });
+
+        property.set(42);
+        assert_eq!(property.get(), 10);
+    }
+
+    struct Person {
+        name: RefCell<String>,
+        property_changed: Event<'static, PropertyChangedArgs>,
+    }
+
+    impl Person {
+        fn rename(&self, name: &str) {
+            *self.name.borrow_mut() = name.to_string();
+            crate::raise_property_changed!(self.name);
+        }
+    }
+
+    impl NotifyPropertyChanged<'static> for Person {
+        fn property_changed(&self) -> &Event<'static, PropertyChangedArgs> {
+            &self.property_changed
+        }
+    }
+
+    #[test]
+    fn test_notify_property_changed() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let person = Person {
+            name: RefCell::new(String::new()),
+            property_changed: Event::new(),
+        };
+
+        let log_clone = Rc::clone(&log);
+        person
+            .property_changed()
+            .add(move |args| log_clone.borrow_mut().push(args.clone()));
+        person.rename("Ada");
+        assert_eq!(*log.borrow(), vec![PropertyChangedArgs::new("name")]);
+    }
+}
@@ -0,0 +1,99 @@
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+
+use crate::{Event, Storage};
+
+/// Type alias for request/response events.
+///
+/// The invoker sends a query of type `TQuery`, and each handler replies with
+/// `Some` answer of type `TReply`, or declines with `None`. See
+/// [`invoke_query`](Event::invoke_query) and
+/// [`first_response`](Event::first_response).
+pub type QueryEvent<'a, TQuery, TReply> = Event<'a, TQuery, Option<TReply>>;
+
+impl<'a, TQuery, TReply, TStorage: Storage> Event<'a, TQuery, Option<TReply>, TStorage> {
+    /// Sends a query to all handlers and collects their replies in invocation
+    /// order, skipping the handlers that declined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::QueryEvent;
+    ///
+    /// let open_with = QueryEvent::<&str, &str>::new();
+    /// open_with.add(|ext| (*ext == "png").then_some("Image Viewer"));
+    /// open_with.add(|ext| Some("Text Editor"));
+    ///
+    /// assert_eq!(open_with.invoke_query(&"png"), vec!["Image Viewer", "Text Editor"]);
+    /// assert_eq!(open_with.invoke_query(&"txt"), vec!["Text Editor"]);
+    /// ```
+    pub fn invoke_query(&self, query: &TQuery) -> Vec<TReply> {
+        let mut replies = Vec::new();
+        self.invoke_with(query, |call| {
+            replies.extend(call().flatten());
+            ControlFlow::Continue(())
+        });
+        replies
+    }
+
+    /// Sends a query to the handlers until one replies, returning its reply, or
+    /// `None` if all of them declined.
+    ///
+    /// The handlers after the one that replied are not called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::QueryEvent;
+    ///
+    /// let resolve = QueryEvent::<&str, u16>::new();
+    /// resolve.add(|service| (*service == "http").then_some(80));
+    /// resolve.add(|service| (*service == "ssh").then_some(22));
+    ///
+    /// assert_eq!(resolve.first_response(&"ssh"), Some(22));
+    /// assert_eq!(resolve.first_response(&"ftp"), None);
+    /// ```
+    pub fn first_response(&self, query: &TQuery) -> Option<TReply> {
+        let mut reply = None;
+        self.invoke_with(query, |call| match call().flatten() {
+            Some(answer) => {
+                reply = Some(answer);
+                ControlFlow::Break(())
+            }
+            None => ControlFlow::Continue(()),
+        });
+        reply
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_query() {
+        let calls = Cell::new(0);
+        let event = QueryEvent::<i32, i32>::new();
+        event.add(|_| {
+            calls.set(calls.get() + 1);
+            None
+        });
+        event.add(|query| {
+            calls.set(calls.get() + 1);
+            (*query > 0).then_some(query * 10)
+        });
+        event.add(|query| {
+            calls.set(calls.get() + 1);
+            Some(query * 100)
+        });
+
+        assert_eq!(event.invoke_query(&1), vec![10, 100]);
+        assert_eq!(calls.replace(0), 3);
+        assert_eq!(event.first_response(&1), Some(10));
+        assert_eq!(calls.replace(0), 2);
+        assert_eq!(event.first_response(&-1), Some(-100));
+        assert_eq!(QueryEvent::<(), ()>::new().first_response(&()), None);
+    }
+}
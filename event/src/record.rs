@@ -0,0 +1,223 @@
+use std::cell::{RefCell, RefMut};
+use std::io::{Read, Write};
+use std::rc::Rc;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::{Event, Storage};
+
+/// Records the invocations of events to a writer, so they can be replayed later
+/// with [`replay`].
+///
+/// Each invocation is written as one line of JSON, before the handlers run. A
+/// recorder can record any number of events whose event arguments serialize to
+/// the same format.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{Event, EventRecorder, replay};
+///
+/// let recorder = EventRecorder::new(Vec::new());
+/// let event = Event::<(i32, i32)>::new();
+/// recorder.record(&event);
+///
+/// event.invoke(&(1, 2));
+/// event.invoke(&(3, 4));
+///
+/// let trace = recorder.writer().clone();
+/// let replayed = Event::<(i32, i32)>::new();
+/// replayed.add(|(x, y)| println!("Clicked at ({}, {})", x, y));
+/// assert_eq!(replay(trace.as_slice(), &replayed).unwrap(), 2);
+/// ```
+pub struct EventRecorder<W> {
+    state: Rc<RefCell<RecorderState<W>>>,
+}
+
+struct RecorderState<W> {
+    writer: W,
+    error: Option<serde_json::Error>,
+}
+
+impl<W: Write> EventRecorder<W> {
+    /// Creates a new EventRecorder that writes to `writer`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventRecorder;
+    ///
+    /// let recorder = EventRecorder::new(std::io::sink());
+    /// ```
+    pub fn new(writer: W) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(RecorderState {
+                writer,
+                error: None,
+            })),
+        }
+    }
+
+    /// Starts recording the invocations of `event`, as middleware.
+    ///
+    /// The recording lasts as long as the event. If writing an invocation fails,
+    /// the handlers still run and the error is kept for
+    /// [`take_error`](Self::take_error).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Event, EventRecorder};
+    ///
+    /// let recorder = EventRecorder::new(Vec::new());
+    /// let event = Event::<u32>::new();
+    /// recorder.record(&event);
+    ///
+    /// event.invoke(&7);
+    /// assert_eq!(recorder.writer().as_slice(), b"7\n");
+    /// ```
+    pub fn record<'a, TEventArgs, TResult, TStorage>(
+        &self,
+        event: &Event<'a, TEventArgs, TResult, TStorage>,
+    ) where
+        TEventArgs: Serialize,
+        TStorage: Storage,
+        W: 'a,
+    {
+        let state = Rc::clone(&self.state);
+        event.wrap(move |arg, next| {
+            {
+                let mut state = state.borrow_mut();
+                let RecorderState { writer, error } = &mut *state;
+                let written = serde_json::to_writer(&mut *writer, arg)
+                    .and_then(|()| writer.write_all(b"\n").map_err(serde_json::Error::io));
+                if let Err(e) = written {
+                    error.get_or_insert(e);
+                }
+            }
+            next(arg);
+        });
+    }
+
+    /// Returns the writer.
+    ///
+    /// # Panics
+    ///
+    /// Invoking a recorded event while the writer is borrowed panics.
+    pub fn writer(&self) -> RefMut<'_, W> {
+        RefMut::map(self.state.borrow_mut(), |state| &mut state.writer)
+    }
+
+    /// Returns the first error that occurred while recording, if any, and clears it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::EventRecorder;
+    ///
+    /// let recorder = EventRecorder::new(Vec::new());
+    /// assert!(recorder.take_error().is_none());
+    /// ```
+    pub fn take_error(&self) -> Option<serde_json::Error> {
+        self.state.borrow_mut().error.take()
+    }
+}
+
+/// Invokes `event` once for each invocation read from a trace written by an
+/// [`EventRecorder`], in the order they were recorded.
+///
+/// Returns the number of invocations replayed, or the error that stopped the
+/// replay, after the invocations read before it.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{Event, replay};
+///
+/// let event = Event::<String>::new();
+/// event.add(|key| println!("Pressed {}", key));
+///
+/// let trace = "\"a\"\n\"b\"\n";
+/// assert_eq!(replay(trace.as_bytes(), &event).unwrap(), 2); // Prints "Pressed a", then "Pressed b"
+/// ```
+pub fn replay<R, TEventArgs, TResult, TStorage>(
+    reader: R,
+    event: &Event<'_, TEventArgs, TResult, TStorage>,
+) -> Result<usize, serde_json::Error>
+where
+    R: Read,
+    TEventArgs: DeserializeOwned,
+    TStorage: Storage,
+{
+    let mut count = 0;
+    for arg in serde_json::Deserializer::from_reader(reader).into_iter() {
+        event.invoke(&arg?);
+        count += 1;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::io;
+
+    use super::*;
+
+    #[test]
+    fn test_record_and_replay() {
+        let recorder = EventRecorder::new(Vec::new());
+        let first = Event::<(String, u32)>::new();
+        let second = Event::<(String, u32)>::new();
+        recorder.record(&first);
+        recorder.record(&second);
+
+        first.invoke(&("first".into(), 1));
+        second.invoke(&("second".into(), 2));
+        assert!(recorder.take_error().is_none());
+
+        let log = RefCell::new(vec![]);
+        let replayed = Event::new();
+        replayed.add(|arg: &(String, u32)| log.borrow_mut().push(arg.clone()));
+
+        let trace = recorder.writer().clone();
+        assert_eq!(replay(trace.as_slice(), &replayed).unwrap(), 2);
+        assert_eq!(
+            *log.borrow(),
+            vec![("first".to_string(), 1), ("second".to_string(), 2)]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        struct Broken;
+
+        impl Write for Broken {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::ErrorKind::BrokenPipe.into())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let called = RefCell::new(false);
+        let recorder = EventRecorder::new(Broken);
+        let event = Event::new();
+        recorder.record(&event);
+        event.add(|_: &u32| *called.borrow_mut() = true);
+
+        event.invoke(&1);
+        assert!(*called.borrow());
+        assert!(recorder.take_error().unwrap().is_io());
+        assert!(recorder.take_error().is_none());
+
+        let count = RefCell::new(0);
+        let event = Event::new();
+        event.add(|_: &u32| *count.borrow_mut() += 1);
+        assert!(replay("1\n2\nnope\n3\n".as_bytes(), &event).is_err());
+        assert_eq!(*count.borrow(), 2);
+    }
+}
@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::HandlerId;
+use crate::id;
+use crate::store::{DefaultStorage, HandlerStore, Storage};
+
+/// Handler storage for the events that only need add, remove and invoke.
+///
+/// Handlers live in the default store, while `order` lists their keys in insertion order,
+/// so a handler that reuses the slot of a removed one still runs after the
+/// handlers that were added before it.
+pub(crate) struct Registry<TEventArgs, THandler> {
+    slots: <DefaultStorage as Storage>::Store<(u64, THandler)>,
+    order: Vec<usize>,
+    marker: PhantomData<fn(&TEventArgs)>,
+}
+
+impl<TEventArgs, THandler: Clone> Clone for Registry<TEventArgs, THandler> {
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            order: self.order.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<TEventArgs, THandler> Registry<TEventArgs, THandler> {
+    pub(crate) fn new() -> Self {
+        Self {
+            slots: HandlerStore::with_capacity(0),
+            order: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    pub(crate) fn insert(&mut self, handler: THandler) -> HandlerId<TEventArgs> {
+        let generation = id::next_generation();
+        let key = self.slots.insert((generation, handler));
+        self.order.push(key);
+        HandlerId::new(key, generation)
+    }
+
+    pub(crate) fn remove(&mut self, id: HandlerId<TEventArgs>) -> bool {
+        let current = self
+            .slots
+            .get(id.key())
+            .is_some_and(|&(generation, _)| generation == id.generation());
+        if current {
+            self.slots.remove(id.key());
+            self.order.retain(|&key| key != id.key());
+        }
+        current
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.slots.clear();
+        self.order.clear();
+    }
+
+    /// Returns the handlers in insertion order.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &THandler> {
+        self.order
+            .iter()
+            .filter_map(|&key| self.slots.get(key).map(|(_, handler)| handler))
+    }
+}
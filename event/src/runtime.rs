@@ -0,0 +1,156 @@
+use std::future::Future;
+use std::panic;
+
+use tokio::runtime::Handle;
+
+use crate::{Event, HandlerId};
+
+impl<'a, TEventArgs: Clone + Send + 'static> Event<'a, TEventArgs> {
+    /// Adds an async event handler that is spawned onto a Tokio runtime each time
+    /// the event is invoked, without waiting for it to complete.
+    ///
+    /// This lets synchronous code notify async subscribers with a plain
+    /// [`invoke`](Self::invoke): the handler receives a clone of the event
+    /// arguments, and its future runs as a task of `runtime`. Like any detached
+    /// task, a panic in the future does not reach the invoker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let runtime = tokio::runtime::Runtime::new().unwrap();
+    /// let (sender, receiver) = std::sync::mpsc::channel();
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.add_spawned(runtime.handle().clone(), move |args| {
+    ///     let sender = sender.clone();
+    ///     async move { sender.send(args).unwrap() }
+    /// });
+    ///
+    /// event.invoke(&1); // Returns without waiting for the task
+    /// assert_eq!(receiver.recv(), Ok(1));
+    /// ```
+    pub fn add_spawned<F, Fut>(&self, runtime: Handle, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(TEventArgs) -> Fut + 'a,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.add(move |arg| {
+            runtime.spawn(handler(arg.clone()));
+        })
+    }
+
+    /// Adds an async event handler that is spawned onto a Tokio runtime each time
+    /// the event is invoked, blocking the invoker until the task completes.
+    ///
+    /// Unlike [`add_spawned`](Self::add_spawned), the invocation returns only
+    /// once the handler is done, and a panic in its future is resumed on the
+    /// invoking thread, as if a synchronous handler had panicked.
+    ///
+    /// # Panics
+    ///
+    /// Invoking the event panics if it happens within an asynchronous execution
+    /// context, see [`Handle::block_on`]. The task must be driven by the runtime
+    /// while the invoker blocks, so a current-thread runtime must be running on
+    /// another thread, otherwise the invocation never returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let runtime = tokio::runtime::Runtime::new().unwrap();
+    /// let saved = Arc::new(Mutex::new(vec![]));
+    ///
+    /// let event = Event::<u32>::new();
+    /// let log = Arc::clone(&saved);
+    /// event.add_spawned_joined(runtime.handle().clone(), move |args| {
+    ///     let log = Arc::clone(&log);
+    ///     async move { log.lock().unwrap().push(args) }
+    /// });
+    ///
+    /// event.invoke(&1); // Waits for the task
+    /// assert_eq!(*saved.lock().unwrap(), vec![1]);
+    /// ```
+    pub fn add_spawned_joined<F, Fut>(&self, runtime: Handle, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(TEventArgs) -> Fut + 'a,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.add(move |arg| {
+            let task = runtime.spawn(handler(arg.clone()));
+            if let Err(error) = runtime.block_on(task)
+                && error.is_panic()
+            {
+                panic::resume_unwind(error.into_panic());
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, mpsc};
+    use std::time::Duration;
+
+    use super::*;
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_time()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_add_spawned() {
+        let runtime = runtime();
+        let (sender, receiver) = mpsc::channel();
+        let event = Event::new();
+        let handle = event.add_spawned(runtime.handle().clone(), move |arg: i32| {
+            let sender = sender.clone();
+            async move { sender.send(arg * 10).unwrap() }
+        });
+
+        event.invoke(&1);
+        event.invoke(&2);
+        let mut received = vec![
+            receiver.recv_timeout(Duration::from_secs(5)).unwrap(),
+            receiver.recv_timeout(Duration::from_secs(5)).unwrap(),
+        ];
+        received.sort();
+        assert_eq!(received, vec![10, 20]);
+
+        assert!(event.remove(handle));
+        event.invoke(&3);
+        assert!(receiver.recv_timeout(Duration::from_millis(50)).is_err());
+    }
+
+    #[test]
+    fn test_add_spawned_joined() {
+        let runtime = runtime();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let event = Event::new();
+        let counter = Arc::clone(&calls);
+        event.add_spawned_joined(runtime.handle().clone(), move |arg: usize| {
+            let counter = Arc::clone(&counter);
+            async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                assert!(arg > 0, "zero");
+                counter.fetch_add(arg, Ordering::SeqCst);
+            }
+        });
+
+        event.invoke(&1);
+        event.invoke(&2);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        let panic = panic::catch_unwind(panic::AssertUnwindSafe(|| event.invoke(&0))).unwrap_err();
+        assert_eq!(panic.downcast_ref::<&str>(), Some(&"zero"));
+    }
+}
@@ -0,0 +1,240 @@
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+use crate::HandlerId;
+use crate::registry::Registry;
+
+/// Type alias for event handlers that receive the sender of the event.
+///
+/// Each handler is a shared function that takes a reference to the object that
+/// raised the event and a reference to the event arguments.
+pub type SenderEventHandler<'a, TSender, TEventArgs> = Rc<dyn Fn(&TSender, &TEventArgs) + 'a>;
+
+/// An event whose handlers receive the object that raised it, like C#'s
+/// `EventHandler(object sender, EventArgs e)`.
+///
+/// This lets one handler be attached to the events of many sources and still tell
+/// them apart, without repeating the sender in every event arguments type. The
+/// sender may be unsized, e.g. `dyn Widget`.
+///
+/// Handlers run in the order they were added. They may add or remove handlers
+/// while the event is being invoked, which takes effect from the next invocation.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::SenderEvent;
+///
+/// struct Button {
+///     name: &'static str,
+/// }
+///
+/// let clicked = SenderEvent::<Button, (i32, i32)>::new();
+/// clicked.add(|button, (x, y)| println!("{} clicked at ({}, {})", button.name, x, y));
+///
+/// let ok = Button { name: "OK" };
+/// clicked.invoke(&ok, &(1, 2)); // Prints "OK clicked at (1, 2)"
+/// ```
+pub struct SenderEvent<'a, TSender: ?Sized, TEventArgs> {
+    handlers: RefCell<Registry<TEventArgs, SenderEventHandler<'a, TSender, TEventArgs>>>,
+}
+
+impl<'a, TSender: ?Sized, TEventArgs> Default for SenderEvent<'a, TSender, TEventArgs> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, TSender: ?Sized, TEventArgs> SenderEvent<'a, TSender, TEventArgs> {
+    /// Creates a new, empty SenderEvent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SenderEvent;
+    ///
+    /// let event: SenderEvent<str, ()> = SenderEvent::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            handlers: Registry::new().into(),
+        }
+    }
+
+    /// Adds an event handler to the event.
+    ///
+    /// The handler should be a closure that accepts references to the sender and
+    /// to the event arguments. The closure will be executed when the event is invoked.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SenderEvent;
+    ///
+    /// let event = SenderEvent::<str, u32>::new();
+    /// let handle = event.add(|sender, args| {
+    ///     println!("{} raised the event with {}", sender, args);
+    /// });
+    /// ```
+    pub fn add<F>(&self, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TSender, &TEventArgs) + 'a,
+    {
+        self.handlers.borrow_mut().insert(Rc::new(handler))
+    }
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SenderEvent;
+    ///
+    /// let event = SenderEvent::<str, u32>::new();
+    /// let handle = event.add(|sender, args| {
+    ///     println!("{} raised the event with {}", sender, args);
+    /// });
+    ///
+    /// assert!(event.remove(handle));
+    /// assert!(!event.remove(handle)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        self.handlers.borrow_mut().remove(handle)
+    }
+
+    /// Returns the number of event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SenderEvent;
+    ///
+    /// let event = SenderEvent::<str, u32>::new();
+    /// event.add(|sender, args| println!("Handler 1"));
+    ///
+    /// assert_eq!(event.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.handlers.borrow().len()
+    }
+
+    /// Returns `true` if the event has no handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SenderEvent;
+    ///
+    /// let event: SenderEvent<str, ()> = SenderEvent::new();
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SenderEvent;
+    ///
+    /// let event = SenderEvent::<str, u32>::new();
+    /// event.add(|sender, args| { println!("Handler 1"); });
+    /// event.add(|sender, args| { println!("Handler 2"); });
+    ///
+    /// event.clear(); // Remove all handlers
+    /// ```
+    pub fn clear(&self) {
+        self.handlers.borrow_mut().clear();
+    }
+
+    /// Invokes all event handlers sequentially (one after another), on behalf
+    /// of `sender`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SenderEvent;
+    ///
+    /// let event = SenderEvent::<str, ()>::new();
+    /// event.add(|sender, args| { println!("Handler 1, sent by {}", sender); });
+    /// event.add(|sender, args| { println!("Handler 2, sent by {}", sender); });
+    ///
+    /// event.invoke("window", &()); // Execute all handlers in order
+    /// ```
+    pub fn invoke(&self, sender: &TSender, arg: &TEventArgs) {
+        let handlers: Vec<_> = self.handlers.borrow().iter().cloned().collect();
+        for handler in handlers {
+            handler(sender, arg);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    struct Slider {
+        name: &'static str,
+        value_changed: SenderEvent<'static, Slider, u32>,
+    }
+
+    #[test]
+    fn test_shared_handler_sees_sender() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let sliders = [
+            Slider {
+                name: "volume",
+                value_changed: SenderEvent::new(),
+            },
+            Slider {
+                name: "balance",
+                value_changed: SenderEvent::new(),
+            },
+        ];
+
+        for slider in &sliders {
+            let log = Rc::clone(&log);
+            slider
+                .value_changed
+                .add(move |sender, value| log.borrow_mut().push((sender.name, *value)));
+        }
+        for (value, slider) in sliders.iter().enumerate() {
+            slider.value_changed.invoke(slider, &(value as u32));
+        }
+
+        assert_eq!(*log.borrow(), vec![("volume", 0), ("balance", 1)]);
+    }
+
+    #[test]
+    fn test_remove_during_invoke() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let event = Rc::new(SenderEvent::<str, ()>::new());
+        let handle = Rc::new(RefCell::new(None));
+
+        let weak = Rc::downgrade(&event);
+        let (log_clone, handle_clone) = (Rc::clone(&log), Rc::clone(&handle));
+        event.add(move |sender, _| {
+            log_clone.borrow_mut().push(sender.to_string());
+            if let Some(handle) = handle_clone.borrow_mut().take() {
+                weak.upgrade().unwrap().remove(handle);
+            }
+        });
+        let log_clone = Rc::clone(&log);
+        *handle.borrow_mut() =
+            Some(event.add(move |_, _| log_clone.borrow_mut().push("second".to_string())));
+
+        event.invoke("first", &());
+        event.invoke("again", &());
+        assert_eq!(*log.borrow(), vec!["first", "second", "again"]);
+    }
+}
@@ -0,0 +1,236 @@
+use alloc::rc::Rc;
+#[cfg(feature = "std")]
+use alloc::sync::Arc;
+use core::ops::Deref;
+
+use crate::Event;
+#[cfg(feature = "std")]
+use crate::SyncEvent;
+
+/// A cloneable handle to an [`Event`].
+///
+/// All clones refer to the same event, so the owner of an event can hand out
+/// clones to subscribers and still invoke it. The handle dereferences to the
+/// event, whose methods all take `&self`.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::SharedEvent;
+///
+/// let event = SharedEvent::<u32>::new();
+/// let subscriber = event.clone();
+/// subscriber.add(|args| println!("Event invoked with {}", args));
+///
+/// event.invoke(&1); // Prints "Event invoked with 1"
+/// ```
+pub struct SharedEvent<'a, TEventArgs, TResult = ()> {
+    event: Rc<Event<'a, TEventArgs, TResult>>,
+}
+
+impl<'a, TEventArgs, TResult> SharedEvent<'a, TEventArgs, TResult> {
+    /// Creates a new, empty SharedEvent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SharedEvent;
+    ///
+    /// let event: SharedEvent<()> = SharedEvent::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::from(Event::new())
+    }
+
+    /// Returns `true` if both handles refer to the same event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SharedEvent;
+    ///
+    /// let event: SharedEvent<()> = SharedEvent::new();
+    /// assert!(SharedEvent::ptr_eq(&event, &event.clone()));
+    /// assert!(!SharedEvent::ptr_eq(&event, &SharedEvent::new()));
+    /// ```
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Rc::ptr_eq(&this.event, &other.event)
+    }
+}
+
+impl<'a, TEventArgs, TResult> Default for SharedEvent<'a, TEventArgs, TResult> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, TEventArgs, TResult> Clone for SharedEvent<'a, TEventArgs, TResult> {
+    fn clone(&self) -> Self {
+        Self {
+            event: Rc::clone(&self.event),
+        }
+    }
+}
+
+impl<'a, TEventArgs, TResult> From<Event<'a, TEventArgs, TResult>>
+    for SharedEvent<'a, TEventArgs, TResult>
+{
+    fn from(event: Event<'a, TEventArgs, TResult>) -> Self {
+        Self {
+            event: Rc::new(event),
+        }
+    }
+}
+
+impl<'a, TEventArgs, TResult> Deref for SharedEvent<'a, TEventArgs, TResult> {
+    type Target = Event<'a, TEventArgs, TResult>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.event
+    }
+}
+
+/// A cloneable handle to a [`SyncEvent`], which can be sent to other threads.
+///
+/// This is the thread-safe counterpart of [`SharedEvent`].
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+///
+/// use event_rs::SharedSyncEvent;
+///
+/// let event = SharedSyncEvent::<u32>::new();
+/// let subscriber = event.clone();
+/// thread::spawn(move || {
+///     subscriber.add(|args| println!("Event invoked with {}", args));
+/// })
+/// .join()
+/// .unwrap();
+///
+/// event.invoke(&1); // Prints "Event invoked with 1"
+/// ```
+#[cfg(feature = "std")]
+pub struct SharedSyncEvent<'a, TEventArgs> {
+    event: Arc<SyncEvent<'a, TEventArgs>>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, TEventArgs> SharedSyncEvent<'a, TEventArgs> {
+    /// Creates a new, empty SharedSyncEvent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SharedSyncEvent;
+    ///
+    /// let event: SharedSyncEvent<()> = SharedSyncEvent::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::from(SyncEvent::new())
+    }
+
+    /// Returns `true` if both handles refer to the same event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SharedSyncEvent;
+    ///
+    /// let event: SharedSyncEvent<()> = SharedSyncEvent::new();
+    /// assert!(SharedSyncEvent::ptr_eq(&event, &event.clone()));
+    /// assert!(!SharedSyncEvent::ptr_eq(&event, &SharedSyncEvent::new()));
+    /// ```
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.event, &other.event)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, TEventArgs> Default for SharedSyncEvent<'a, TEventArgs> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, TEventArgs> Clone for SharedSyncEvent<'a, TEventArgs> {
+    fn clone(&self) -> Self {
+        Self {
+            event: Arc::clone(&self.event),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, TEventArgs> From<SyncEvent<'a, TEventArgs>> for SharedSyncEvent<'a, TEventArgs> {
+    fn from(event: SyncEvent<'a, TEventArgs>) -> Self {
+        Self {
+            event: Arc::new(event),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, TEventArgs> Deref for SharedSyncEvent<'a, TEventArgs> {
+    type Target = SyncEvent<'a, TEventArgs>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    struct Button<'a> {
+        clicked: SharedEvent<'a, u32>,
+    }
+
+    #[test]
+    fn test_clones_share_handlers() {
+        let log = RefCell::new(vec![]);
+        let button = Button {
+            clicked: SharedEvent::new(),
+        };
+
+        let subscriber = button.clicked.clone();
+        let handle = subscriber.add(|arg| log.borrow_mut().push(*arg));
+        button.clicked.invoke(&1);
+        assert!(button.clicked.remove(handle));
+        subscriber.invoke(&2);
+
+        assert_eq!(*log.borrow(), vec![1]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_shared_sync_event() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::thread;
+
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let event = SharedSyncEvent::<usize>::new();
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let event = event.clone();
+                thread::spawn(move || {
+                    event.add(|arg| {
+                        COUNTER.fetch_add(*arg, Ordering::SeqCst);
+                    });
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        event.invoke(&1);
+        assert_eq!(COUNTER.load(Ordering::SeqCst), 4);
+    }
+}
@@ -0,0 +1,155 @@
+use core::convert::Infallible;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures::Sink;
+
+use crate::Event;
+#[cfg(feature = "std")]
+use crate::SyncEvent;
+
+/// A sink that invokes an event with each item sent to it, see [`Event::sink`].
+#[must_use = "sinks do nothing unless items are sent to them"]
+pub struct EventSink<'e, 'a, TEventArgs> {
+    event: &'e Event<'a, TEventArgs>,
+}
+
+impl<TEventArgs> Sink<TEventArgs> for EventSink<'_, '_, TEventArgs> {
+    type Error = Infallible;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: TEventArgs) -> Result<(), Self::Error> {
+        self.event.invoke(&item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<'a, TEventArgs> Event<'a, TEventArgs> {
+    /// Returns a sink that invokes the event with each item sent to it.
+    ///
+    /// Since an `Event` cannot leave its thread, neither can the sink, and each
+    /// item is raised synchronously on the owner's thread as it is sent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::{SinkExt, stream};
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// event.add(|value| println!("Received {}", value));
+    ///
+    /// # futures::executor::block_on(async {
+    /// let mut values = stream::iter([1, 2, 3].map(Ok));
+    /// event.sink().send_all(&mut values).await.unwrap(); // Prints 1, 2 and 3
+    /// # });
+    /// ```
+    pub fn sink(&self) -> EventSink<'_, 'a, TEventArgs> {
+        EventSink { event: self }
+    }
+}
+
+#[cfg(feature = "std")]
+/// A sink that invokes a [`SyncEvent`] with each item sent to it, see [`SyncEvent::sink`].
+#[must_use = "sinks do nothing unless items are sent to them"]
+pub struct SyncEventSink<'e, 'a, TEventArgs> {
+    event: &'e SyncEvent<'a, TEventArgs>,
+}
+
+#[cfg(feature = "std")]
+impl<TEventArgs> Sink<TEventArgs> for SyncEventSink<'_, '_, TEventArgs> {
+    type Error = Infallible;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: TEventArgs) -> Result<(), Self::Error> {
+        self.event.invoke(&item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, TEventArgs> SyncEvent<'a, TEventArgs> {
+    /// Returns a sink that invokes the event with each item sent to it.
+    ///
+    /// Each item is raised synchronously on the thread that sends it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::SinkExt;
+    ///
+    /// use event_rs::SyncEvent;
+    ///
+    /// let event = SyncEvent::<u32>::new();
+    /// event.add(|value| println!("Received {}", value));
+    ///
+    /// # futures::executor::block_on(async {
+    /// event.sink().send(1).await.unwrap(); // Prints "Received 1"
+    /// # });
+    /// ```
+    pub fn sink(&self) -> SyncEventSink<'_, 'a, TEventArgs> {
+        SyncEventSink { event: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    #[cfg(feature = "std")]
+    use std::sync::Mutex;
+
+    use futures::executor::block_on;
+    use futures::{SinkExt, StreamExt, stream};
+
+    use super::*;
+
+    #[test]
+    fn test_event_sink() {
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+        event.add(|value: &i32| log.borrow_mut().push(*value));
+
+        block_on(async {
+            let mut sink = event.sink();
+            sink.send(1).await.unwrap();
+            sink.send_all(&mut stream::iter([2, 3]).map(Ok))
+                .await
+                .unwrap();
+        });
+        assert_eq!(*log.borrow(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sync_event_sink() {
+        let log = Mutex::new(vec![]);
+        let event = SyncEvent::new();
+        event.add(|value: &i32| log.lock().unwrap().push(*value));
+
+        block_on(event.sink().send_all(&mut stream::iter([1, 2]).map(Ok))).unwrap();
+        assert_eq!(*log.lock().unwrap(), vec![1, 2]);
+    }
+}
@@ -0,0 +1,246 @@
+use alloc::rc::Rc;
+use core::ops::Deref;
+
+use crate::{DefaultStorage, Event, HandlerId, Storage};
+
+/// The publishing half of a split [`Event`], see [`Event::split`].
+///
+/// The source dereferences to the event, so its owner keeps the whole API,
+/// including [`invoke`](Event::invoke) and [`clear`](Event::clear).
+pub struct EventSource<'a, TEventArgs, TResult = (), TStorage: Storage = DefaultStorage> {
+    event: Rc<Event<'a, TEventArgs, TResult, TStorage>>,
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> EventSource<'a, TEventArgs, TResult, TStorage> {
+    /// Returns another subscribing half of the event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let (source, _) = Event::<u32>::new().split();
+    /// let handle = source.handle();
+    /// handle.add(|args| println!("Event invoked with {}", args));
+    ///
+    /// assert_eq!(source.len(), 1);
+    /// ```
+    pub fn handle(&self) -> EventHandle<'a, TEventArgs, TResult, TStorage> {
+        EventHandle {
+            event: Rc::clone(&self.event),
+        }
+    }
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> Deref
+    for EventSource<'a, TEventArgs, TResult, TStorage>
+{
+    type Target = Event<'a, TEventArgs, TResult, TStorage>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.event
+    }
+}
+
+/// The subscribing half of a split [`Event`], see [`Event::split`].
+///
+/// A handle can only add and remove handlers, so it can be handed out publicly
+/// while only the [`EventSource`] can raise the event. All clones refer to the
+/// same event.
+pub struct EventHandle<'a, TEventArgs, TResult = (), TStorage: Storage = DefaultStorage> {
+    event: Rc<Event<'a, TEventArgs, TResult, TStorage>>,
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> EventHandle<'a, TEventArgs, TResult, TStorage> {
+    /// Adds an event handler to the event.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let (source, handle) = Event::<u32>::new().split();
+    /// handle.add(|args| println!("Event invoked with {}", args));
+    ///
+    /// source.invoke(&1); // Prints "Event invoked with 1"
+    /// ```
+    pub fn add<F>(&self, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        self.event.add(handler)
+    }
+
+    /// Adds an event handler with the given priority, see
+    /// [`Event::add_with_priority`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let (source, handle) = Event::<()>::new().split();
+    /// handle.add(|args| println!("Runs second"));
+    /// handle.add_with_priority(10, |args| println!("Runs first"));
+    ///
+    /// source.invoke(&());
+    /// ```
+    pub fn add_with_priority<F>(&self, priority: i32, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        self.event.add_with_priority(priority, handler)
+    }
+
+    /// Adds an event handler that is removed after its first invocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let (source, handle) = Event::<()>::new().split();
+    /// handle.once(|args| println!("Only once"));
+    ///
+    /// source.invoke(&()); // Prints "Only once"
+    /// source.invoke(&()); // Prints nothing
+    /// ```
+    pub fn once<F>(&self, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: FnOnce(&TEventArgs) -> TResult + 'a,
+    {
+        self.event.once(handler)
+    }
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let (_, handle) = Event::<u32>::new().split();
+    /// let id = handle.add(|args| println!("Event invoked with {}", args));
+    ///
+    /// assert!(handle.remove(id));
+    /// assert!(!handle.remove(id)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        self.event.remove(handle)
+    }
+
+    /// Returns the number of event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let (_, handle) = Event::<u32>::new().split();
+    /// handle.add(|args| println!("Handler 1"));
+    ///
+    /// assert_eq!(handle.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.event.len()
+    }
+
+    /// Returns `true` if the event has no handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let (_, handle) = Event::<()>::new().split();
+    /// assert!(handle.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.event.is_empty()
+    }
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> Clone
+    for EventHandle<'a, TEventArgs, TResult, TStorage>
+{
+    fn clone(&self) -> Self {
+        Self {
+            event: Rc::clone(&self.event),
+        }
+    }
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> Event<'a, TEventArgs, TResult, TStorage> {
+    /// Splits the event into a source that can invoke it and a handle that can
+    /// only add and remove handlers.
+    ///
+    /// Exposing an event publicly also exposes [`invoke`](Self::invoke) and
+    /// [`clear`](Self::clear). Keeping the source private and exposing the handle
+    /// instead ensures that only the declaring type can raise the event, as in C#.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::{Event, EventHandle, EventSource};
+    ///
+    /// struct Button {
+    ///     clicked: EventSource<'static, ()>,
+    ///     pub on_clicked: EventHandle<'static, ()>,
+    /// }
+    ///
+    /// impl Button {
+    ///     fn new() -> Self {
+    ///         let (clicked, on_clicked) = Event::new().split();
+    ///         Self { clicked, on_clicked }
+    ///     }
+    ///
+    ///     fn click(&self) {
+    ///         self.clicked.invoke(&());
+    ///     }
+    /// }
+    ///
+    /// let button = Button::new();
+    /// button.on_clicked.add(|_| println!("Clicked"));
+    /// button.click(); // Prints "Clicked"
+    /// ```
+    pub fn split(
+        self,
+    ) -> (
+        EventSource<'a, TEventArgs, TResult, TStorage>,
+        EventHandle<'a, TEventArgs, TResult, TStorage>,
+    ) {
+        let source = EventSource {
+            event: Rc::new(self),
+        };
+        let handle = source.handle();
+        (source, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        let log = RefCell::new(vec![]);
+        let (source, handle) = Event::<u32>::new().split();
+
+        let subscriber = handle.clone();
+        let first = subscriber.add(|arg| log.borrow_mut().push(*arg));
+        handle.once(|arg| log.borrow_mut().push(arg * 10));
+        assert_eq!(source.len(), 2);
+
+        source.invoke(&1);
+        assert!(handle.remove(first));
+        source.invoke(&2);
+        assert!(subscriber.is_empty());
+        assert_eq!(*log.borrow(), vec![1, 10]);
+    }
+}
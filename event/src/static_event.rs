@@ -0,0 +1,292 @@
+use core::cell::Cell;
+use core::fmt;
+
+use crate::HandlerId;
+use crate::id::next_generation;
+
+/// A handler stored in a [`StaticEvent`], along with its unique number.
+type Slot<'a, TEventArgs> = Option<(u64, &'a dyn Fn(&TEventArgs))>;
+
+/// The error returned by [`StaticEvent::try_add`] when the event is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError;
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the event has no room for another handler")
+    }
+}
+
+impl core::error::Error for CapacityError {}
+
+/// An event that stores up to `N` handlers inline, without allocating.
+///
+/// Handlers are borrowed rather than boxed, so they can be functions or closures
+/// that live at least as long as the event, and the event never touches the heap.
+/// This suits embedded targets without an allocator, at the cost of a fixed
+/// capacity: [`try_add`](Self::try_add) fails once `N` handlers are attached.
+///
+/// Handlers run in the order they were added, and may add or remove handlers
+/// while the event is being invoked, with the same effect as for [`Event`](crate::Event).
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::StaticEvent;
+///
+/// fn on_tick(tick: &u32) {
+///     println!("Tick {}", tick);
+/// }
+///
+/// let event = StaticEvent::<u32, 2>::new();
+/// event.try_add(&on_tick).unwrap();
+/// event.try_add(&on_tick).unwrap();
+/// assert!(event.try_add(&on_tick).is_err()); // Full
+///
+/// event.invoke(&1); // Prints "Tick 1" twice
+/// ```
+pub struct StaticEvent<'a, TEventArgs, const N: usize> {
+    slots: [Cell<Slot<'a, TEventArgs>>; N],
+}
+
+impl<'a, TEventArgs, const N: usize> Default for StaticEvent<'a, TEventArgs, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, TEventArgs, const N: usize> StaticEvent<'a, TEventArgs, N> {
+    /// Creates a new, empty StaticEvent with room for `N` handlers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StaticEvent;
+    ///
+    /// let event: StaticEvent<(), 4> = StaticEvent::new();
+    /// assert_eq!(event.capacity(), 4);
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            slots: [const { Cell::new(None) }; N],
+        }
+    }
+
+    /// Adds an event handler to the event.
+    ///
+    /// Returns a handle that can be used to remove the handler later, or
+    /// [`CapacityError`] if the event already has `N` handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StaticEvent;
+    ///
+    /// let handler = |args: &u32| println!("Event invoked with {}", args);
+    /// let event = StaticEvent::<u32, 1>::new();
+    ///
+    /// assert!(event.try_add(&handler).is_ok());
+    /// assert!(event.try_add(&handler).is_err());
+    /// ```
+    pub fn try_add(
+        &self,
+        handler: &'a dyn Fn(&TEventArgs),
+    ) -> Result<HandlerId<TEventArgs>, CapacityError> {
+        let key = self
+            .slots
+            .iter()
+            .position(|slot| slot.get().is_none())
+            .ok_or(CapacityError)?;
+        let generation = next_generation();
+        self.slots[key].set(Some((generation, handler)));
+        Ok(HandlerId::new(key, generation))
+    }
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StaticEvent;
+    ///
+    /// let handler = |args: &u32| println!("Event invoked with {}", args);
+    /// let event = StaticEvent::<u32, 1>::new();
+    /// let handle = event.try_add(&handler).unwrap();
+    ///
+    /// assert!(event.remove(handle));
+    /// assert!(!event.remove(handle)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        let Some(slot) = self.slots.get(handle.key()) else {
+            return false;
+        };
+        match slot.get() {
+            Some((generation, _)) if generation == handle.generation() => {
+                slot.set(None);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the number of event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StaticEvent;
+    ///
+    /// let handler = |args: &u32| println!("Handler 1");
+    /// let event = StaticEvent::<u32, 4>::new();
+    /// event.try_add(&handler).unwrap();
+    ///
+    /// assert_eq!(event.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| slot.get().is_some())
+            .count()
+    }
+
+    /// Returns `true` if the event has no handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StaticEvent;
+    ///
+    /// let event: StaticEvent<(), 4> = StaticEvent::new();
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of handlers the event can hold, which is `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StaticEvent;
+    ///
+    /// let event: StaticEvent<(), 8> = StaticEvent::new();
+    /// assert_eq!(event.capacity(), 8);
+    /// ```
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Removes all event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StaticEvent;
+    ///
+    /// let handler = |args: &u32| println!("Handler 1");
+    /// let event = StaticEvent::<u32, 4>::new();
+    /// event.try_add(&handler).unwrap();
+    ///
+    /// event.clear(); // Remove all handlers
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn clear(&self) {
+        for slot in &self.slots {
+            slot.set(None);
+        }
+    }
+
+    /// Invokes all event handlers sequentially (one after another).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StaticEvent;
+    ///
+    /// let first = |args: &()| println!("Handler 1");
+    /// let second = |args: &()| println!("Handler 2");
+    /// let event = StaticEvent::<(), 2>::new();
+    /// event.try_add(&first).unwrap();
+    /// event.try_add(&second).unwrap();
+    ///
+    /// event.invoke(&()); // Execute all handlers in order
+    /// ```
+    pub fn invoke(&self, arg: &TEventArgs) {
+        // Copy the handlers so that handlers added during the invocation wait for
+        // the next one, and sort them back into insertion order, since removals let
+        // later handlers take earlier slots.
+        let mut snapshot: [(usize, Slot<'a, TEventArgs>); N] =
+            core::array::from_fn(|key| (key, self.slots[key].get()));
+        snapshot.sort_unstable_by_key(|(_, slot)| slot.map(|(generation, _)| generation));
+
+        for (key, slot) in snapshot {
+            let Some((generation, handler)) = slot else {
+                continue;
+            };
+            if matches!(self.slots[key].get(), Some((current, _)) if current == generation) {
+                handler(arg);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_capacity() {
+        let handler = |_: &()| {};
+        let event = StaticEvent::<(), 2>::new();
+
+        let first = event.try_add(&handler).unwrap();
+        event.try_add(&handler).unwrap();
+        assert_eq!(event.try_add(&handler), Err(CapacityError));
+        assert_eq!(event.len(), 2);
+
+        assert!(event.remove(first));
+        assert!(!event.remove(first));
+        assert!(event.try_add(&handler).is_ok());
+    }
+
+    #[test]
+    fn test_insertion_order_after_reuse() {
+        let log = RefCell::new(vec![]);
+        let first = |_: &()| log.borrow_mut().push(1);
+        let second = |_: &()| log.borrow_mut().push(2);
+        let third = |_: &()| log.borrow_mut().push(3);
+        let event = StaticEvent::<(), 3>::new();
+
+        let handle = event.try_add(&first).unwrap();
+        event.try_add(&second).unwrap();
+        event.remove(handle);
+        event.try_add(&third).unwrap(); // Reuses the first slot
+
+        event.invoke(&());
+        assert_eq!(*log.borrow(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_remove_during_invoke() {
+        let log = RefCell::new(vec![]);
+        let event = StaticEvent::<(), 2>::new();
+        let handle = std::cell::Cell::new(None);
+        let second = |_: &()| log.borrow_mut().push(2);
+        let first = |_: &()| {
+            log.borrow_mut().push(1);
+            event.remove(handle.get().unwrap());
+        };
+
+        event.try_add(&first).unwrap();
+        handle.set(Some(event.try_add(&second).unwrap()));
+
+        event.invoke(&());
+        event.invoke(&());
+        assert_eq!(*log.borrow(), vec![1, 1]);
+    }
+}
@@ -0,0 +1,215 @@
+use core::cell::RefCell;
+
+use crate::{Event, HandlerId};
+
+/// An event that remembers its latest invocation and replays it to new handlers.
+///
+/// Handlers added after the event has been invoked are immediately called with
+/// the most recent event arguments, so late subscribers always see the current
+/// state. This suits state-style events such as "configuration changed".
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::StickyEvent;
+///
+/// let event = StickyEvent::<&str>::new();
+/// event.invoke(&"dark");
+///
+/// event.add(|theme| {
+///     println!("Theme is now {}", theme); // Called at once with "dark"
+/// });
+///
+/// event.invoke(&"light"); // Prints "Theme is now light"
+/// ```
+pub struct StickyEvent<'a, TEventArgs> {
+    event: Event<'a, TEventArgs>,
+    latest: RefCell<Option<TEventArgs>>,
+}
+
+impl<'a, TEventArgs: Clone> Default for StickyEvent<'a, TEventArgs> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, TEventArgs: Clone> StickyEvent<'a, TEventArgs> {
+    /// Creates a new, empty StickyEvent that has not been invoked yet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StickyEvent;
+    ///
+    /// let event: StickyEvent<()> = StickyEvent::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            event: Event::new(),
+            latest: RefCell::new(None),
+        }
+    }
+
+    /// Adds an event handler to the event.
+    ///
+    /// If the event has been invoked before, the handler is called right away
+    /// with the latest event arguments.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StickyEvent;
+    ///
+    /// let event = StickyEvent::<u32>::new();
+    /// let handle = event.add(|args| {
+    ///     println!("Event invoked with {}", args);
+    /// });
+    /// ```
+    pub fn add<F>(&self, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) + 'a,
+    {
+        if let Some(arg) = self.latest() {
+            handler(&arg);
+        }
+        self.event.add(handler)
+    }
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StickyEvent;
+    ///
+    /// let event = StickyEvent::<u32>::new();
+    /// let handle = event.add(|args| {
+    ///     println!("Event invoked with {}", args);
+    /// });
+    ///
+    /// assert!(event.remove(handle));
+    /// assert!(!event.remove(handle)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        self.event.remove(handle)
+    }
+
+    /// Returns the number of event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StickyEvent;
+    ///
+    /// let event = StickyEvent::<u32>::new();
+    /// event.add(|args| println!("Handler 1"));
+    ///
+    /// assert_eq!(event.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.event.len()
+    }
+
+    /// Returns `true` if the event has no handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StickyEvent;
+    ///
+    /// let event: StickyEvent<()> = StickyEvent::new();
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.event.is_empty()
+    }
+
+    /// Removes all event handlers.
+    ///
+    /// The latest event arguments are kept and replayed to future handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StickyEvent;
+    ///
+    /// let event = StickyEvent::<u32>::new();
+    /// event.add(|args| { println!("Handler 1"); });
+    /// event.add(|args| { println!("Handler 2"); });
+    ///
+    /// event.clear(); // Remove all handlers
+    /// ```
+    pub fn clear(&self) {
+        self.event.clear();
+    }
+
+    /// Stores the event arguments as the latest value and invokes all event handlers
+    /// sequentially (one after another).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StickyEvent;
+    ///
+    /// let event = StickyEvent::new();
+    /// event.add(|args| { println!("Handler 1"); });
+    /// event.add(|args| { println!("Handler 2"); });
+    ///
+    /// event.invoke(&()); // Execute all handlers in order
+    /// ```
+    pub fn invoke(&self, arg: &TEventArgs) {
+        *self.latest.borrow_mut() = Some(arg.clone());
+        self.event.invoke(arg);
+    }
+
+    /// Returns the event arguments of the latest invocation, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::StickyEvent;
+    ///
+    /// let event = StickyEvent::new();
+    /// assert_eq!(event.latest(), None);
+    ///
+    /// event.invoke(&42);
+    /// assert_eq!(event.latest(), Some(42));
+    /// ```
+    pub fn latest(&self) -> Option<TEventArgs> {
+        self.latest.borrow().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_to_new_handlers() {
+        let log = RefCell::new(vec![]);
+        let event = StickyEvent::new();
+
+        event.add(|arg: &i32| log.borrow_mut().push(("early", *arg)));
+        assert!(log.borrow().is_empty());
+
+        event.invoke(&1);
+        event.invoke(&2);
+        event.add(|arg: &i32| log.borrow_mut().push(("late", *arg)));
+        event.invoke(&3);
+
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                ("early", 1),
+                ("early", 2),
+                ("late", 2),
+                ("early", 3),
+                ("late", 3)
+            ]
+        );
+    }
+}
@@ -0,0 +1,601 @@
+use alloc::vec::Vec;
+
+/// Keyed storage for event handlers, like a slab or an arena.
+///
+/// Events store each handler under the key returned by [`insert`](Self::insert),
+/// and keep track of the invocation order themselves, so a store only needs to
+/// map keys to values. A key must stay valid until its value is removed, and may
+/// be handed out again afterwards.
+///
+/// Implement this trait, together with [`Storage`], to keep handlers in a custom
+/// container.
+pub trait HandlerStore<T> {
+    /// Creates an empty store with room for at least `capacity` values.
+    fn with_capacity(capacity: usize) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the number of values the store can hold without reallocating.
+    fn capacity(&self) -> usize;
+
+    /// Reserves room for at least `additional` more values.
+    fn reserve(&mut self, additional: usize);
+
+    /// Shrinks the store as much as possible without invalidating any key.
+    fn shrink_to_fit(&mut self);
+
+    /// Stores a value and returns its key.
+    fn insert(&mut self, value: T) -> usize;
+
+    /// Removes and returns the value under `key`, if there is one.
+    fn remove(&mut self, key: usize) -> Option<T>;
+
+    /// Returns the value under `key`, if there is one.
+    fn get(&self, key: usize) -> Option<&T>;
+
+    /// Returns the value under `key` mutably, if there is one.
+    fn get_mut(&mut self, key: usize) -> Option<&mut T>;
+
+    /// Returns the number of stored values.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the store holds no values.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all values.
+    fn clear(&mut self);
+
+    /// Keeps only the values for which `f` returns `true`.
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool;
+
+    /// Returns the stored values and their keys, in no particular order.
+    fn iter<'s>(&'s self) -> impl Iterator<Item = (usize, &'s T)>
+    where
+        T: 's;
+}
+
+/// A kind of handler storage, selected with the last type parameter of
+/// [`Event`](crate::Event).
+///
+/// The combinators and the bridges to channels and streams are implemented for
+/// events with the [`DefaultStorage`].
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{Event, VecStorage};
+///
+/// let event = Event::<u32, (), VecStorage>::default();
+/// event.add(|args| println!("Event invoked with {}", args));
+/// event.invoke(&1);
+/// ```
+pub trait Storage {
+    /// The store that holds values of type `T`.
+    type Store<T>: HandlerStore<T>;
+}
+
+/// Stores handlers in a [`Slab`](slab::Slab).
+///
+/// This is the default storage when the `slab` feature is enabled.
+#[cfg(feature = "slab")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SlabStorage;
+
+#[cfg(feature = "slab")]
+impl Storage for SlabStorage {
+    type Store<T> = slab::Slab<T>;
+}
+
+#[cfg(feature = "slab")]
+impl<T> HandlerStore<T> for slab::Slab<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        slab::Slab::with_capacity(capacity)
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+
+    fn insert(&mut self, value: T) -> usize {
+        self.insert(value)
+    }
+
+    fn remove(&mut self, key: usize) -> Option<T> {
+        self.try_remove(key)
+    }
+
+    fn get(&self, key: usize) -> Option<&T> {
+        self.get(key)
+    }
+
+    fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        self.get_mut(key)
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.retain(|_, value| f(value));
+    }
+
+    fn iter<'s>(&'s self) -> impl Iterator<Item = (usize, &'s T)>
+    where
+        T: 's,
+    {
+        self.iter()
+    }
+}
+
+/// Stores handlers in a [`VecStore`], which needs no dependencies.
+///
+/// This is the default storage when the `slab` feature is disabled.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VecStorage;
+
+impl Storage for VecStorage {
+    type Store<T> = VecStore<T>;
+}
+
+/// The default storage: [`SlabStorage`] with the `slab` feature, and
+/// [`VecStorage`] otherwise.
+#[cfg(feature = "slab")]
+pub type DefaultStorage = SlabStorage;
+
+/// The default storage: [`SlabStorage`] with the `slab` feature, and
+/// [`VecStorage`] otherwise.
+#[cfg(not(feature = "slab"))]
+pub type DefaultStorage = VecStorage;
+
+/// A [`HandlerStore`] built on a `Vec`, which reuses the keys of removed values.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{HandlerStore, VecStore};
+///
+/// let mut store = VecStore::with_capacity(2);
+/// let key = store.insert("first");
+/// store.insert("second");
+///
+/// assert_eq!(store.remove(key), Some("first"));
+/// assert_eq!(store.insert("third"), key); // Reuses the key
+/// ```
+#[derive(Debug, Clone)]
+pub struct VecStore<T> {
+    entries: Vec<Option<T>>,
+    vacant: Vec<usize>,
+}
+
+impl<T> Default for VecStore<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> VecStore<T> {
+    /// Creates a new, empty VecStore
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::VecStore;
+    ///
+    /// let store: VecStore<u32> = VecStore::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            vacant: Vec::new(),
+        }
+    }
+}
+
+impl<T> HandlerStore<T> for VecStore<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+            vacant: Vec::new(),
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        let additional = additional.saturating_sub(self.vacant.len());
+        self.entries.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        while let Some(None) = self.entries.last() {
+            self.entries.pop();
+        }
+        let len = self.entries.len();
+        self.vacant.retain(|&key| key < len);
+        self.entries.shrink_to_fit();
+        self.vacant.shrink_to_fit();
+    }
+
+    fn insert(&mut self, value: T) -> usize {
+        match self.vacant.pop() {
+            Some(key) => {
+                self.entries[key] = Some(value);
+                key
+            }
+            None => {
+                self.entries.push(Some(value));
+                self.entries.len() - 1
+            }
+        }
+    }
+
+    fn remove(&mut self, key: usize) -> Option<T> {
+        let value = self.entries.get_mut(key)?.take()?;
+        self.vacant.push(key);
+        Some(value)
+    }
+
+    fn get(&self, key: usize) -> Option<&T> {
+        self.entries.get(key)?.as_ref()
+    }
+
+    fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        self.entries.get_mut(key)?.as_mut()
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len() - self.vacant.len()
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.vacant.clear();
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        for (key, entry) in self.entries.iter_mut().enumerate() {
+            if entry.as_mut().is_some_and(|value| !f(value)) {
+                *entry = None;
+                self.vacant.push(key);
+            }
+        }
+    }
+
+    fn iter<'s>(&'s self) -> impl Iterator<Item = (usize, &'s T)>
+    where
+        T: 's,
+    {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(key, entry)| Some((key, entry.as_ref()?)))
+    }
+}
+
+/// Stores up to `N` handlers inline in an [`InlineStore`], and only allocates
+/// for the handlers beyond that.
+///
+/// Most events have only a handler or two, and keeping their slots inline
+/// saves allocating the slot container and keeps them next to the event in
+/// memory. Only the slots are inline: the event still keeps the invocation
+/// order in a `Vec`, closures other than [`add_fn`](crate::Event::add_fn)
+/// functions are still allocated, and each invocation still allocates a
+/// snapshot of the handlers.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{Event, InlineStorage};
+///
+/// let event = Event::<u32, (), InlineStorage<2>>::default();
+/// event.add(|args| println!("Event invoked with {}", args));
+/// event.invoke(&1);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InlineStorage<const N: usize>;
+
+impl<const N: usize> Storage for InlineStorage<N> {
+    type Store<T> = InlineStore<T, N>;
+}
+
+/// A [`HandlerStore`] that keeps its first `N` values inline, and the rest in
+/// a [`VecStore`].
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{HandlerStore, InlineStore};
+///
+/// let mut store = InlineStore::<_, 2>::new();
+/// store.insert("first");
+/// store.insert("second");
+/// assert_eq!(store.capacity(), 2); // Nothing allocated yet
+///
+/// store.insert("third"); // Spills to the heap
+/// assert!(store.capacity() > 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct InlineStore<T, const N: usize> {
+    inline: [Option<T>; N],
+    spilled: VecStore<T>,
+}
+
+impl<T, const N: usize> Default for InlineStore<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> InlineStore<T, N> {
+    /// Creates a new, empty InlineStore
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::InlineStore;
+    ///
+    /// let store: InlineStore<u32, 4> = InlineStore::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            inline: [const { None }; N],
+            spilled: VecStore::new(),
+        }
+    }
+}
+
+impl<T, const N: usize> HandlerStore<T> for InlineStore<T, N> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inline: [const { None }; N],
+            spilled: VecStore::with_capacity(capacity.saturating_sub(N)),
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        N + self.spilled.capacity()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        let vacant = self.inline.iter().filter(|entry| entry.is_none()).count();
+        self.spilled.reserve(additional.saturating_sub(vacant));
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.spilled.shrink_to_fit();
+    }
+
+    fn insert(&mut self, value: T) -> usize {
+        match self.inline.iter().position(Option::is_none) {
+            Some(key) => {
+                self.inline[key] = Some(value);
+                key
+            }
+            None => N + self.spilled.insert(value),
+        }
+    }
+
+    fn remove(&mut self, key: usize) -> Option<T> {
+        match key.checked_sub(N) {
+            None => self.inline[key].take(),
+            Some(key) => self.spilled.remove(key),
+        }
+    }
+
+    fn get(&self, key: usize) -> Option<&T> {
+        match key.checked_sub(N) {
+            None => self.inline[key].as_ref(),
+            Some(key) => self.spilled.get(key),
+        }
+    }
+
+    fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        match key.checked_sub(N) {
+            None => self.inline[key].as_mut(),
+            Some(key) => self.spilled.get_mut(key),
+        }
+    }
+
+    fn len(&self) -> usize {
+        let inline = self.inline.iter().filter(|entry| entry.is_some()).count();
+        inline + self.spilled.len()
+    }
+
+    fn clear(&mut self) {
+        self.inline = [const { None }; N];
+        self.spilled.clear();
+    }
+
+    fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        for entry in &mut self.inline {
+            if entry.as_mut().is_some_and(|value| !f(value)) {
+                *entry = None;
+            }
+        }
+        self.spilled.retain(f);
+    }
+
+    fn iter<'s>(&'s self) -> impl Iterator<Item = (usize, &'s T)>
+    where
+        T: 's,
+    {
+        let inline = self
+            .inline
+            .iter()
+            .enumerate()
+            .filter_map(|(key, entry)| Some((key, entry.as_ref()?)));
+        let spilled = self.spilled.iter().map(|(key, value)| (N + key, value));
+        inline.chain(spilled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::Event;
+
+    fn exercise<S: HandlerStore<char>>() {
+        let mut store = S::with_capacity(2);
+        assert!(store.capacity() >= 2);
+
+        let a = store.insert('a');
+        let b = store.insert('b');
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.get(a), Some(&'a'));
+
+        assert_eq!(store.remove(a), Some('a'));
+        assert_eq!(store.remove(a), None);
+        assert_eq!(store.get(a), None);
+
+        let c = store.insert('c');
+        *store.get_mut(c).unwrap() = 'C';
+        store.retain(|value| *value != 'b');
+        assert_eq!(store.get(b), None);
+        assert_eq!(store.iter().collect::<Vec<_>>(), vec![(c, &'C')]);
+
+        store.shrink_to_fit();
+        assert_eq!(store.get(c), Some(&'C'));
+        store.clear();
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_vec_store() {
+        exercise::<VecStore<char>>();
+    }
+
+    #[test]
+    fn test_inline_store() {
+        exercise::<InlineStore<char, 0>>();
+        exercise::<InlineStore<char, 1>>();
+        exercise::<InlineStore<char, 4>>();
+    }
+
+    #[test]
+    fn test_inline_store_spills() {
+        let mut store = InlineStore::<u32, 2>::with_capacity(2);
+        let keys = [store.insert(1), store.insert(2)];
+        assert_eq!(store.capacity(), 2);
+
+        let spilled = store.insert(3);
+        assert_eq!(store.get(spilled), Some(&3));
+        store.remove(keys[0]);
+        assert_eq!(store.insert(4), keys[0]); // Fills the inline slot first
+        assert_eq!(store.len(), 3);
+    }
+
+    #[cfg(feature = "slab")]
+    #[test]
+    fn test_slab_store() {
+        exercise::<slab::Slab<char>>();
+    }
+
+    #[test]
+    fn test_event_with_vec_storage() {
+        let log = std::cell::RefCell::new(vec![]);
+        let event = Event::<u32, (), VecStorage>::default();
+
+        let first = event.add(|arg| log.borrow_mut().push((1, *arg)));
+        event.add(|arg| log.borrow_mut().push((2, *arg)));
+        event.invoke(&1);
+        assert!(event.remove(first));
+        event.add(|arg| log.borrow_mut().push((3, *arg)));
+        event.invoke(&2);
+
+        assert_eq!(*log.borrow(), vec![(1, 1), (2, 1), (2, 2), (3, 2)]);
+    }
+
+    #[test]
+    fn test_custom_storage() {
+        thread_local! {
+            static INSERTS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        struct Counted<T>(VecStore<T>);
+
+        impl<T> HandlerStore<T> for Counted<T> {
+            fn with_capacity(capacity: usize) -> Self {
+                Counted(VecStore::with_capacity(capacity))
+            }
+            fn capacity(&self) -> usize {
+                self.0.capacity()
+            }
+            fn reserve(&mut self, additional: usize) {
+                self.0.reserve(additional);
+            }
+            fn shrink_to_fit(&mut self) {
+                self.0.shrink_to_fit();
+            }
+            fn insert(&mut self, value: T) -> usize {
+                INSERTS.with(|inserts| inserts.set(inserts.get() + 1));
+                self.0.insert(value)
+            }
+            fn remove(&mut self, key: usize) -> Option<T> {
+                self.0.remove(key)
+            }
+            fn get(&self, key: usize) -> Option<&T> {
+                self.0.get(key)
+            }
+            fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+                self.0.get_mut(key)
+            }
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+            fn clear(&mut self) {
+                self.0.clear();
+            }
+            fn retain<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+                self.0.retain(f);
+            }
+            fn iter<'s>(&'s self) -> impl Iterator<Item = (usize, &'s T)>
+            where
+                T: 's,
+            {
+                self.0.iter()
+            }
+        }
+
+        struct CountedStorage;
+
+        impl Storage for CountedStorage {
+            type Store<T> = Counted<T>;
+        }
+
+        let event = Event::<(), (), CountedStorage>::default();
+        event.add(|_| {});
+        event.add(|_| {});
+        assert_eq!(event.len(), 2);
+        assert_eq!(INSERTS.with(Cell::get), 2);
+    }
+}
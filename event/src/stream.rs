@@ -0,0 +1,215 @@
+use alloc::collections::VecDeque;
+use alloc::rc::{Rc, Weak};
+use core::cell::RefCell;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use futures::Stream;
+
+use crate::handlers::{Attachment, Handler};
+use crate::{Backpressure, DefaultStorage, Event};
+
+struct Channel<TEventArgs> {
+    queue: VecDeque<TEventArgs>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+/// The handler side of an [`EventStream`], which closes the stream when the
+/// handler is removed or the event is dropped.
+struct Sender<TEventArgs> {
+    channel: Weak<RefCell<Channel<TEventArgs>>>,
+}
+
+impl<TEventArgs> Drop for Sender<TEventArgs> {
+    fn drop(&mut self) {
+        if let Some(channel) = self.channel.upgrade() {
+            let mut channel = channel.borrow_mut();
+            channel.closed = true;
+            if let Some(waker) = channel.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// A stream of the event arguments an event is invoked with, see [`Event::stream`].
+///
+/// The stream ends once its handler is removed from the event, or the event is dropped.
+/// Dropping the stream removes its handler from the event.
+#[must_use = "streams do nothing unless polled"]
+pub struct EventStream<'a, TEventArgs> {
+    channel: Rc<RefCell<Channel<TEventArgs>>>,
+    _attachment: Attachment<'a, TEventArgs, (), DefaultStorage>,
+}
+
+impl<TEventArgs> Stream for EventStream<'_, TEventArgs> {
+    type Item = TEventArgs;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut channel = self.channel.borrow_mut();
+        match channel.queue.pop_front() {
+            Some(arg) => Poll::Ready(Some(arg)),
+            None if channel.closed => Poll::Ready(None),
+            None => {
+                channel.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<'a, TEventArgs: Clone + 'a> Event<'a, TEventArgs> {
+    /// Returns a stream that yields a clone of the event arguments each time
+    /// the event is invoked.
+    ///
+    /// Invocations are queued until the stream is polled, so none are missed.
+    /// The stream ends once the event is dropped, and dropping the stream
+    /// removes its handler from the event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<u32>::new();
+    /// let mut stream = event.stream();
+    ///
+    /// event.invoke(&1);
+    /// event.invoke(&2);
+    /// drop(event);
+    ///
+    /// # futures::executor::block_on(async {
+    /// while let Some(value) = stream.next().await {
+    ///     println!("Received {}", value);
+    /// }
+    /// # });
+    /// ```
+    pub fn stream(&self) -> EventStream<'a, TEventArgs> {
+        self.stream_with(Backpressure::Unbounded)
+    }
+
+    /// Returns a stream like [`stream`](Self::stream), whose queue of
+    /// invocations waiting to be polled follows the given policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `policy` is [`Backpressure::Block`], since invoking the event
+    /// cannot wait for the stream to be polled. Use
+    /// [`Backpressure::DropNewest`] to drop the new invocations once the queue
+    /// is full, or [`Backpressure::Unbounded`] to keep them all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    ///
+    /// use event_rs::{Backpressure, Event};
+    ///
+    /// let event = Event::<u32>::new();
+    /// let stream = event.stream_with(Backpressure::DropNewest(2));
+    ///
+    /// event.invoke(&1);
+    /// event.invoke(&2);
+    /// event.invoke(&3); // Dropped
+    /// drop(event);
+    ///
+    /// # futures::executor::block_on(async {
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![1, 2]);
+    /// # });
+    /// ```
+    pub fn stream_with(&self, policy: Backpressure) -> EventStream<'a, TEventArgs> {
+        assert!(
+            !matches!(policy, Backpressure::Block(_)),
+            "streams cannot block the invoker, use DropNewest or Unbounded instead"
+        );
+        let channel = Rc::new(RefCell::new(Channel {
+            queue: VecDeque::new(),
+            waker: None,
+            closed: false,
+        }));
+        let sender = Sender {
+            channel: Rc::downgrade(&channel),
+        };
+        let attachment = self.attach(Handler::guarded(move |arg: &TEventArgs, expired| {
+            let Some(channel) = sender.channel.upgrade() else {
+                expired.set(true);
+                return None;
+            };
+            let mut channel = channel.borrow_mut();
+            let _ = policy.push(&mut channel.queue, arg.clone());
+            if let Some(waker) = channel.waker.take() {
+                waker.wake();
+            }
+            Some(())
+        }));
+        EventStream {
+            channel,
+            _attachment: attachment,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use futures::executor::block_on;
+
+    use super::*;
+
+    #[test]
+    fn test_stream() {
+        let event = Event::new();
+        let mut stream = event.stream();
+
+        event.invoke(&1);
+        event.invoke(&2);
+        assert_eq!(block_on(stream.next()), Some(1));
+
+        event.invoke(&3);
+        drop(event);
+        assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_stream_with() {
+        let event = Event::new();
+        let oldest = event.stream_with(Backpressure::DropOldest(2));
+        let latest = event.stream_with(Backpressure::Coalesce);
+        let newest = event.stream_with(Backpressure::DropNewest(2));
+
+        for arg in 1..=3 {
+            event.invoke(&arg);
+        }
+        drop(event);
+        assert_eq!(block_on(oldest.collect::<Vec<_>>()), vec![2, 3]);
+        assert_eq!(block_on(latest.collect::<Vec<_>>()), vec![3]);
+        assert_eq!(block_on(newest.collect::<Vec<_>>()), vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "streams cannot block the invoker")]
+    fn test_stream_with_block() {
+        let event = Event::<i32>::new();
+        let _ = event.stream_with(Backpressure::Block(2));
+    }
+
+    #[test]
+    fn test_drop_stream() {
+        let event = Event::new();
+        let stream = event.stream();
+        let handle = event.add(|_: &()| {});
+
+        drop(stream);
+        assert_eq!(event.handles(), vec![handle]);
+
+        for _ in 0..3 {
+            drop(event.stream());
+        }
+        assert_eq!(event.len(), 1);
+        assert!(event.remove(handle));
+        assert_eq!(event.len(), 0);
+    }
+}
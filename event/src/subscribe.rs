@@ -0,0 +1,327 @@
+use alloc::boxed::Box;
+
+#[cfg(feature = "arc-swap")]
+use crate::LockFreeEvent;
+use crate::id::next_generation;
+use crate::{
+    CompositeEvent, Event, EventHandle, EventSource, HandlerId, LatchEvent, OnceEvent,
+    PropagatingEvent, PropagationArgs, SharedEvent, StickyEvent, Storage,
+};
+#[cfg(feature = "std")]
+use crate::{SharedSyncEvent, SyncEvent};
+
+/// An object-safe interface to the events that handlers can be added to and
+/// removed from.
+///
+/// Downstream code can accept `&dyn Subscribe<T>` to take anything
+/// subscribable without naming a concrete event type. The trait is implemented
+/// by [`Event`] and the types that wrap one, [`SharedEvent`], [`EventSource`],
+/// [`EventHandle`] and [`CompositeEvent`], by [`StickyEvent`],
+/// [`PropagatingEvent`], [`OnceEvent`] and [`LatchEvent`]. Events whose handlers
+/// must be `Send + Sync` implement [`SubscribeSync`] instead.
+///
+/// [`StaticEvent`](crate::StaticEvent) and `CriticalSectionEvent` borrow their
+/// handlers so that they never allocate, and cannot take ownership of a boxed
+/// one.
+/// Events whose handlers have another signature, such as
+/// [`EventMutArgs`](crate::EventMutArgs) or [`SenderEvent`](crate::SenderEvent),
+/// do not implement it either.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{Event, StickyEvent, Subscribe};
+///
+/// fn log_changes(source: &dyn Subscribe<u32>) {
+///     source.add(Box::new(|value| println!("Changed to {}", value)));
+/// }
+///
+/// let event = Event::new();
+/// let sticky = StickyEvent::new();
+/// log_changes(&event);
+/// log_changes(&sticky);
+///
+/// event.invoke(&1); // Prints "Changed to 1"
+/// sticky.invoke(&2); // Prints "Changed to 2"
+/// ```
+pub trait Subscribe<'a, TEventArgs> {
+    /// Adds an event handler, returning a handle that can be used to remove it
+    /// later.
+    fn add(&self, handler: Box<dyn Fn(&TEventArgs) + 'a>) -> HandlerId<TEventArgs>;
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    fn remove(&self, handle: HandlerId<TEventArgs>) -> bool;
+}
+
+impl<'a, TEventArgs: 'a, TStorage: Storage> Subscribe<'a, TEventArgs>
+    for Event<'a, TEventArgs, (), TStorage>
+{
+    fn add(&self, handler: Box<dyn Fn(&TEventArgs) + 'a>) -> HandlerId<TEventArgs> {
+        Event::add(self, handler)
+    }
+
+    fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        Event::remove(self, handle)
+    }
+}
+
+impl<'a, TEventArgs: 'a, TStorage: Storage> Subscribe<'a, TEventArgs>
+    for EventHandle<'a, TEventArgs, (), TStorage>
+{
+    fn add(&self, handler: Box<dyn Fn(&TEventArgs) + 'a>) -> HandlerId<TEventArgs> {
+        EventHandle::add(self, handler)
+    }
+
+    fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        EventHandle::remove(self, handle)
+    }
+}
+
+impl<'a, TEventArgs: 'a> Subscribe<'a, TEventArgs> for SharedEvent<'a, TEventArgs> {
+    fn add(&self, handler: Box<dyn Fn(&TEventArgs) + 'a>) -> HandlerId<TEventArgs> {
+        Subscribe::add(&**self, handler)
+    }
+
+    fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        Subscribe::remove(&**self, handle)
+    }
+}
+
+impl<'a, TEventArgs: 'a, TStorage: Storage> Subscribe<'a, TEventArgs>
+    for EventSource<'a, TEventArgs, (), TStorage>
+{
+    fn add(&self, handler: Box<dyn Fn(&TEventArgs) + 'a>) -> HandlerId<TEventArgs> {
+        Subscribe::add(&**self, handler)
+    }
+
+    fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        Subscribe::remove(&**self, handle)
+    }
+}
+
+impl<'s, 'a, TEventArgs: 'a> Subscribe<'a, TEventArgs> for CompositeEvent<'s, 'a, TEventArgs> {
+    fn add(&self, handler: Box<dyn Fn(&TEventArgs) + 'a>) -> HandlerId<TEventArgs> {
+        Subscribe::add(&**self, handler)
+    }
+
+    fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        Subscribe::remove(&**self, handle)
+    }
+}
+
+impl<'a, TEventArgs: Clone + 'a> Subscribe<'a, TEventArgs> for StickyEvent<'a, TEventArgs> {
+    fn add(&self, handler: Box<dyn Fn(&TEventArgs) + 'a>) -> HandlerId<TEventArgs> {
+        StickyEvent::add(self, handler)
+    }
+
+    fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        StickyEvent::remove(self, handle)
+    }
+}
+
+impl<'a, TEventArgs: 'a> Subscribe<'a, PropagationArgs<TEventArgs>>
+    for PropagatingEvent<'a, TEventArgs>
+{
+    fn add(
+        &self,
+        handler: Box<dyn Fn(&PropagationArgs<TEventArgs>) + 'a>,
+    ) -> HandlerId<PropagationArgs<TEventArgs>> {
+        PropagatingEvent::add(self, handler)
+    }
+
+    fn remove(&self, handle: HandlerId<PropagationArgs<TEventArgs>>) -> bool {
+        PropagatingEvent::remove(self, handle)
+    }
+}
+
+/// Like [`OnceEvent::add`], the handler runs right away if the event has been
+/// invoked, and the returned handle then refers to no handler.
+impl<'a, TEventArgs: 'a> Subscribe<'a, TEventArgs> for OnceEvent<'a, TEventArgs> {
+    fn add(&self, handler: Box<dyn Fn(&TEventArgs) + 'a>) -> HandlerId<TEventArgs> {
+        OnceEvent::add(self, handler).unwrap_or_else(detached)
+    }
+
+    fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        OnceEvent::remove(self, handle)
+    }
+}
+
+/// Like [`LatchEvent::add`], the handler runs right away if the latch is open,
+/// and the returned handle then refers to no handler.
+impl<'a> Subscribe<'a, ()> for LatchEvent<'a> {
+    fn add(&self, handler: Box<dyn Fn(&()) + 'a>) -> HandlerId<()> {
+        LatchEvent::add(self, move || handler(&())).unwrap_or_else(detached)
+    }
+
+    fn remove(&self, handle: HandlerId<()>) -> bool {
+        LatchEvent::remove(self, handle)
+    }
+}
+
+/// Returns a handle that refers to no handler, for a handler that already ran
+/// instead of being stored.
+fn detached<TEventArgs>() -> HandlerId<TEventArgs> {
+    HandlerId::new(usize::MAX, next_generation())
+}
+
+/// The counterpart of [`Subscribe`] for the events shared between threads,
+/// whose handlers must be `Send + Sync`.
+///
+/// It is implemented by `SyncEvent` and `SharedSyncEvent`, with the `std`
+/// feature, and by `LockFreeEvent`, with the `arc-swap` feature.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::{SubscribeSync, SyncEvent};
+///
+/// fn log_changes(source: &dyn SubscribeSync<u32>) {
+///     source.add(Box::new(|value| println!("Changed to {}", value)));
+/// }
+///
+/// let event = SyncEvent::new();
+/// log_changes(&event);
+///
+/// event.invoke(&1); // Prints "Changed to 1"
+/// ```
+pub trait SubscribeSync<'a, TEventArgs> {
+    /// Adds an event handler, returning a handle that can be used to remove it
+    /// later.
+    fn add(&self, handler: Box<dyn Fn(&TEventArgs) + Send + Sync + 'a>) -> HandlerId<TEventArgs>;
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    fn remove(&self, handle: HandlerId<TEventArgs>) -> bool;
+}
+
+#[cfg(feature = "std")]
+impl<'a, TEventArgs: 'a> SubscribeSync<'a, TEventArgs> for SyncEvent<'a, TEventArgs> {
+    fn add(&self, handler: Box<dyn Fn(&TEventArgs) + Send + Sync + 'a>) -> HandlerId<TEventArgs> {
+        SyncEvent::add(self, handler)
+    }
+
+    fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        SyncEvent::remove(self, handle)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, TEventArgs: 'a> SubscribeSync<'a, TEventArgs> for SharedSyncEvent<'a, TEventArgs> {
+    fn add(&self, handler: Box<dyn Fn(&TEventArgs) + Send + Sync + 'a>) -> HandlerId<TEventArgs> {
+        SubscribeSync::add(&**self, handler)
+    }
+
+    fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        SubscribeSync::remove(&**self, handle)
+    }
+}
+
+#[cfg(feature = "arc-swap")]
+impl<'a, TEventArgs: 'a> SubscribeSync<'a, TEventArgs> for LockFreeEvent<'a, TEventArgs> {
+    fn add(&self, handler: Box<dyn Fn(&TEventArgs) + Send + Sync + 'a>) -> HandlerId<TEventArgs> {
+        LockFreeEvent::add(self, handler)
+    }
+
+    fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        LockFreeEvent::remove(self, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_subscribe() {
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+        let sticky = StickyEvent::new();
+        let (source, handle) = Event::new().split();
+        let shared = SharedEvent::new();
+        let composite = CompositeEvent::new();
+        let sources: [&dyn Subscribe<i32>; 6] =
+            [&event, &sticky, &handle, &source, &shared, &composite];
+
+        let handles: Vec<_> = sources
+            .iter()
+            .map(|source| source.add(Box::new(|arg| log.borrow_mut().push(*arg))))
+            .collect();
+        event.invoke(&1);
+        sticky.invoke(&2);
+        source.invoke(&3);
+        shared.invoke(&4);
+        composite.invoke(&5);
+        assert_eq!(*log.borrow(), vec![1, 2, 3, 3, 4, 5]);
+
+        for (source, handle) in sources.iter().zip(handles) {
+            assert!(source.remove(handle));
+            assert!(!source.remove(handle));
+        }
+        event.invoke(&1);
+        sticky.invoke(&2);
+        source.invoke(&3);
+        shared.invoke(&4);
+        composite.invoke(&5);
+        assert_eq!(log.borrow().len(), 6);
+    }
+
+    #[test]
+    fn test_subscribe_once() {
+        let log = RefCell::new(vec![]);
+        let once = OnceEvent::new();
+        let latch = LatchEvent::new(1);
+
+        let handle = Subscribe::add(&once, Box::new(|arg: &i32| log.borrow_mut().push(*arg)));
+        assert!(Subscribe::remove(&once, handle));
+        Subscribe::add(&once, Box::new(|arg: &i32| log.borrow_mut().push(*arg)));
+        Subscribe::add(&latch, Box::new(|_: &()| log.borrow_mut().push(0)));
+        once.invoke(1);
+        latch.signal();
+        assert_eq!(*log.borrow(), vec![1, 0]);
+
+        // The handlers run right away, and their handles refer to nothing
+        let handle = Subscribe::add(&once, Box::new(|arg: &i32| log.borrow_mut().push(*arg)));
+        assert!(!Subscribe::remove(&once, handle));
+        let handle = Subscribe::add(&latch, Box::new(|_: &()| log.borrow_mut().push(0)));
+        assert!(!Subscribe::remove(&latch, handle));
+        assert_eq!(*log.borrow(), vec![1, 0, 1, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_subscribe_sync() {
+        use std::sync::Mutex;
+
+        let log = Mutex::new(vec![]);
+        let event = SyncEvent::new();
+        let shared = SharedSyncEvent::new();
+        #[cfg(feature = "arc-swap")]
+        let lock_free = LockFreeEvent::new();
+        let sources: Vec<&dyn SubscribeSync<i32>> = vec![
+            &event,
+            &shared,
+            #[cfg(feature = "arc-swap")]
+            &lock_free,
+        ];
+
+        let handles: Vec<_> = sources
+            .iter()
+            .map(|source| source.add(Box::new(|arg| log.lock().unwrap().push(*arg))))
+            .collect();
+        event.invoke(&1);
+        shared.invoke(&2);
+        #[cfg(feature = "arc-swap")]
+        lock_free.invoke(&3);
+        assert_eq!(log.lock().unwrap().len(), sources.len());
+
+        for (source, handle) in sources.iter().zip(handles) {
+            assert!(source.remove(handle));
+            assert!(!source.remove(handle));
+        }
+    }
+}
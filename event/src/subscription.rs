@@ -0,0 +1,150 @@
+use crate::{DefaultStorage, Event, HandlerId, Storage};
+
+/// A guard that removes its handler from an [`Event`] when dropped.
+///
+/// Created by [`Event::subscribe`]. Keep the guard alive for as long as the
+/// handler should stay attached, e.g. as a field of the subscribing object.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use event_rs::Event;
+///
+/// let counter = Cell::new(0);
+/// let event = Event::<()>::new();
+///
+/// {
+///     let _subscription = event.subscribe(|_| counter.set(counter.get() + 1));
+///     event.invoke(&());
+/// } // Unsubscribed here
+///
+/// event.invoke(&());
+/// assert_eq!(counter.get(), 1);
+/// ```
+#[must_use = "dropping a Subscription immediately removes its handler"]
+pub struct Subscription<'e, 'a, TEventArgs, TResult = (), TStorage: Storage = DefaultStorage> {
+    event: &'e Event<'a, TEventArgs, TResult, TStorage>,
+    handle: HandlerId<TEventArgs>,
+}
+
+impl<'e, 'a, TEventArgs, TResult, TStorage: Storage>
+    Subscription<'e, 'a, TEventArgs, TResult, TStorage>
+{
+    pub(crate) fn new(
+        event: &'e Event<'a, TEventArgs, TResult, TStorage>,
+        handle: HandlerId<TEventArgs>,
+    ) -> Self {
+        Self { event, handle }
+    }
+
+    /// Returns the handle of the subscribed handler.
+    pub fn handle(&self) -> HandlerId<TEventArgs> {
+        self.handle
+    }
+
+    /// Pauses the handler, so that it is skipped until it is resumed.
+    ///
+    /// See [`Event::pause`].
+    pub fn pause(&self) -> bool {
+        self.event.pause(self.handle)
+    }
+
+    /// Resumes the handler after [`pause`](Self::pause).
+    pub fn resume(&self) -> bool {
+        self.event.resume(self.handle)
+    }
+
+    /// Returns `true` if the handler is paused.
+    pub fn is_paused(&self) -> bool {
+        self.event.is_paused(self.handle)
+    }
+
+    /// Removes the handler now.
+    ///
+    /// Returns `true` if the handler was still attached, `false` otherwise.
+    pub fn unsubscribe(self) -> bool {
+        let removed = self.event.remove(self.handle);
+        core::mem::forget(self);
+        removed
+    }
+
+    /// Consumes the guard without removing the handler.
+    ///
+    /// Returns the handle, which can still be passed to [`Event::remove`].
+    pub fn detach(self) -> HandlerId<TEventArgs> {
+        let handle = self.handle;
+        core::mem::forget(self);
+        handle
+    }
+}
+
+impl<'e, 'a, TEventArgs, TResult, TStorage: Storage> Drop
+    for Subscription<'e, 'a, TEventArgs, TResult, TStorage>
+{
+    fn drop(&mut self) {
+        self.event.remove(self.handle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_drop_unsubscribes() {
+        let counter = RefCell::new(0);
+        let event = Event::new();
+
+        let subscription = event.subscribe(|_| {
+            *counter.borrow_mut() += 1;
+        });
+
+        event.invoke(&());
+        drop(subscription);
+        event.invoke(&());
+
+        assert_eq!(*counter.borrow(), 1);
+    }
+
+    #[test]
+    fn test_unsubscribe_and_detach() {
+        let counter = RefCell::new(0);
+        let event = Event::new();
+
+        let subscription = event.subscribe(|_| {
+            *counter.borrow_mut() += 1;
+        });
+        assert!(subscription.unsubscribe());
+
+        let handle = event
+            .subscribe(|_| {
+                *counter.borrow_mut() += 10;
+            })
+            .detach();
+
+        event.invoke(&());
+        assert_eq!(*counter.borrow(), 10);
+        assert!(event.remove(handle));
+    }
+
+    #[test]
+    fn test_pause_and_resume() {
+        let counter = RefCell::new(0);
+        let event = Event::new();
+
+        let subscription = event.subscribe(|_| {
+            *counter.borrow_mut() += 1;
+        });
+        assert!(subscription.pause());
+        assert!(subscription.is_paused());
+        event.invoke(&());
+
+        assert!(subscription.resume());
+        event.invoke(&());
+        assert_eq!(*counter.borrow(), 1);
+    }
+}
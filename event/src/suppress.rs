@@ -0,0 +1,212 @@
+use alloc::boxed::Box;
+
+use crate::{DefaultStorage, Event, Storage};
+
+/// Type alias for the function that coalesces the invocations of a suppressed
+/// event into one, given the value so far, if any.
+type CoalesceFn<'a, TEventArgs> =
+    Box<dyn FnMut(Option<TEventArgs>, &TEventArgs) -> TEventArgs + 'a>;
+
+/// The invocations held back by a coalescing [`Suppression`].
+pub(crate) struct Held<'a, TEventArgs> {
+    coalesce: CoalesceFn<'a, TEventArgs>,
+    value: Option<TEventArgs>,
+}
+
+/// A guard that keeps an [`Event`] disabled until it is dropped.
+///
+/// Created by [`Event::suppress`], [`Event::suppress_latest`] or
+/// [`Event::suppress_fold`]. When dropped, the guard restores the state the
+/// event had before, so guards can be nested. A coalescing guard then invokes
+/// the event once, if it was invoked while suppressed.
+#[must_use = "dropping a Suppression immediately re-enables its event"]
+pub struct Suppression<'e, 'a, TEventArgs, TResult = (), TStorage: Storage = DefaultStorage> {
+    event: &'e Event<'a, TEventArgs, TResult, TStorage>,
+    was_enabled: bool,
+    /// The held invocations of an enclosing coalescing guard, for a coalescing guard.
+    outer: Option<Option<Held<'a, TEventArgs>>>,
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> Event<'a, TEventArgs, TResult, TStorage> {
+    /// Disables the event until the returned guard is dropped.
+    ///
+    /// This suits bulk updates, whose intermediate notifications would only
+    /// cause churn. See [`set_enabled`](Self::set_enabled).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let changed = Event::<usize>::new();
+    /// changed.add(|len| println!("{} items", len));
+    ///
+    /// {
+    ///     let _suppression = changed.suppress();
+    ///     for len in 1..=100 {
+    ///         changed.invoke(&len); // Prints nothing
+    ///     }
+    /// }
+    ///
+    /// changed.invoke(&100); // Prints "100 items"
+    /// ```
+    pub fn suppress(&self) -> Suppression<'_, 'a, TEventArgs, TResult, TStorage> {
+        let was_enabled = self.is_enabled();
+        self.set_enabled(false);
+        Suppression {
+            event: self,
+            was_enabled,
+            outer: None,
+        }
+    }
+
+    /// Disables the event until the returned guard is dropped, then invokes it
+    /// once with the latest event arguments it was invoked with meanwhile.
+    ///
+    /// This is the "batch update, then notify once" pattern of data binding. If
+    /// the event was not invoked while suppressed, it is not invoked afterwards
+    /// either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// let changed = Event::<usize>::new();
+    /// changed.add(|len| println!("{} items", len));
+    ///
+    /// {
+    ///     let _suppression = changed.suppress_latest();
+    ///     for len in 1..=100 {
+    ///         changed.invoke(&len);
+    ///     }
+    /// } // Prints "100 items"
+    /// ```
+    pub fn suppress_latest(&self) -> Suppression<'_, 'a, TEventArgs, TResult, TStorage>
+    where
+        TEventArgs: Clone,
+    {
+        self.suppress_coalesced(Box::new(|_, arg: &TEventArgs| arg.clone()))
+    }
+
+    /// Disables the event until the returned guard is dropped, then invokes it
+    /// once with the event arguments it was invoked with meanwhile, folded with `f`.
+    ///
+    /// The first event arguments start the fold, and `f` combines the value so
+    /// far with each of the following ones. If the event was not invoked while
+    /// suppressed, it is not invoked afterwards either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::Event;
+    ///
+    /// // Dirty ranges of rows to redraw
+    /// let dirty = Event::<(u32, u32)>::new();
+    /// dirty.add(|(start, end)| println!("Redraw rows {} to {}", start, end));
+    ///
+    /// {
+    ///     let _suppression = dirty.suppress_fold(|(start, end), &(other_start, other_end)| {
+    ///         (start.min(other_start), end.max(other_end))
+    ///     });
+    ///     dirty.invoke(&(10, 12));
+    ///     dirty.invoke(&(3, 4));
+    /// } // Prints "Redraw rows 3 to 12"
+    /// ```
+    pub fn suppress_fold<F>(&self, mut f: F) -> Suppression<'_, 'a, TEventArgs, TResult, TStorage>
+    where
+        TEventArgs: Clone,
+        F: FnMut(TEventArgs, &TEventArgs) -> TEventArgs + 'a,
+    {
+        self.suppress_coalesced(Box::new(move |value, arg| match value {
+            Some(value) => f(value, arg),
+            None => arg.clone(),
+        }))
+    }
+
+    fn suppress_coalesced(
+        &self,
+        coalesce: CoalesceFn<'a, TEventArgs>,
+    ) -> Suppression<'_, 'a, TEventArgs, TResult, TStorage> {
+        let outer = self.held.replace(Some(Held {
+            coalesce,
+            value: None,
+        }));
+        let mut suppression = self.suppress();
+        suppression.outer = Some(outer);
+        suppression
+    }
+
+    /// Records an invocation of the disabled event for a coalescing suppression.
+    pub(crate) fn hold(&self, arg: &TEventArgs) {
+        if let Some(held) = self.held.borrow_mut().as_mut() {
+            held.value = Some((held.coalesce)(held.value.take(), arg));
+        }
+    }
+}
+
+impl<'e, 'a, TEventArgs, TResult, TStorage: Storage> Drop
+    for Suppression<'e, 'a, TEventArgs, TResult, TStorage>
+{
+    fn drop(&mut self) {
+        let held = self
+            .outer
+            .take()
+            .and_then(|outer| self.event.held.replace(outer));
+        self.event.set_enabled(self.was_enabled);
+
+        // Passed on to an enclosing suppression if the event is still disabled
+        if let Some(value) = held.and_then(|held| held.value) {
+            self.event.invoke(&value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_nested_suppression() {
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+        event.add(|arg: &i32| log.borrow_mut().push(*arg));
+        event.wrap(|arg, next| next(&(arg * 10)));
+
+        let outer = event.suppress();
+        let inner = event.suppress();
+        event.invoke(&1);
+        drop(inner);
+        event.invoke_all(&[2, 3]);
+        assert!(!event.is_enabled());
+        drop(outer);
+
+        event.invoke(&4);
+        assert_eq!(*log.borrow(), vec![40]);
+    }
+
+    #[test]
+    fn test_coalescing_suppression() {
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+        event.add(|arg: &i32| log.borrow_mut().push(*arg));
+
+        drop(event.suppress_latest());
+        assert!(log.borrow().is_empty());
+
+        {
+            let _sum = event.suppress_fold(|sum, arg| sum + arg);
+            event.invoke(&1);
+            {
+                let _latest = event.suppress_latest();
+                event.invoke_all(&[2, 3]);
+            }
+            let _plain = event.suppress();
+            event.invoke(&4);
+        }
+        assert_eq!(*log.borrow(), vec![8]);
+        assert!(event.is_enabled());
+    }
+}
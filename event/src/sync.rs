@@ -0,0 +1,429 @@
+#[cfg(feature = "parking_lot")]
+use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[cfg(not(feature = "parking_lot"))]
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use std::sync::Arc;
+
+use crate::HandlerId;
+use crate::registry::Registry;
+
+/// Type alias for thread-safe event handlers.
+///
+/// Each handler is a boxed function that takes a reference to event arguments
+/// and can be shared across threads.
+pub type SyncEventHandler<'a, TEventArgs> = Box<dyn Fn(&TEventArgs) + Send + Sync + 'a>;
+
+/// A handler as stored by a [`SyncEvent`], shared with the clones of the event.
+type SharedHandler<'a, TEventArgs> = Arc<dyn Fn(&TEventArgs) + Send + Sync + 'a>;
+
+/// A thread-safe event that allows multiple handlers to be attached.
+///
+/// This is the `Send + Sync` counterpart of [`Event`](crate::Event).
+/// Handlers can be added, removed, and invoked concurrently from multiple threads.
+/// Handlers run in the order they were added.
+///
+/// Internally, it keeps handlers in a `RwLock`, so concurrent invocations do not
+/// block each other. The lock is the one from `std`, or from `parking_lot` with the
+/// `parking_lot` feature, which is faster and never poisoned.
+///
+/// Cloning the event copies its list of handlers, which are reference counted
+/// rather than duplicated, so it is a cheap way to take a snapshot of the
+/// subscribers, e.g. to hand one to each worker thread. The clone is independent
+/// of the original afterwards, but handles to the handlers it copied stay valid
+/// for both.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::thread;
+///
+/// use event_rs::SyncEvent;
+///
+/// let counter = AtomicUsize::new(0);
+/// let event = SyncEvent::<u32>::new();
+/// event.add(|args| {
+///     counter.fetch_add(*args as usize, Ordering::SeqCst);
+/// });
+///
+/// thread::scope(|s| {
+///     s.spawn(|| event.invoke(&1));
+///     s.spawn(|| event.invoke(&2));
+/// });
+///
+/// assert_eq!(counter.load(Ordering::SeqCst), 3);
+/// ```
+pub struct SyncEvent<'a, TEventArgs> {
+    handlers: RwLock<Registry<TEventArgs, SharedHandler<'a, TEventArgs>>>,
+}
+
+impl<'a, TEventArgs> Clone for SyncEvent<'a, TEventArgs> {
+    fn clone(&self) -> Self {
+        Self {
+            handlers: self.read().clone().into(),
+        }
+    }
+}
+
+impl<'a, TEventArgs> Default for SyncEvent<'a, TEventArgs> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, TEventArgs> SyncEvent<'a, TEventArgs> {
+    /// Creates a new, empty SyncEvent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SyncEvent;
+    ///
+    /// let event: SyncEvent<()> = SyncEvent::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            handlers: Registry::new().into(),
+        }
+    }
+
+    /// Adds an event handler to the event.
+    ///
+    /// The handler should be a closure that accepts a reference to the event arguments
+    /// and returns nothing. The closure will be executed when the event is invoked.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SyncEvent;
+    ///
+    /// let event = SyncEvent::<()>::new();
+    /// let handle = event.add(|args| {
+    ///     println!("Event invoked");
+    /// });
+    /// ```
+    pub fn add<F>(&self, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) + Send + Sync + 'a,
+    {
+        self.write().insert(Arc::new(handler))
+    }
+
+    /// Removes an event handler using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SyncEvent;
+    ///
+    /// let event = SyncEvent::<()>::new();
+    /// let handle = event.add(|args| {
+    ///     println!("Event invoked");
+    /// });
+    ///
+    /// assert!(event.remove(handle));
+    /// assert!(!event.remove(handle)); // Already removed
+    /// ```
+    pub fn remove(&self, handle: HandlerId<TEventArgs>) -> bool {
+        self.write().remove(handle)
+    }
+
+    /// Returns the number of event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SyncEvent;
+    ///
+    /// let event = SyncEvent::<()>::new();
+    /// event.add(|args| println!("Handler 1"));
+    ///
+    /// assert_eq!(event.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Returns `true` if the event has no handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SyncEvent;
+    ///
+    /// let event: SyncEvent<()> = SyncEvent::new();
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all event handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SyncEvent;
+    ///
+    /// let event = SyncEvent::<()>::new();
+    /// event.add(|args| { println!("Handler 1"); });
+    /// event.add(|args| { println!("Handler 2"); });
+    ///
+    /// event.clear(); // Remove all handlers
+    /// ```
+    pub fn clear(&self) {
+        self.write().clear();
+    }
+
+    /// Invokes all event handlers sequentially on the calling thread.
+    ///
+    /// Other threads may invoke the event at the same time. The handlers are
+    /// copied out of the lock before they run, so handlers may add or remove
+    /// handlers, which takes effect from the next invocation, and changes from
+    /// other threads never wait for a running handler.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::SyncEvent;
+    ///
+    /// let event = SyncEvent::new();
+    /// event.add(|args| { println!("Handler 1"); });
+    /// event.add(|args| { println!("Handler 2"); });
+    ///
+    /// event.invoke(&()); // Execute all handlers in order
+    /// ```
+    pub fn invoke(&self, arg: &TEventArgs) {
+        for handler in self.snapshot() {
+            handler(arg);
+        }
+    }
+
+    /// Invokes all event handlers in parallel on the rayon thread pool, and waits
+    /// for them to complete.
+    ///
+    /// This suits independent, CPU-bound handlers. The handlers run in no
+    /// particular order, so they must not rely on each other's effects. Like
+    /// [`invoke`](Self::invoke), it works on a snapshot of the handlers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    ///
+    /// use event_rs::SyncEvent;
+    ///
+    /// let total = AtomicU32::new(0);
+    /// let event = SyncEvent::<u32>::new();
+    /// event.add(|args| { total.fetch_add(args * 2, Ordering::Relaxed); });
+    /// event.add(|args| { total.fetch_add(args * 3, Ordering::Relaxed); });
+    ///
+    /// event.invoke_par(&10);
+    /// assert_eq!(total.load(Ordering::Relaxed), 50);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn invoke_par(&self, arg: &TEventArgs)
+    where
+        TEventArgs: Sync,
+    {
+        use rayon::prelude::*;
+
+        self.snapshot().par_iter().for_each(|handler| handler(arg));
+    }
+
+    /// Returns the handlers in insertion order, without holding the lock.
+    fn snapshot(&self) -> Vec<SharedHandler<'a, TEventArgs>> {
+        self.read().iter().cloned().collect()
+    }
+
+    #[cfg(not(feature = "parking_lot"))]
+    fn read(&self) -> RwLockReadGuard<'_, Registry<TEventArgs, SharedHandler<'a, TEventArgs>>> {
+        self.handlers.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[cfg(not(feature = "parking_lot"))]
+    fn write(&self) -> RwLockWriteGuard<'_, Registry<TEventArgs, SharedHandler<'a, TEventArgs>>> {
+        self.handlers
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[cfg(feature = "parking_lot")]
+    fn read(&self) -> RwLockReadGuard<'_, Registry<TEventArgs, SharedHandler<'a, TEventArgs>>> {
+        self.handlers.read()
+    }
+
+    #[cfg(feature = "parking_lot")]
+    fn write(&self) -> RwLockWriteGuard<'_, Registry<TEventArgs, SharedHandler<'a, TEventArgs>>> {
+        self.handlers.write()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_invoke() {
+        let counter = AtomicUsize::new(0);
+        let event = SyncEvent::new();
+
+        event.invoke(&());
+
+        event.add(|_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        event.add(|_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        event.invoke(&());
+        event.invoke(&());
+        assert_eq!(counter.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_concurrent_access() {
+        let counter = AtomicUsize::new(0);
+        let event = SyncEvent::new();
+
+        thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    let handle = event.add(|_| {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                    });
+                    event.invoke(&());
+                    assert!(event.remove(handle));
+                });
+            }
+        });
+
+        event.invoke(&());
+        assert!(counter.load(Ordering::SeqCst) >= 4);
+        assert!(counter.load(Ordering::SeqCst) <= 16);
+    }
+
+    #[test]
+    fn test_insertion_order_after_reuse() {
+        let log = Mutex::new(vec![]);
+        let event = SyncEvent::new();
+
+        let first = event.add(|_| log.lock().unwrap().push(1));
+        event.add(|_| log.lock().unwrap().push(2));
+        assert!(event.remove(first));
+        event.add(|_| log.lock().unwrap().push(3));
+
+        event.invoke(&());
+        assert_eq!(*log.lock().unwrap(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_stale_handle() {
+        let counter = AtomicUsize::new(0);
+        let event = SyncEvent::new();
+
+        let stale = event.add(|_| {});
+        assert!(event.remove(stale));
+        event.add(|_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert!(!event.remove(stale));
+        event.invoke(&());
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_remove_handler_twice() {
+        let counter = AtomicUsize::new(0);
+        let event = SyncEvent::new();
+
+        let handle = event.add(|_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert!(event.remove(handle));
+        assert!(!event.remove(handle));
+
+        event.invoke(&());
+        assert_eq!(counter.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_reentrant_changes() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let added = Arc::new(Mutex::new(None));
+        let event = Arc::new(SyncEvent::new());
+
+        let first = event.add({
+            let (event, counter, added) = (Arc::downgrade(&event), counter.clone(), added.clone());
+            move |_: &()| {
+                let counter = Arc::clone(&counter);
+                let handle = event.upgrade().unwrap().add(move |_| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                });
+                *added.lock().unwrap() = Some(handle);
+            }
+        });
+        event.invoke(&());
+        assert_eq!(counter.load(Ordering::SeqCst), 0);
+        assert!(event.remove(first));
+
+        let added = added.lock().unwrap().unwrap();
+        event.add({
+            let event = Arc::downgrade(&event);
+            move |_| assert!(event.upgrade().unwrap().remove(added))
+        });
+        event.invoke(&());
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        assert_eq!(event.len(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_invoke_par() {
+        let log = Mutex::new(vec![]);
+        let event = SyncEvent::new();
+        for i in 0..16 {
+            let log = &log;
+            event.add(move |arg: &u64| log.lock().unwrap().push(i * arg));
+        }
+
+        event.invoke_par(&2);
+        let mut log = std::mem::take(&mut *log.lock().unwrap());
+        log.sort();
+        assert_eq!(log, (0..16).map(|i| i * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_clone() {
+        let log = Mutex::new(vec![]);
+        let event = SyncEvent::new();
+        let first = event.add(|arg: &i32| log.lock().unwrap().push(*arg));
+
+        let snapshot = event.clone();
+        event.add(|arg: &i32| log.lock().unwrap().push(arg * 10));
+        assert_eq!(snapshot.len(), 1);
+
+        thread::scope(|s| {
+            s.spawn(|| snapshot.invoke(&1));
+        });
+        assert!(snapshot.remove(first));
+        assert!(event.remove(first));
+        assert!(snapshot.is_empty());
+
+        event.invoke(&2);
+        assert_eq!(*log.lock().unwrap(), vec![1, 20]);
+    }
+}
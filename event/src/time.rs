@@ -0,0 +1,214 @@
+use std::cell::{Cell, RefCell};
+use std::time::{Duration, Instant};
+
+use crate::handlers::{Deferred, Handler};
+use crate::{Event, EventHandler, HandlerId, Storage};
+
+/// Coalesces bursts of invocations into a single call after a quiet period.
+struct Debounce<'a, TEventArgs, TResult> {
+    handler: EventHandler<'a, TEventArgs, TResult>,
+    delay: Duration,
+    pending: RefCell<Option<(TEventArgs, Instant)>>,
+}
+
+impl<'a, TEventArgs: Clone, TResult> Deferred<TEventArgs> for Debounce<'a, TEventArgs, TResult> {
+    fn record(&self, arg: &TEventArgs) {
+        *self.pending.borrow_mut() = Some((arg.clone(), Instant::now()));
+    }
+
+    fn poll(&self, now: Instant) {
+        let due = self
+            .pending
+            .borrow()
+            .as_ref()
+            .is_some_and(|(_, at)| now.saturating_duration_since(*at) >= self.delay);
+
+        if let Some((arg, _)) = due.then(|| self.pending.take()).flatten() {
+            (self.handler)(&arg);
+        }
+    }
+}
+
+impl<'a, TEventArgs, TResult, TStorage: Storage> Event<'a, TEventArgs, TResult, TStorage> {
+    /// Adds a debounced event handler to the event.
+    ///
+    /// Instead of running on every invocation, the handler runs once with the
+    /// latest event arguments after the event has not been invoked for `delay`.
+    /// Since the event has no timer of its own, the call is made by the first
+    /// [`pump`](Self::pump) after the quiet period; its result is discarded.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<&str>::new();
+    /// event.add_debounced(Duration::from_millis(10), |path| {
+    ///     println!("Reloading after changes to {}", path);
+    /// });
+    ///
+    /// event.invoke(&"a.toml");
+    /// event.invoke(&"b.toml");
+    /// event.pump(); // Still within the quiet period, nothing happens
+    ///
+    /// thread::sleep(Duration::from_millis(10));
+    /// event.pump(); // Prints "Reloading after changes to b.toml"
+    /// ```
+    pub fn add_debounced<F>(&self, delay: Duration, handler: F) -> HandlerId<TEventArgs>
+    where
+        TEventArgs: Clone + 'a,
+        TResult: 'a,
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        self.insert(Handler::Deferred(Box::new(Debounce {
+            handler: Box::new(handler),
+            delay,
+            pending: RefCell::new(None),
+        })))
+    }
+
+    /// Adds a throttled event handler to the event.
+    ///
+    /// The handler runs at most once per `interval`: it runs on the first invocation,
+    /// and invocations within `interval` of the last call are dropped.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<(i32, i32)>::new();
+    /// event.add_throttled(Duration::from_millis(16), |(x, y)| {
+    ///     println!("Mouse moved to ({}, {})", x, y);
+    /// });
+    ///
+    /// event.invoke(&(0, 0)); // Prints "Mouse moved to (0, 0)"
+    /// event.invoke(&(1, 1)); // Dropped, too soon after the last call
+    /// ```
+    pub fn add_throttled<F>(&self, interval: Duration, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        let last = Cell::new(None::<Instant>);
+        self.insert(Handler::guarded(move |arg, _| {
+            let now = Instant::now();
+            let ready = last
+                .get()
+                .is_none_or(|last| now.saturating_duration_since(last) >= interval);
+
+            ready.then(|| {
+                last.set(Some(now));
+                handler(arg)
+            })
+        }))
+    }
+
+    /// Adds an event handler that is removed once `ttl` has elapsed.
+    ///
+    /// The handler runs for invocations within `ttl` of being added. Since the
+    /// event has no timer of its own, an expired handler is removed lazily, by
+    /// the first invocation after its expiry.
+    ///
+    /// Returns a handle that can be used to remove the handler earlier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// use event_rs::Event;
+    ///
+    /// let event = Event::<&str>::new();
+    /// event.add_for(Duration::from_millis(10), |message| {
+    ///     println!("Toast: {}", message);
+    /// });
+    ///
+    /// event.invoke(&"Saved"); // Prints "Toast: Saved"
+    ///
+    /// thread::sleep(Duration::from_millis(10));
+    /// event.invoke(&"Saved again"); // Expired, the handler is removed
+    /// assert!(event.is_empty());
+    /// ```
+    pub fn add_for<F>(&self, ttl: Duration, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) -> TResult + 'a,
+    {
+        let added = Instant::now();
+        self.insert(Handler::guarded(move |arg, expired| {
+            if added.elapsed() >= ttl {
+                expired.set(true);
+                return None;
+            }
+            Some(handler(arg))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_add_debounced() {
+        let log = RefCell::new(vec![]);
+        let event = Event::new();
+
+        event.add_debounced(Duration::from_millis(20), |arg: &i32| {
+            log.borrow_mut().push(*arg)
+        });
+
+        event.pump();
+        for i in 0..5 {
+            event.invoke(&i);
+        }
+        event.pump();
+        assert!(log.borrow().is_empty());
+
+        thread::sleep(Duration::from_millis(20));
+        event.pump();
+        event.pump();
+        assert_eq!(*log.borrow(), vec![4]);
+    }
+
+    #[test]
+    fn test_add_throttled() {
+        let event = Event::new();
+
+        event.add_throttled(Duration::from_millis(20), |arg: &i32| *arg);
+
+        assert_eq!(event.invoke_collect(&1), vec![1]);
+        assert!(event.invoke_collect(&2).is_empty());
+
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(event.invoke_collect(&3), vec![3]);
+        assert!(event.invoke_collect(&4).is_empty());
+    }
+
+    #[test]
+    fn test_add_for() {
+        let event = Event::new();
+
+        event.add_for(Duration::from_millis(20), |arg: &i32| *arg);
+        event.add_for(Duration::ZERO, |arg: &i32| arg * 10);
+
+        assert_eq!(event.invoke_collect(&1), vec![1]);
+        assert_eq!(event.len(), 1);
+
+        thread::sleep(Duration::from_millis(20));
+        assert!(event.invoke_collect(&2).is_empty());
+        assert!(event.is_empty());
+    }
+}
@@ -0,0 +1,369 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::{Event, HandlerId};
+
+/// A level of the topic trie of a [`TopicBus`].
+///
+/// Wildcard filters are stored like any other level under `"+"` and `"#"`.
+struct Node<'a, TEventArgs> {
+    event: Option<Rc<Event<'a, TEventArgs>>>,
+    children: HashMap<String, Node<'a, TEventArgs>>,
+}
+
+impl<'a, TEventArgs> Default for Node<'a, TEventArgs> {
+    fn default() -> Self {
+        Self {
+            event: None,
+            children: HashMap::new(),
+        }
+    }
+}
+
+impl<'a, TEventArgs> Node<'a, TEventArgs> {
+    fn find(&self, filter: &str) -> Option<&Self> {
+        filter
+            .split('/')
+            .try_fold(self, |node, level| node.children.get(level))
+    }
+
+    /// Drops the event of the filter at `levels` if it has no handlers left, and
+    /// the levels that lead only to it.
+    ///
+    /// Returns `true` if this node is left empty.
+    fn prune(&mut self, levels: &[&str]) -> bool {
+        match levels.split_first() {
+            Some((level, rest)) => {
+                if let Some(node) = self.children.get_mut(*level)
+                    && node.prune(rest)
+                {
+                    self.children.remove(*level);
+                }
+            }
+            None => {
+                if self.event.as_ref().is_some_and(|event| event.is_empty()) {
+                    self.event = None;
+                }
+            }
+        }
+        self.event.is_none() && self.children.is_empty()
+    }
+
+    /// Collects the events of the filters that match the remaining `levels`.
+    fn matches(&self, levels: &[&str], events: &mut Vec<Rc<Event<'a, TEventArgs>>>) {
+        // A trailing `#` also matches the parent level, e.g. `a/#` matches `a`.
+        if let Some(event) = self.children.get("#").and_then(|node| node.event.as_ref()) {
+            events.push(Rc::clone(event));
+        }
+
+        let Some((level, rest)) = levels.split_first() else {
+            events.extend(self.event.iter().cloned());
+            return;
+        };
+        if let Some(node) = self.children.get(*level) {
+            node.matches(rest, events);
+        }
+        if *level != "+"
+            && let Some(node) = self.children.get("+")
+        {
+            node.matches(rest, events);
+        }
+    }
+}
+
+/// A publish/subscribe bus that routes events by string topic.
+///
+/// Topics are defined at runtime, which suits plugin systems where the set of
+/// events is not known at compile time. Every topic carries the same argument
+/// type `TEventArgs`; use [`EventBus`](crate::EventBus) to route by type instead.
+///
+/// Topics are hierarchical, with levels separated by `/`. Like in MQTT, a
+/// subscription may use wildcards that occupy a whole level: `+` matches any
+/// single level, and `#` as the last level matches any number of levels,
+/// including none. So `"sensor/+/temperature"` matches
+/// `"sensor/kitchen/temperature"`, and `"sensor/#"` matches `"sensor"` and
+/// every topic below it.
+///
+/// Internally, each subscribed topic filter owns an [`Event`] stored in a trie
+/// with a `HashMap` per level, so publishing costs one hash lookup per level
+/// and wildcard rather than a scan of all subscriptions.
+///
+/// # Examples
+///
+/// ```
+/// use event_rs::TopicBus;
+///
+/// let bus = TopicBus::<&str>::new();
+/// bus.subscribe("user/created", |name| {
+///     println!("Welcome, {}!", name);
+/// });
+///
+/// bus.publish("user/created", &"Alice");
+/// bus.publish("user/deleted", &"Bob"); // No handlers, nothing happens
+/// ```
+pub struct TopicBus<'a, TEventArgs> {
+    topics: RefCell<Node<'a, TEventArgs>>,
+}
+
+impl<'a, TEventArgs> Default for TopicBus<'a, TEventArgs> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, TEventArgs> TopicBus<'a, TEventArgs> {
+    /// Creates a new, empty TopicBus
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::TopicBus;
+    ///
+    /// let bus: TopicBus<()> = TopicBus::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            topics: Node::default().into(),
+        }
+    }
+
+    /// Adds an event handler for the given topic filter, which may contain
+    /// wildcards.
+    ///
+    /// Returns a handle that can be used to remove the handler later
+    /// with [`unsubscribe`](Self::unsubscribe).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the filter is malformed: a wildcard must occupy a whole level,
+    /// and `#` must be the last level. Such a filter could never match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::TopicBus;
+    ///
+    /// let bus = TopicBus::<()>::new();
+    /// let handle = bus.subscribe("app/started", |args| {
+    ///     println!("Started");
+    /// });
+    /// bus.subscribe("app/#", |args| println!("Any app event"));
+    /// ```
+    pub fn subscribe<F>(&self, topic: &str, handler: F) -> HandlerId<TEventArgs>
+    where
+        F: Fn(&TEventArgs) + 'a,
+    {
+        assert!(is_valid_filter(topic), "malformed topic filter {:?}", topic);
+        let event = match self.topic(topic) {
+            Some(event) => event,
+            None => {
+                let mut node = &mut *self.topics.borrow_mut();
+                for level in topic.split('/') {
+                    node = node.children.entry(level.to_owned()).or_default();
+                }
+                Rc::clone(node.event.get_or_insert_default())
+            }
+        };
+        event.add(handler)
+    }
+
+    /// Removes an event handler from the given topic filter using its handle.
+    ///
+    /// Returns `true` if the handler was found and removed, `false` otherwise.
+    /// Once a filter has no handlers left, it is removed from the bus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::TopicBus;
+    ///
+    /// let bus = TopicBus::<()>::new();
+    /// let handle = bus.subscribe("app/started", |args| {
+    ///     println!("Started");
+    /// });
+    ///
+    /// assert!(bus.unsubscribe("app/started", handle));
+    /// assert!(!bus.unsubscribe("app/started", handle)); // Already removed
+    /// ```
+    pub fn unsubscribe(&self, topic: &str, handle: HandlerId<TEventArgs>) -> bool {
+        let removed = self.topic(topic).is_some_and(|event| event.remove(handle));
+        if removed {
+            let levels: Vec<_> = topic.split('/').collect();
+            self.topics.borrow_mut().prune(&levels);
+        }
+        removed
+    }
+
+    /// Invokes all handlers subscribed to a filter that matches the given topic.
+    ///
+    /// The handlers of an exact subscription run after those of matching `#`
+    /// filters and before those of matching `+` filters. Handlers may subscribe
+    /// or publish to other topics while running.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::TopicBus;
+    ///
+    /// let bus = TopicBus::new();
+    /// bus.subscribe("sensor/temperature", |celsius| assert_eq!(*celsius, 21.5));
+    ///
+    /// bus.subscribe("sensor/+", |celsius| println!("{} degrees", celsius));
+    ///
+    /// bus.publish("sensor/temperature", &21.5);
+    /// ```
+    pub fn publish(&self, topic: &str, arg: &TEventArgs) {
+        let mut events = Vec::new();
+        let levels: Vec<_> = topic.split('/').collect();
+        self.topics.borrow().matches(&levels, &mut events);
+        for event in events {
+            event.invoke(arg);
+        }
+    }
+
+    /// Removes all event handlers of every topic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use event_rs::TopicBus;
+    ///
+    /// let bus = TopicBus::<()>::new();
+    /// bus.subscribe("app/started", |args| println!("Started"));
+    ///
+    /// bus.clear(); // Remove all handlers
+    /// ```
+    pub fn clear(&self) {
+        *self.topics.borrow_mut() = Node::default();
+    }
+
+    fn topic(&self, topic: &str) -> Option<Rc<Event<'a, TEventArgs>>> {
+        self.topics.borrow().find(topic)?.event.clone()
+    }
+}
+
+/// Returns whether every wildcard of `filter` occupies a whole level, with `#`
+/// only as the last level.
+fn is_valid_filter(filter: &str) -> bool {
+    let mut levels = filter.split('/').peekable();
+    while let Some(level) = levels.next() {
+        let valid = match level {
+            "+" => true,
+            "#" => levels.peek().is_none(),
+            _ => !level.contains(['+', '#']),
+        };
+        if !valid {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_publish_routes_by_topic() {
+        let log = RefCell::new(vec![]);
+        let bus = TopicBus::new();
+
+        bus.subscribe("a", |arg: &i32| log.borrow_mut().push(("a", *arg)));
+        bus.subscribe("b", |arg: &i32| log.borrow_mut().push(("b", *arg)));
+        bus.subscribe("a", |arg: &i32| log.borrow_mut().push(("a", *arg * 10)));
+
+        bus.publish("a", &1);
+        bus.publish("b", &2);
+        bus.publish("c", &3);
+
+        assert_eq!(*log.borrow(), vec![("a", 1), ("a", 10), ("b", 2)]);
+    }
+
+    #[test]
+    fn test_wildcards() {
+        let log = RefCell::new(vec![]);
+        let bus = TopicBus::new();
+
+        bus.subscribe("sensor/+/temperature", |arg: &i32| {
+            log.borrow_mut().push(("+", *arg))
+        });
+        bus.subscribe("sensor/#", |arg: &i32| log.borrow_mut().push(("#", *arg)));
+        bus.subscribe("#", |arg: &i32| log.borrow_mut().push(("all", *arg)));
+        bus.subscribe("+/+", |arg: &i32| log.borrow_mut().push(("+/+", *arg)));
+
+        bus.publish("sensor/kitchen/temperature", &1);
+        bus.publish("sensor/kitchen/humidity", &2);
+        bus.publish("sensor", &3);
+        bus.publish("sensor/+", &4);
+        bus.publish("light/on", &5);
+
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                ("all", 1),
+                ("#", 1),
+                ("+", 1),
+                ("all", 2),
+                ("#", 2),
+                ("all", 3),
+                ("#", 3),
+                ("all", 4),
+                ("#", 4),
+                ("+/+", 4),
+                ("all", 5),
+                ("+/+", 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let counter = RefCell::new(0);
+        let bus = TopicBus::new();
+
+        let handle = bus.subscribe("a", |_: &()| *counter.borrow_mut() += 1);
+
+        assert!(!bus.unsubscribe("b", handle));
+        assert!(bus.unsubscribe("a", handle));
+
+        bus.publish("a", &());
+        assert_eq!(*counter.borrow(), 0);
+    }
+
+    #[test]
+    fn test_unsubscribe_prunes_topics() {
+        let bus = TopicBus::new();
+        let parent = bus.subscribe("a", |_: &()| {});
+        let first = bus.subscribe("a/b/c", |_: &()| {});
+        let second = bus.subscribe("a/b/c", |_: &()| {});
+
+        assert!(bus.unsubscribe("a/b/c", first));
+        assert!(bus.topics.borrow().find("a/b/c").is_some());
+        assert!(bus.unsubscribe("a/b/c", second));
+        assert!(bus.topics.borrow().find("a/b").is_none());
+        assert!(bus.topics.borrow().find("a").is_some());
+
+        assert!(bus.unsubscribe("a", parent));
+        assert!(bus.topics.borrow().children.is_empty());
+    }
+
+    #[test]
+    fn test_valid_filters() {
+        for filter in ["a", "a/b", "+", "#", "a/+/b", "a/#", "+/#", "", "a//b"] {
+            assert!(is_valid_filter(filter), "{:?}", filter);
+        }
+        for filter in ["a/#/b", "#/a", "a/b#", "sen+sor", "a/++", "##"] {
+            assert!(!is_valid_filter(filter), "{:?}", filter);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "malformed topic filter \"a/#/b\"")]
+    fn test_subscribe_malformed_filter() {
+        let bus = TopicBus::new();
+        bus.subscribe("a/#/b", |_: &()| {});
+    }
+}
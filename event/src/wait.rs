@@ -0,0 +1,124 @@
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::time::Duration;
+
+use crate::SyncEvent;
+
+impl<'a, TEventArgs: Clone + Send + 'a> SyncEvent<'a, TEventArgs> {
+    /// Blocks the current thread until the event is invoked, returning a clone
+    /// of the event arguments, or `None` if `timeout` elapses first.
+    ///
+    /// Only invocations that start after the call are seen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// use event_rs::SyncEvent;
+    ///
+    /// let event = SyncEvent::<u32>::new();
+    ///
+    /// thread::scope(|s| {
+    ///     s.spawn(|| {
+    ///         while event.is_empty() {
+    ///             thread::yield_now();
+    ///         }
+    ///         event.invoke(&42);
+    ///     });
+    ///
+    ///     assert_eq!(event.wait(Duration::from_secs(10)), Some(42));
+    /// });
+    ///
+    /// assert_eq!(event.wait(Duration::from_millis(1)), None);
+    /// ```
+    pub fn wait(&self, timeout: Duration) -> Option<TEventArgs> {
+        self.wait_matching(timeout, |_| true)
+    }
+
+    /// Blocks the current thread until the event is invoked with arguments that
+    /// satisfy `predicate`, returning a clone of them, or `None` if `timeout`
+    /// elapses first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// use event_rs::SyncEvent;
+    ///
+    /// let progress = SyncEvent::<u32>::new();
+    ///
+    /// thread::scope(|s| {
+    ///     s.spawn(|| {
+    ///         while progress.is_empty() {
+    ///             thread::yield_now();
+    ///         }
+    ///         for percent in (0..=100).step_by(10) {
+    ///             progress.invoke(&percent);
+    ///         }
+    ///     });
+    ///
+    ///     let done = progress.wait_matching(Duration::from_secs(10), |percent| *percent == 100);
+    ///     assert_eq!(done, Some(100));
+    /// });
+    /// ```
+    pub fn wait_matching<P>(&self, timeout: Duration, predicate: P) -> Option<TEventArgs>
+    where
+        P: Fn(&TEventArgs) -> bool + Send + Sync + 'a,
+    {
+        let received = Arc::new((Mutex::new(None), Condvar::new()));
+        let sender = Arc::clone(&received);
+        let handle = self.add(move |arg| {
+            if !predicate(arg) {
+                return;
+            }
+            let (value, condvar) = &*sender;
+            let mut value = value.lock().unwrap_or_else(PoisonError::into_inner);
+            if value.is_none() {
+                *value = Some(arg.clone());
+                condvar.notify_one();
+            }
+        });
+
+        let (value, condvar) = &*received;
+        let value = {
+            let value = value.lock().unwrap_or_else(PoisonError::into_inner);
+            let (mut value, _) = condvar
+                .wait_timeout_while(value, timeout, |value| value.is_none())
+                .unwrap_or_else(PoisonError::into_inner);
+            value.take()
+        };
+        self.remove(handle);
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_wait_matching() {
+        let event = SyncEvent::new();
+
+        let value = thread::scope(|s| {
+            s.spawn(|| {
+                while event.is_empty() {
+                    thread::yield_now();
+                }
+                for i in 0..10 {
+                    event.invoke(&i);
+                }
+            });
+            event.wait_matching(Duration::from_secs(10), |i| i % 4 == 3)
+        });
+
+        assert_eq!(value, Some(3));
+        assert!(event.is_empty());
+        assert_eq!(event.wait(Duration::from_millis(1)), None);
+    }
+}
@@ -0,0 +1,111 @@
+use js_sys::Function;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::JsValue;
+
+use crate::{Event, HandlerId, Storage};
+
+impl<'a, TEventArgs, TStorage: Storage> Event<'a, TEventArgs, (), TStorage> {
+    /// Adds a JavaScript function as an event handler.
+    ///
+    /// On each invocation, the event arguments are converted to a JavaScript value
+    /// with `serde_wasm_bindgen` and passed to the function, called with an
+    /// `undefined` `this`. Exceptions thrown by the function, and event arguments
+    /// that cannot be converted, do not keep the other handlers from running:
+    /// they are logged as warnings with the `log` or `tracing` feature, and
+    /// otherwise discarded. Use [`add_js_try`](Self::add_js_try) on an event
+    /// returning `Result<(), JsValue>` to receive them from
+    /// [`invoke_try`](Event::invoke_try) instead.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use event_rs::Event;
+    /// use js_sys::Function;
+    ///
+    /// let event = Event::<(i32, i32)>::new();
+    /// let on_clicked = Function::new_with_args("point", "console.log('Clicked at', point)");
+    /// event.add_js(on_clicked);
+    ///
+    /// event.invoke(&(1, 2)); // Logs "Clicked at [1, 2]" in the browser console
+    /// ```
+    pub fn add_js(&self, function: Function) -> HandlerId<TEventArgs>
+    where
+        TEventArgs: Serialize,
+    {
+        self.add(move |arg| {
+            #[allow(unused_variables)]
+            if let Err(error) = call_js(&function, arg) {
+                #[cfg(feature = "log")]
+                log::warn!("JavaScript event handler failed: {:?}", error);
+                #[cfg(feature = "tracing")]
+                tracing::warn!(?error, "JavaScript event handler failed");
+            }
+        })
+    }
+
+    /// Invokes all event handlers sequentially with event arguments converted from
+    /// a JavaScript value.
+    ///
+    /// This lets JavaScript raise the event. The value is converted with
+    /// `serde_wasm_bindgen`; if it does not match the event arguments, the error
+    /// is returned and the event is not invoked.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use event_rs::Event;
+    /// use wasm_bindgen::JsValue;
+    ///
+    /// let event = Event::<String>::new();
+    /// event.add(|name| println!("Hello, {}!", name));
+    ///
+    /// event.invoke_js(JsValue::from_str("Ada")).unwrap(); // Prints "Hello, Ada!"
+    /// ```
+    pub fn invoke_js(&self, arg: JsValue) -> Result<(), serde_wasm_bindgen::Error>
+    where
+        TEventArgs: DeserializeOwned,
+    {
+        let arg = serde_wasm_bindgen::from_value(arg)?;
+        self.invoke(&arg);
+        Ok(())
+    }
+}
+
+impl<'a, TEventArgs, TStorage: Storage> Event<'a, TEventArgs, Result<(), JsValue>, TStorage> {
+    /// Adds a JavaScript function as a fallible event handler.
+    ///
+    /// Like [`add_js`](Event::add_js), but the exception thrown by the function,
+    /// or the error converting the event arguments, is returned by the handler,
+    /// so that [`invoke_try`](Self::invoke_try) reports it.
+    ///
+    /// Returns a handle that can be used to remove the handler later.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use event_rs::Event;
+    /// use js_sys::Function;
+    /// use wasm_bindgen::JsValue;
+    ///
+    /// let event = Event::<i32, Result<(), JsValue>>::new();
+    /// event.add_js_try(Function::new_with_args("value", "throw new Error('broken')"));
+    ///
+    /// assert!(event.invoke_try(&1).is_err());
+    /// ```
+    pub fn add_js_try(&self, function: Function) -> HandlerId<TEventArgs>
+    where
+        TEventArgs: Serialize,
+    {
+        self.add(move |arg| call_js(&function, arg))
+    }
+}
+
+/// Converts `arg` to a JavaScript value and calls `function` with it.
+fn call_js<T: Serialize>(function: &Function, arg: &T) -> Result<(), JsValue> {
+    let arg = serde_wasm_bindgen::to_value(arg)?;
+    function.call1(&JsValue::UNDEFINED, &arg)?;
+    Ok(())
+}
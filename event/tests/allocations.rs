@@ -0,0 +1,54 @@
+//! Counts the allocations made by adding handlers.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use event_rs::Event;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations `f` makes on the current thread.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn on_event(_: &u32) {}
+
+#[test]
+fn test_add_fn_does_not_allocate() {
+    let event = Event::<u32>::with_capacity(4);
+
+    assert_eq!(
+        allocations(|| {
+            event.add_fn(on_event);
+        }),
+        0
+    );
+    // Closures are shared with the snapshots of invocations
+    assert_eq!(
+        allocations(|| {
+            event.add(|_| {});
+        }),
+        1
+    );
+}